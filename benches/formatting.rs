@@ -0,0 +1,92 @@
+//! Benchmarks of the hot formatting paths: styling single values, combining styles, dumping large
+//! buffers and parsing escape sequences back out of rendered text.
+//!
+//! Run with `cargo bench`; compare runs with `cargo bench -- --save-baseline before` and
+//! `cargo bench -- --baseline before`.
+
+use std::hint::black_box;
+
+use bytescolor::hexdump::HexDump;
+use bytescolor::{ansi, ByteColor, Color, Style, StyledText};
+use criterion::{criterion_group, criterion_main, BenchmarkId, Criterion, Throughput};
+
+fn single_style(c: &mut Criterion) {
+    let mut group = c.benchmark_group("single_style");
+    group.bench_function("str_red", |b| b.iter(|| black_box("warning").red()));
+    group.bench_function("u64_green", |b| b.iter(|| black_box(42_u64).green()));
+    group.bench_function("rgb", |b| {
+        b.iter(|| black_box("accent").rgb(black_box((255, 136, 0))))
+    });
+    group.finish();
+}
+
+fn chained_styles(c: &mut Criterion) {
+    let mut group = c.benchmark_group("chained_styles");
+    group.bench_function("builder", |b| {
+        b.iter(|| {
+            let style = Style::new()
+                .fg(Color::White)
+                .bg(Color::Red)
+                .bold()
+                .underline();
+            style.paint(black_box("alert"))
+        })
+    });
+    group.bench_function("operators", |b| {
+        b.iter(|| (Style::RED | Style::BOLD | Style::UNDERLINE).paint(black_box("alert")))
+    });
+    group.bench_function("styled_text", |b| {
+        b.iter(|| {
+            let mut text = StyledText::plain("status: ");
+            text.push("failed", Style::RED | Style::BOLD);
+            text.push(" (3 tests)", Style::DIM);
+            black_box(&text).render()
+        })
+    });
+    group.finish();
+}
+
+fn hexdump(c: &mut Criterion) {
+    let mut group = c.benchmark_group("hexdump");
+    for size in [4 * 1024, 64 * 1024] {
+        let bytes: Vec<u8> = (0..size).map(|i| (i * 31 % 256) as u8).collect();
+        group.throughput(Throughput::Bytes(size as u64));
+        group.bench_with_input(BenchmarkId::from_parameter(size), &bytes, |b, bytes| {
+            b.iter(|| HexDump::new().render(black_box(bytes)))
+        });
+    }
+    group.finish();
+}
+
+fn strip_and_parse(c: &mut Criterion) {
+    let line = format!(
+        "{} {} {} {}\n",
+        "12:00:01".cyan(),
+        "ERROR".styled(Style::RED | Style::BOLD),
+        "disk full on".yellow(),
+        "/dev/sda1".underline()
+    );
+    let log = line.repeat(1000);
+    let mut group = c.benchmark_group("strip_and_parse");
+    group.throughput(Throughput::Bytes(log.len() as u64));
+    group.bench_function("strip", |b| b.iter(|| ansi::strip(black_box(&log)).len()));
+    group.bench_function("visible_width", |b| {
+        b.iter(|| ansi::visible_width(black_box(&log)))
+    });
+    group.bench_function("from_ansi", |b| {
+        b.iter(|| StyledText::from_ansi(black_box(&log)))
+    });
+    group.bench_function("from_ansi_render", |b| {
+        b.iter(|| StyledText::from_ansi(black_box(&log)).render())
+    });
+    group.finish();
+}
+
+criterion_group!(
+    benches,
+    single_style,
+    chained_styles,
+    hexdump,
+    strip_and_parse
+);
+criterion_main!(benches);
@@ -0,0 +1,28 @@
+//! Escape sequence scanning on arbitrary text: stripping, measuring, truncating and sanitizing must
+//! never panic, and must agree with each other.
+
+#![no_main]
+
+use bytescolor::ansi;
+use bytescolor::sanitize::{Sanitize, ShowControls};
+use libfuzzer_sys::fuzz_target;
+
+fuzz_target!(|data: &[u8]| {
+    let Some((&max, rest)) = data.split_first() else {
+        return;
+    };
+    let text = String::from_utf8_lossy(rest);
+
+    let stripped = ansi::strip(&text);
+    assert!(!stripped.contains('\x1b'));
+    let width = ansi::visible_width(&text);
+    assert_eq!(width, ansi::visible_width(&stripped));
+
+    let truncated = ansi::truncate(&text, usize::from(max));
+    assert!(ansi::visible_width(&truncated) <= usize::from(max));
+    assert!(ansi::visible_width(&truncated) <= width);
+
+    let _ = text.sanitize();
+    let _ = text.escape_controls();
+    let _ = text.show_controls();
+});
@@ -0,0 +1,49 @@
+//! Colorizers of arbitrary binary input: byte strings, dumps, encodings and TLV walks must never panic
+//! or slice out of bounds, whatever the bytes.
+
+#![no_main]
+
+use bytescolor::encoding::EncodingColor;
+use bytescolor::hexdump::{Field, HexDump};
+use bytescolor::sanitize::{Sanitize, ShowControls};
+use bytescolor::tlv::{LengthFormat, TagFormat, Tlv};
+use bytescolor::{ByteColor, Style};
+use libfuzzer_sys::fuzz_target;
+
+fuzz_target!(|data: &[u8]| {
+    let Some((&selector, bytes)) = data.split_first() else {
+        return;
+    };
+
+    let _ = bytes.red();
+    let _ = bytes.styled(Style::BOLD);
+    let _ = bytes.show_controls();
+    let _ = bytes.sanitize();
+
+    let _ = bytes.hex_string_colored();
+    let _ = bytes.base64_colored();
+    let _ = bytes.fingerprint_colored();
+
+    let width = usize::from(selector % 32) + 1;
+    let dump = HexDump::new().width(width).start(usize::from(selector));
+    let _ = dump.render(bytes);
+    let start = usize::from(selector) % (bytes.len() + 1);
+    let fields = [Field::new("field", start, width, Style::CYAN)];
+    let _ = dump.render_annotated(bytes, &fields);
+
+    let formats = [
+        (TagFormat::Ber, LengthFormat::Ber),
+        (TagFormat::Varint, LengthFormat::Varint),
+        (
+            TagFormat::Fixed(usize::from(selector % 4)),
+            LengthFormat::Fixed(usize::from(selector % 9)),
+        ),
+    ];
+    let (tag, length) = formats[usize::from(selector) % formats.len()];
+    let _ = Tlv::der().render(bytes);
+    let _ = Tlv::new()
+        .tag(tag)
+        .length(length)
+        .nested(|tag| tag % 3 == 0)
+        .render(bytes);
+});
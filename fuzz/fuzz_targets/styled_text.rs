@@ -0,0 +1,39 @@
+//! Parsing rendered text back into spans, and slicing and editing the result by visible index, on
+//! arbitrary input: none of it may panic, and rendering must preserve the visible text.
+
+#![no_main]
+
+use bytescolor::{ansi, Style, StyledText};
+use libfuzzer_sys::fuzz_target;
+
+fuzz_target!(|data: &[u8]| {
+    let [a, b, ref rest @ ..] = *data else {
+        return;
+    };
+    let text = String::from_utf8_lossy(rest);
+
+    let parsed = StyledText::from_ansi(&text);
+    let visible = parsed.visible();
+    assert_eq!(visible, ansi::strip(&text).replace('\x1b', ""));
+    let rendered = parsed.render();
+    assert_eq!(ansi::strip(&rendered), visible);
+    assert_eq!(StyledText::from_ansi(&rendered).visible(), visible);
+
+    let (a, b) = (usize::from(a), usize::from(b));
+    let slice = parsed.slice(a.min(b)..a.max(b));
+    assert!(slice.len() <= parsed.len());
+
+    let mut edited = parsed.clone();
+    edited.restyle_range(a..b, Style::RED);
+    assert_eq!(edited.visible(), visible);
+    edited.replace_range_visible(b..a, "x");
+
+    let _ = parsed.style_graphemes(|index, _| {
+        if index % 2 == 0 {
+            Style::BOLD
+        } else {
+            Style::new()
+        }
+    });
+    let _ = parsed.prefix_lines("│ ").indent(a % 8);
+});
@@ -0,0 +1,306 @@
+//! # ANSI Utilities
+//!
+//! Helpers for working with text that already contains ANSI escape sequences: removing them and measuring
+//! how wide the text appears on screen. Layout code throughout the crate relies on these so that padding
+//! and alignment are computed on the visible content only.
+//!
+//! ```rust
+//! use bytescolor::{ansi, ByteColor};
+//!
+//! let styled = "ok".green();
+//! assert_eq!(ansi::strip(&styled), "ok");
+//! assert_eq!(ansi::visible_width(&styled), 2);
+//! ```
+
+use std::borrow::Cow;
+use std::fmt;
+
+const ESC: u8 = 0x1b;
+const BEL: u8 = 0x07;
+
+/// Returns the length in bytes of the escape sequence starting at `bytes[0]` (which must be `ESC`).
+///
+/// Handles CSI (`ESC [ ... final`), string sequences terminated by `BEL` or `ESC \` (OSC, DCS, APC,
+/// PM, SOS) and two-byte escapes. Unterminated sequences extend to the end of the input.
+pub(crate) fn sequence_len(bytes: &[u8]) -> usize {
+    debug_assert_eq!(bytes.first(), Some(&ESC));
+    match bytes.get(1) {
+        None => 1,
+        Some(b'[') => {
+            let mut i = 2;
+            while i < bytes.len() {
+                if (0x40..=0x7e).contains(&bytes[i]) {
+                    return i + 1;
+                }
+                i += 1;
+            }
+            bytes.len()
+        }
+        Some(b']' | b'P' | b'X' | b'^' | b'_') => {
+            let mut i = 2;
+            while i < bytes.len() {
+                match bytes[i] {
+                    BEL => return i + 1,
+                    ESC if bytes.get(i + 1) == Some(&b'\\') => return i + 2,
+                    _ => i += 1,
+                }
+            }
+            bytes.len()
+        }
+        Some(_) => 2,
+    }
+}
+
+/// Removes every ANSI escape sequence from `text`.
+///
+/// Returns the input unchanged (and without allocating) when it contains no escape character.
+///
+/// # Examples
+///
+/// ```rust
+/// use bytescolor::ansi;
+///
+/// assert_eq!(ansi::strip("\x1b[1;31merror\x1b[0m: boom"), "error: boom");
+/// ```
+pub fn strip(text: &str) -> Cow<'_, str> {
+    if !text.as_bytes().contains(&ESC) {
+        return Cow::Borrowed(text);
+    }
+    let bytes = text.as_bytes();
+    let mut out = String::with_capacity(text.len());
+    let mut pos = 0;
+    while let Some(offset) = bytes[pos..].iter().position(|&b| b == ESC) {
+        let esc = pos + offset;
+        out.push_str(&text[pos..esc]);
+        pos = esc + sequence_len(&bytes[esc..]);
+        // Unterminated sequences may end inside a multi-byte character.
+        while !text.is_char_boundary(pos) {
+            pos += 1;
+        }
+    }
+    out.push_str(&text[pos..]);
+    Cow::Owned(out)
+}
+
+/// Returns the number of terminal columns `text` occupies, ignoring escape sequences.
+///
+/// Without the `unicode-width` feature every character counts as one column. With it, East Asian wide
+/// characters and emoji count as two and combining marks and zero-width joiners as none.
+///
+/// # Examples
+///
+/// ```rust
+/// use bytescolor::{ansi, ByteColor};
+///
+/// assert_eq!(ansi::visible_width(&"naïve".bold()), 5);
+/// ```
+pub fn visible_width(text: &str) -> usize {
+    text_width(&strip(text))
+}
+
+/// Returns the number of columns of text without escape sequences.
+pub(crate) fn text_width(text: &str) -> usize {
+    #[cfg(feature = "unicode-width")]
+    {
+        unicode_width::UnicodeWidthStr::width(text)
+    }
+    #[cfg(not(feature = "unicode-width"))]
+    {
+        text.chars().count()
+    }
+}
+
+/// Returns the number of columns of a single character.
+#[cfg(feature = "highlight")]
+pub(crate) fn char_width(c: char) -> usize {
+    #[cfg(feature = "unicode-width")]
+    {
+        unicode_width::UnicodeWidthChar::width(c).unwrap_or(0)
+    }
+    #[cfg(not(feature = "unicode-width"))]
+    {
+        let _ = c;
+        1
+    }
+}
+
+/// Splits text without escape sequences into grapheme clusters, the units perceived as one character:
+/// an emoji with its modifiers and joined parts, or a letter with its combining marks.
+///
+/// With the `unicode-segmentation` feature, clusters follow the extended grapheme cluster rules of
+/// UAX #29. Otherwise, a character is grouped with the combining marks, variation selectors, emoji
+/// modifiers and tags following it, characters joined by a zero-width joiner, regional indicator pairs,
+/// and `\r\n`, which covers common text.
+pub(crate) fn graphemes(text: &str) -> impl Iterator<Item = &str> {
+    #[cfg(feature = "unicode-segmentation")]
+    {
+        unicode_segmentation::UnicodeSegmentation::graphemes(text, true)
+    }
+    #[cfg(not(feature = "unicode-segmentation"))]
+    {
+        Graphemes { text }
+    }
+}
+
+#[cfg(not(feature = "unicode-segmentation"))]
+struct Graphemes<'a> {
+    text: &'a str,
+}
+
+#[cfg(not(feature = "unicode-segmentation"))]
+impl<'a> Iterator for Graphemes<'a> {
+    type Item = &'a str;
+
+    fn next(&mut self) -> Option<&'a str> {
+        let mut chars = self.text.char_indices();
+        let (_, first) = chars.next()?;
+        let mut end = first.len_utf8();
+        let mut previous = first;
+        let mut regional = is_regional_indicator(first);
+        for (index, c) in chars {
+            let joined = match (previous, c) {
+                ('\r', '\n') => true,
+                (previous, _) if previous.is_control() => false,
+                ('\u{200d}', _) => true,
+                (_, c) if is_extend(c) => true,
+                (_, c) if regional && is_regional_indicator(c) => {
+                    regional = false;
+                    true
+                }
+                _ => false,
+            };
+            if !joined {
+                break;
+            }
+            end = index + c.len_utf8();
+            previous = c;
+        }
+        let (cluster, rest) = self.text.split_at(end);
+        self.text = rest;
+        Some(cluster)
+    }
+}
+
+/// Returns `true` for characters that extend the preceding grapheme cluster.
+#[cfg(not(feature = "unicode-segmentation"))]
+fn is_extend(c: char) -> bool {
+    matches!(
+        c,
+        '\u{300}'..='\u{36f}'
+            | '\u{483}'..='\u{489}'
+            | '\u{591}'..='\u{5bd}'
+            | '\u{610}'..='\u{61a}'
+            | '\u{64b}'..='\u{65f}'
+            | '\u{900}'..='\u{903}'
+            | '\u{93a}'..='\u{94f}'
+            | '\u{1ab0}'..='\u{1aff}'
+            | '\u{1dc0}'..='\u{1dff}'
+            | '\u{200c}'..='\u{200d}'
+            | '\u{20d0}'..='\u{20ff}'
+            | '\u{3099}'..='\u{309a}'
+            | '\u{fe00}'..='\u{fe0f}'
+            | '\u{fe20}'..='\u{fe2f}'
+            | '\u{1f3fb}'..='\u{1f3ff}'
+            | '\u{e0020}'..='\u{e007f}'
+            | '\u{e0100}'..='\u{e01ef}'
+    )
+}
+
+#[cfg(not(feature = "unicode-segmentation"))]
+fn is_regional_indicator(c: char) -> bool {
+    ('\u{1f1e6}'..='\u{1f1ff}').contains(&c)
+}
+
+/// Shortens `text` to at most `max` visible columns (see [`visible_width`]), keeping the escape
+/// sequences that precede the cut. A wide character that would straddle the limit is dropped. A reset
+/// is appended when the cut drops styled text, so styles do not leak past the result.
+///
+/// # Examples
+///
+/// ```rust
+/// # if cfg!(feature = "no-color-build") { return; }
+/// use bytescolor::{ansi, ByteColor};
+///
+/// let warning = "warning".yellow();
+/// assert_eq!(ansi::truncate(&warning, 4), "\x1b[33mwarn\x1b[0m");
+/// assert_eq!(ansi::truncate("short", 10), "short");
+/// ```
+pub fn truncate(text: &str, max: usize) -> Cow<'_, str> {
+    if visible_width(text) <= max {
+        return Cow::Borrowed(text);
+    }
+    // The visible runs of `text`, as their offset in the stripped text and in `text`.
+    let bytes = text.as_bytes();
+    let mut runs = Vec::new();
+    let mut stripped = String::with_capacity(text.len());
+    let mut pos = 0;
+    while pos < text.len() {
+        let end = bytes[pos..]
+            .iter()
+            .position(|&b| b == ESC)
+            .map_or(text.len(), |offset| pos + offset);
+        if end > pos {
+            runs.push((stripped.len(), pos));
+            stripped.push_str(&text[pos..end]);
+        }
+        if end == text.len() {
+            break;
+        }
+        pos = end + sequence_len(&bytes[end..]);
+        while !text.is_char_boundary(pos) {
+            pos += 1;
+        }
+    }
+    // Cut at the cluster boundary found by adding up cluster widths, backing off while the width of
+    // the kept text, which is not always the sum of the widths of its clusters, exceeds the limit.
+    let mut boundaries = vec![0];
+    let mut visible = 0;
+    for cluster in graphemes(&stripped) {
+        visible += text_width(cluster);
+        if visible > max {
+            break;
+        }
+        boundaries.push(boundaries[boundaries.len() - 1] + cluster.len());
+    }
+    let mut cut = boundaries.pop().unwrap_or_default();
+    while text_width(&stripped[..cut]) > max {
+        cut = boundaries.pop().unwrap_or_default();
+    }
+    let end = runs
+        .iter()
+        .rev()
+        .find(|(start, _)| *start <= cut)
+        .map_or(0, |&(start, offset)| offset + cut - start);
+    let mut out = text[..end].to_string();
+    if out.as_bytes().contains(&ESC) {
+        out.push_str("\x1b[0m");
+    }
+    Cow::Owned(out)
+}
+
+/// Writes `text` to `f`, applying the width, fill, alignment and precision flags of the formatter to
+/// the visible content only, with the padding placed outside the escape sequences.
+pub(crate) fn pad(f: &mut fmt::Formatter<'_>, text: &str) -> fmt::Result {
+    if f.width().is_none() && f.precision().is_none() {
+        return f.write_str(text);
+    }
+    let text = match f.precision() {
+        Some(max) => truncate(text, max),
+        None => Cow::Borrowed(text),
+    };
+    let padding = f.width().unwrap_or(0).saturating_sub(visible_width(&text));
+    let (before, after) = match f.align() {
+        Some(fmt::Alignment::Right) => (padding, 0),
+        Some(fmt::Alignment::Center) => (padding / 2, padding - padding / 2),
+        Some(fmt::Alignment::Left) | None => (0, padding),
+    };
+    let fill = f.fill();
+    for _ in 0..before {
+        fmt::Write::write_char(f, fill)?;
+    }
+    f.write_str(&text)?;
+    for _ in 0..after {
+        fmt::Write::write_char(f, fill)?;
+    }
+    Ok(())
+}
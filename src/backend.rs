@@ -0,0 +1,134 @@
+//! # Color Backends
+//!
+//! A [`ColorBackend`] turns a [`Style`] into the bytes that select and reset it on the target. The
+//! default [`Ansi`] backend emits SGR escape sequences; targets that speak something else — serial
+//! consoles, proprietary log viewers, markup-based sinks — can install their own encoder with
+//! [`set_backend`] and keep using every method of [`ByteColor`](crate::ByteColor) and [`Style`].
+//!
+//! ```rust
+//! # if cfg!(feature = "no-color-build") { return; }
+//! use std::fmt;
+//!
+//! use bytescolor::backend::{self, ColorBackend};
+//! use bytescolor::control::ColorLevel;
+//! use bytescolor::{ByteColor, Style};
+//!
+//! /// Marks styled text with tags understood by a log viewer.
+//! struct Tags;
+//!
+//! impl ColorBackend for Tags {
+//!     fn write_start(&self, style: Style, f: &mut dyn fmt::Write) -> fmt::Result {
+//!         match style.foreground() {
+//!             Some(color) => write!(f, "[{:?}]", color),
+//!             None => f.write_str("[styled]"),
+//!         }
+//!     }
+//!
+//!     fn write_end(&self, _style: Style, f: &mut dyn fmt::Write) -> fmt::Result {
+//!         f.write_str("[/]")
+//!     }
+//!
+//!     fn color_level(&self) -> ColorLevel {
+//!         ColorLevel::Ansi16
+//!     }
+//! }
+//!
+//! static TAGS: Tags = Tags;
+//!
+//! backend::set_backend(&TAGS);
+//! assert_eq!("failed".red().as_str(), "[Red]failed[/]");
+//! # backend::set_backend(&backend::Ansi);
+//! ```
+
+use std::fmt;
+use std::sync::RwLock;
+
+use crate::control::ColorLevel;
+use crate::Style;
+
+/// Encodes styles for a particular kind of output.
+///
+/// Styles handed to a backend are never plain, and their colors have already been downgraded to
+/// [`color_level`](ColorBackend::color_level) (and to the global level set through
+/// [`control`](crate::control)).
+pub trait ColorBackend: Send + Sync {
+    /// Writes whatever switches the output to `style`.
+    fn write_start(&self, style: Style, f: &mut dyn fmt::Write) -> fmt::Result;
+
+    /// Writes whatever resets the output after text written in `style`.
+    fn write_end(&self, style: Style, f: &mut dyn fmt::Write) -> fmt::Result;
+
+    /// Writes whatever switches the output from text written in `from` to text written in `to`. Either
+    /// style may be plain here, and they are never equal.
+    ///
+    /// Defaults to ending `from` and starting `to`; backends able to change part of a style in place
+    /// override it to produce less output.
+    fn write_transition(&self, from: Style, to: Style, f: &mut dyn fmt::Write) -> fmt::Result {
+        if !from.is_plain() {
+            self.write_end(from, f)?;
+        }
+        if !to.is_plain() {
+            self.write_start(to, f)?;
+        }
+        Ok(())
+    }
+
+    /// Returns the richest colors the backend can encode. Defaults to [`ColorLevel::TrueColor`].
+    fn color_level(&self) -> ColorLevel {
+        ColorLevel::TrueColor
+    }
+
+    /// Returns `true` if the backend can encode 24-bit colors.
+    fn supports_truecolor(&self) -> bool {
+        self.color_level() == ColorLevel::TrueColor
+    }
+
+    /// Returns `true` if the backend can color underlines independently of the text.
+    /// Defaults to `false`.
+    fn supports_underline_color(&self) -> bool {
+        false
+    }
+}
+
+/// The default backend, emitting ANSI SGR escape sequences.
+#[derive(Debug, Clone, Copy, Default)]
+pub struct Ansi;
+
+impl ColorBackend for Ansi {
+    fn write_start(&self, style: Style, f: &mut dyn fmt::Write) -> fmt::Result {
+        style.write_prefix(f)
+    }
+
+    fn write_end(&self, _style: Style, f: &mut dyn fmt::Write) -> fmt::Result {
+        f.write_str("\x1b[0m")
+    }
+
+    /// Writes the shortest SGR sequence for the change, e.g. only `ESC [ 22 m` to turn bold off,
+    /// falling back to a reset followed by the new style when that is shorter.
+    fn write_transition(&self, from: Style, to: Style, f: &mut dyn fmt::Write) -> fmt::Result {
+        from.write_sgr_transition(to, f)
+    }
+
+    fn supports_underline_color(&self) -> bool {
+        true
+    }
+}
+
+static BACKEND: RwLock<&'static dyn ColorBackend> = RwLock::new(&Ansi);
+
+/// Installs `backend` for all subsequent rendering.
+///
+/// Backends are usually `static` items; one built at runtime can be installed with
+/// [`Box::leak`].
+pub fn set_backend(backend: &'static dyn ColorBackend) {
+    *BACKEND
+        .write()
+        .unwrap_or_else(|poisoned| poisoned.into_inner()) = backend;
+}
+
+/// Returns the installed backend.
+pub fn backend() -> &'static dyn ColorBackend {
+    *BACKEND
+        .read()
+        .unwrap_or_else(|poisoned| poisoned.into_inner())
+}
@@ -0,0 +1,205 @@
+//! # Background Detection
+//!
+//! Colors that read well on a dark terminal can be unreadable on a light one. [`Background`] tells the
+//! two apart so themes can pick suitable palettes.
+//!
+//! [`Background::detect`] inspects the `COLORFGBG` variable set by rxvt, Konsole and others, and never
+//! touches the terminal. With the `background-query` feature, [`query`] asks the terminal itself for its
+//! background color (OSC 11), which works in most modern emulators.
+//!
+//! ```rust
+//! use bytescolor::background::Background;
+//! use bytescolor::Color;
+//!
+//! let accent = match Background::detect() {
+//!     Background::Light => Color::Blue,
+//!     Background::Dark | Background::Unknown => Color::BrightCyan,
+//! };
+//! ```
+//!
+//! This module is available with the `detect` feature, enabled by default.
+
+/// The luminance class of the terminal background.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash, Default)]
+pub enum Background {
+    Light,
+    Dark,
+    /// The background could not be determined.
+    #[default]
+    Unknown,
+}
+
+impl Background {
+    /// Detects the background from the `COLORFGBG` environment variable, see
+    /// [`from_colorfgbg`](Background::from_colorfgbg).
+    pub fn detect() -> Self {
+        match std::env::var("COLORFGBG") {
+            Ok(value) => Background::from_colorfgbg(&value),
+            Err(_) => Background::Unknown,
+        }
+    }
+
+    /// Classifies a `COLORFGBG` value such as `"15;0"` or `"0;default;15"`: the last field is the
+    /// palette index of the background.
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// use bytescolor::background::Background;
+    ///
+    /// assert_eq!(Background::from_colorfgbg("15;0"), Background::Dark);
+    /// assert_eq!(Background::from_colorfgbg("0;default;15"), Background::Light);
+    /// assert_eq!(Background::from_colorfgbg("default;default"), Background::Unknown);
+    /// ```
+    pub fn from_colorfgbg(value: &str) -> Self {
+        match value
+            .rsplit(';')
+            .next()
+            .and_then(|bg| bg.trim().parse::<u8>().ok())
+        {
+            Some(0..=6 | 8) => Background::Dark,
+            Some(7 | 9..=15) => Background::Light,
+            _ => Background::Unknown,
+        }
+    }
+
+    /// Classifies a background color by its relative luminance.
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// use bytescolor::background::Background;
+    ///
+    /// assert_eq!(Background::from_rgb((0x28, 0x2c, 0x34)), Background::Dark);
+    /// assert_eq!(Background::from_rgb((0xfd, 0xf6, 0xe3)), Background::Light);
+    /// ```
+    pub fn from_rgb((r, g, b): (u8, u8, u8)) -> Self {
+        let luminance = (0.2126 * r as f64 + 0.7152 * g as f64 + 0.0722 * b as f64) / 255.0;
+        if luminance > 0.5 {
+            Background::Light
+        } else {
+            Background::Dark
+        }
+    }
+
+    /// Returns `true` for a light background.
+    pub fn is_light(&self) -> bool {
+        *self == Background::Light
+    }
+
+    /// Returns `true` for a dark background.
+    pub fn is_dark(&self) -> bool {
+        *self == Background::Dark
+    }
+}
+
+/// Asks the terminal for its background color (OSC 11) and classifies it, falling back to
+/// [`Background::detect`] when the terminal does not answer within `timeout`.
+///
+/// The query is written to and read from the controlling terminal (`/dev/tty`), which is put in raw
+/// mode for the duration of the call, so it works even when standard streams are redirected. A device
+/// attributes request is sent after the query: every terminal answers it, so a terminal that ignores
+/// OSC 11 is recognized without waiting for the full timeout.
+///
+/// On platforms other than Unix the terminal is not queried: this is [`Background::detect`].
+///
+/// ```rust,no_run
+/// use std::time::Duration;
+/// use bytescolor::background;
+///
+/// let background = background::query(Duration::from_millis(100));
+/// ```
+#[cfg(feature = "background-query")]
+pub fn query(timeout: std::time::Duration) -> Background {
+    #[cfg(unix)]
+    if let Some(rgb) = tty::query_rgb(timeout) {
+        return Background::from_rgb(rgb);
+    }
+    #[cfg(not(unix))]
+    let _ = timeout;
+    Background::detect()
+}
+
+#[cfg(all(unix, feature = "background-query"))]
+mod tty {
+    use std::fs::{File, OpenOptions};
+    use std::io::{Read, Write};
+    use std::os::unix::io::AsRawFd;
+    use std::time::{Duration, Instant};
+
+    pub(super) fn query_rgb(timeout: Duration) -> Option<(u8, u8, u8)> {
+        let tty = OpenOptions::new()
+            .read(true)
+            .write(true)
+            .open("/dev/tty")
+            .ok()?;
+        let fd = tty.as_raw_fd();
+        // SAFETY: `termios` is plain data, and `fd` is a valid descriptor owned by `tty`.
+        let mut original: libc::termios = unsafe { std::mem::zeroed() };
+        if unsafe { libc::tcgetattr(fd, &mut original) } != 0 {
+            return None;
+        }
+        let mut raw = original;
+        raw.c_lflag &= !(libc::ICANON | libc::ECHO);
+        raw.c_cc[libc::VMIN] = 0;
+        raw.c_cc[libc::VTIME] = 0;
+        if unsafe { libc::tcsetattr(fd, libc::TCSANOW, &raw) } != 0 {
+            return None;
+        }
+        let response = exchange(&tty, timeout);
+        unsafe { libc::tcsetattr(fd, libc::TCSANOW, &original) };
+        parse_reply(&response?)
+    }
+
+    /// Sends the OSC 11 query followed by a primary device attributes request, and reads until the
+    /// answer to the latter (`ESC [ ? ... c`) arrives.
+    fn exchange(mut tty: &File, timeout: Duration) -> Option<Vec<u8>> {
+        tty.write_all(b"\x1b]11;?\x1b\\\x1b[c").ok()?;
+        tty.flush().ok()?;
+        let deadline = Instant::now() + timeout;
+        let mut response = Vec::new();
+        let mut buf = [0u8; 64];
+        while !answered(&response) {
+            let remaining = deadline.checked_duration_since(Instant::now())?;
+            let mut pollfd = libc::pollfd {
+                fd: tty.as_raw_fd(),
+                events: libc::POLLIN,
+                revents: 0,
+            };
+            let millis = remaining.as_millis().min(libc::c_int::MAX as u128) as libc::c_int;
+            // SAFETY: `pollfd` points to a single valid entry.
+            if unsafe { libc::poll(&mut pollfd, 1, millis) } <= 0 {
+                return None;
+            }
+            let n = tty.read(&mut buf).ok()?;
+            if n == 0 || response.len() > 1024 {
+                return None;
+            }
+            response.extend_from_slice(&buf[..n]);
+        }
+        Some(response)
+    }
+
+    fn answered(response: &[u8]) -> bool {
+        response
+            .windows(3)
+            .position(|w| w == b"\x1b[?")
+            .is_some_and(|start| response[start..].contains(&b'c'))
+    }
+
+    /// Extracts the color from a reply such as `ESC ] 11 ; rgb:RRRR/GGGG/BBBB ESC \`.
+    fn parse_reply(response: &[u8]) -> Option<(u8, u8, u8)> {
+        let text = String::from_utf8_lossy(response);
+        let start = text.find("]11;rgb:")? + "]11;rgb:".len();
+        let end = text[start..].find(['\x07', '\x1b'])? + start;
+        let mut channels = text[start..end].split('/').map(|hex| {
+            if hex.is_empty() || hex.len() > 4 {
+                return None;
+            }
+            let value = u32::from_str_radix(hex, 16).ok()?;
+            let max = (1u32 << (4 * hex.len())) - 1;
+            Some((value * 255 / max) as u8)
+        });
+        Some((channels.next()??, channels.next()??, channels.next()??))
+    }
+}
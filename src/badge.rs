@@ -0,0 +1,66 @@
+//! # Badges
+//!
+//! [`Badge`] renders text as a padded, bold label on a colored background, the ` PASS ` and ` FAIL `
+//! chips of test runners and CI tools. The foreground is checked against the background and replaced with
+//! black or white when the two would be hard to tell apart, so any background gives a readable badge.
+//!
+//! ```rust
+//! use bytescolor::badge::Badge;
+//! use bytescolor::Color;
+//!
+//! println!("{} parser::tokens", "PASS".badge(Color::White, Color::Green)); // Black on green
+//! println!("{} parser::errors", "FAIL".badge(Color::White, Color::Red)); // White on red
+//! ```
+//!
+//! This module is available with the `widgets` feature, enabled by default.
+
+use crate::style::MIN_CONTRAST;
+use crate::{Color, Style, StyledString};
+
+/// Extension trait rendering text as a badge.
+pub trait Badge {
+    /// Renders the text with a space on each side, in bold `fg` on `bg`. When `fg` contrasts too
+    /// little with `bg` (see [`Color::contrast_ratio`]), black or bright white is used instead,
+    /// whichever contrasts more.
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// # if cfg!(feature = "no-color-build") { return; }
+    /// use bytescolor::badge::Badge;
+    /// use bytescolor::Color;
+    ///
+    /// let badge = "PASS".badge(Color::White, Color::Green);
+    /// assert_eq!(badge, " PASS ");
+    /// assert_eq!(badge.as_str(), "\x1b[1;30;42m PASS \x1b[0m");
+    /// assert_eq!("FAIL".badge(Color::White, Color::Red).as_str(), "\x1b[1;37;41m FAIL \x1b[0m");
+    /// ```
+    fn badge(&self, fg: Color, bg: Color) -> StyledString;
+}
+
+impl Badge for str {
+    fn badge(&self, fg: Color, bg: Color) -> StyledString {
+        Style::new()
+            .fg(readable(fg, bg))
+            .bg(bg)
+            .bold()
+            .paint(format_args!(" {} ", self))
+    }
+}
+
+impl Badge for [u8] {
+    fn badge(&self, fg: Color, bg: Color) -> StyledString {
+        String::from_utf8_lossy(self).badge(fg, bg)
+    }
+}
+
+/// Returns `fg` if it is readable on `bg`, or else black or bright white, whichever contrasts more.
+fn readable(fg: Color, bg: Color) -> Color {
+    if fg.contrast_ratio(bg) >= MIN_CONTRAST {
+        return fg;
+    }
+    [Color::Black, Color::BrightWhite]
+        .into_iter()
+        .max_by(|a, b| a.contrast_ratio(bg).total_cmp(&b.contrast_ratio(bg)))
+        .expect("two candidates")
+}
@@ -0,0 +1,208 @@
+//! # Banners
+//!
+//! Large-letter text for eye-catching CLI headers, drawn with block characters from a small built-in
+//! FIGlet-like font (upper-case letters, digits and common punctuation, five rows high). Banners can be
+//! colored with a single [`Style`] or a horizontal [`Gradient`].
+//!
+//! ```rust
+//! use bytescolor::banner::{banner, Banner};
+//! use bytescolor::gradient::Gradient;
+//!
+//! println!("{}", banner("hello"));
+//! println!("{}", Banner::new("v1.0").gradient(Gradient::heatmap()));
+//! ```
+//!
+//! This module is available with the `widgets` feature, enabled by default.
+
+use std::fmt;
+
+use crate::gradient::Gradient;
+use crate::{Color, Style};
+
+const HEIGHT: usize = 5;
+
+/// Returns the glyph for `c`, with `#` marking filled cells. Letters are case-insensitive and
+/// unsupported characters fall back to `?`.
+fn glyph(c: char) -> [&'static str; HEIGHT] {
+    match c.to_ascii_uppercase() {
+        'A' => [" ### ", "#   #", "#####", "#   #", "#   #"],
+        'B' => ["#### ", "#   #", "#### ", "#   #", "#### "],
+        'C' => [" ####", "#    ", "#    ", "#    ", " ####"],
+        'D' => ["#### ", "#   #", "#   #", "#   #", "#### "],
+        'E' => ["#####", "#    ", "#### ", "#    ", "#####"],
+        'F' => ["#####", "#    ", "#### ", "#    ", "#    "],
+        'G' => [" ####", "#    ", "#  ##", "#   #", " ####"],
+        'H' => ["#   #", "#   #", "#####", "#   #", "#   #"],
+        'I' => ["###", " # ", " # ", " # ", "###"],
+        'J' => ["  ###", "   # ", "   # ", "#  # ", " ##  "],
+        'K' => ["#   #", "#  # ", "###  ", "#  # ", "#   #"],
+        'L' => ["#    ", "#    ", "#    ", "#    ", "#####"],
+        'M' => ["#   #", "## ##", "# # #", "#   #", "#   #"],
+        'N' => ["#   #", "##  #", "# # #", "#  ##", "#   #"],
+        'O' => [" ### ", "#   #", "#   #", "#   #", " ### "],
+        'P' => ["#### ", "#   #", "#### ", "#    ", "#    "],
+        'Q' => [" ### ", "#   #", "# # #", "#  # ", " ## #"],
+        'R' => ["#### ", "#   #", "#### ", "#  # ", "#   #"],
+        'S' => [" ####", "#    ", " ### ", "    #", "#### "],
+        'T' => ["#####", "  #  ", "  #  ", "  #  ", "  #  "],
+        'U' => ["#   #", "#   #", "#   #", "#   #", " ### "],
+        'V' => ["#   #", "#   #", "#   #", " # # ", "  #  "],
+        'W' => ["#   #", "#   #", "# # #", "## ##", "#   #"],
+        'X' => ["#   #", " # # ", "  #  ", " # # ", "#   #"],
+        'Y' => ["#   #", " # # ", "  #  ", "  #  ", "  #  "],
+        'Z' => ["#####", "   # ", "  #  ", " #   ", "#####"],
+        '0' => [" ### ", "#  ##", "# # #", "##  #", " ### "],
+        '1' => [" # ", "## ", " # ", " # ", "###"],
+        '2' => [" ### ", "#   #", "  ## ", " #   ", "#####"],
+        '3' => ["#### ", "    #", " ### ", "    #", "#### "],
+        '4' => ["#   #", "#   #", "#####", "    #", "    #"],
+        '5' => ["#####", "#    ", "#### ", "    #", "#### "],
+        '6' => [" ### ", "#    ", "#### ", "#   #", " ### "],
+        '7' => ["#####", "    #", "   # ", "  #  ", "  #  "],
+        '8' => [" ### ", "#   #", " ### ", "#   #", " ### "],
+        '9' => [" ### ", "#   #", " ####", "    #", " ### "],
+        ' ' => ["   ", "   ", "   ", "   ", "   "],
+        '!' => ["#", "#", "#", " ", "#"],
+        '.' => [" ", " ", " ", " ", "#"],
+        ',' => ["  ", "  ", "  ", " #", "# "],
+        ':' => [" ", "#", " ", "#", " "],
+        '-' => ["    ", "    ", "####", "    ", "    "],
+        '_' => ["     ", "     ", "     ", "     ", "#####"],
+        '/' => ["    #", "   # ", "  #  ", " #   ", "#    "],
+        '+' => ["     ", "  #  ", "#####", "  #  ", "     "],
+        '=' => ["    ", "####", "    ", "####", "    "],
+        '\'' => ["#", "#", " ", " ", " "],
+        '(' => [" #", "# ", "# ", "# ", " #"],
+        ')' => ["# ", " #", " #", " #", "# "],
+        _ => [" ### ", "#   #", "  ## ", "     ", "  #  "],
+    }
+}
+
+/// How a banner is colored.
+#[derive(Debug, Clone, PartialEq, Eq, Hash)]
+enum Fill {
+    Solid(Style),
+    Gradient(Gradient),
+}
+
+/// Large-letter text rendered with the built-in block font.
+///
+/// # Examples
+///
+/// ```rust
+/// use bytescolor::banner::Banner;
+/// use bytescolor::Style;
+///
+/// let art = Banner::new("hi").style(Style::new()).render();
+/// assert_eq!(art, "█   █ ███\n█   █  █ \n█████  █ \n█   █  █ \n█   █ ███");
+/// ```
+#[derive(Debug, Clone, PartialEq, Eq, Hash)]
+pub struct Banner {
+    text: String,
+    fill: Fill,
+    pixel: char,
+}
+
+impl Banner {
+    /// Creates a bold cyan banner for `text`. Line breaks in `text` start a new row of letters.
+    pub fn new(text: impl Into<String>) -> Self {
+        Banner {
+            text: text.into(),
+            fill: Fill::Solid(Style::new().fg(Color::Cyan).bold()),
+            pixel: '█',
+        }
+    }
+
+    /// Colors the whole banner with `style`.
+    pub fn style(mut self, style: Style) -> Self {
+        self.fill = Fill::Solid(style);
+        self
+    }
+
+    /// Colors the banner along `gradient`, from its left edge to its right edge.
+    pub fn gradient(mut self, gradient: Gradient) -> Self {
+        self.fill = Fill::Gradient(gradient);
+        self
+    }
+
+    /// Sets the character drawn for filled cells (`█` by default).
+    pub fn pixel(mut self, pixel: char) -> Self {
+        self.pixel = pixel;
+        self
+    }
+
+    /// Renders the banner.
+    pub fn render(&self) -> String {
+        let blocks: Vec<String> = self.text.split('\n').map(|line| self.block(line)).collect();
+        blocks.join("\n\n")
+    }
+
+    /// Renders a single line of text as `HEIGHT` rows.
+    fn block(&self, line: &str) -> String {
+        let mut rows: [String; HEIGHT] = Default::default();
+        for (index, c) in line.chars().enumerate() {
+            for (row, part) in rows.iter_mut().zip(glyph(c)) {
+                if index > 0 {
+                    row.push(' ');
+                }
+                row.push_str(part);
+            }
+        }
+        let width = rows[0].chars().count();
+        rows.iter()
+            .map(|row| match &self.fill {
+                Fill::Solid(style) => self.paint_row(row, |_| *style),
+                Fill::Gradient(gradient) => self.paint_row(row, |column| {
+                    let t = if width > 1 {
+                        column as f64 / (width - 1) as f64
+                    } else {
+                        0.0
+                    };
+                    Style::new().fg(gradient.at(t))
+                }),
+            })
+            .collect::<Vec<_>>()
+            .join("\n")
+    }
+
+    /// Replaces `#` cells with the pixel character, styling runs of filled cells that share a style.
+    fn paint_row(&self, row: &str, style_at: impl Fn(usize) -> Style) -> String {
+        let mut out = String::new();
+        let mut run = String::new();
+        let mut run_style: Option<Style> = None;
+        for (column, cell) in row.chars().enumerate() {
+            if cell == '#' {
+                let style = style_at(column);
+                if run_style != Some(style) {
+                    if let Some(previous) = run_style {
+                        out.push_str(&previous.wrap(&run));
+                        run.clear();
+                    }
+                    run_style = Some(style);
+                }
+                run.push(self.pixel);
+            } else {
+                if let Some(previous) = run_style.take() {
+                    out.push_str(&previous.wrap(&run));
+                    run.clear();
+                }
+                out.push(' ');
+            }
+        }
+        if let Some(previous) = run_style {
+            out.push_str(&previous.wrap(&run));
+        }
+        out
+    }
+}
+
+impl fmt::Display for Banner {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.write_str(&self.render())
+    }
+}
+
+/// Renders `text` as a bold cyan banner.
+pub fn banner(text: &str) -> String {
+    Banner::new(text).render()
+}
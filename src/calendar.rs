@@ -0,0 +1,241 @@
+//! # Calendar Heatmaps
+//!
+//! [`Calendar`] draws daily values as a contribution calendar like the one on GitHub profiles: a
+//! column per week, a row per weekday, each day a cell whose background is picked from a palette by
+//! how its value compares to the busiest day, with month names above the weeks they start in.
+//!
+//! ```text
+//!     Apr     May
+//!     ░░▓▓██░░▒▒░░▓▓██
+//! Mon ▒▒░░▓▓██░░▒▒░░▓▓
+//!     ██▒▒░░▓▓░░██▒▒░░
+//! ```
+//!
+//! ```rust
+//! use std::collections::BTreeMap;
+//! use bytescolor::calendar::Calendar;
+//!
+//! let mut commits = BTreeMap::new();
+//! commits.insert((2024, 5, 1), 3.0);
+//! commits.insert((2024, 5, 2), 8.0);
+//! commits.insert((2024, 6, 14), 1.0);
+//! println!("{}", Calendar::new().days(commits));
+//! ```
+//!
+//! Cells are colored with background colors only, so they are invisible when escape sequences are
+//! disabled.
+//!
+//! This module is available with the `widgets` feature, enabled by default.
+
+use std::collections::BTreeMap;
+use std::fmt;
+
+use crate::time::{civil_from_days, days_from_civil};
+use crate::{Color, Style, StyledText};
+
+const LABEL: Style = Style::new().dim();
+
+const MONTHS: [&str; 12] = [
+    "Jan", "Feb", "Mar", "Apr", "May", "Jun", "Jul", "Aug", "Sep", "Oct", "Nov", "Dec",
+];
+
+/// A calendar of daily values, dates given as `(year, month, day)`.
+///
+/// # Examples
+///
+/// ```rust
+/// # if cfg!(feature = "no-color-build") { return; }
+/// use bytescolor::calendar::Calendar;
+/// use bytescolor::ansi;
+///
+/// // 2024-04-29 is a Monday, 2024-05-04 a Saturday.
+/// let calendar: Calendar = [((2024, 4, 29), 1.0), ((2024, 5, 3), 4.0), ((2024, 5, 4), 2.0)]
+///     .into_iter()
+///     .collect();
+/// let lines: Vec<String> = calendar
+///     .render()
+///     .lines()
+///     .map(|line| ansi::strip(line).into_owned())
+///     .collect();
+/// assert_eq!(lines[0], "    Apr");
+/// assert_eq!(lines[2], "Mon   ");
+/// assert_eq!(lines[6], "Fri   ");
+/// assert_eq!(lines.len(), 9);
+/// ```
+#[derive(Debug, Clone, PartialEq)]
+pub struct Calendar {
+    days: BTreeMap<i64, f64>,
+    palette: Vec<Color>,
+    legend: bool,
+}
+
+impl Default for Calendar {
+    fn default() -> Self {
+        Calendar {
+            days: BTreeMap::new(),
+            palette: vec![
+                Color::Fixed(236),
+                Color::Rgb(14, 68, 41),
+                Color::Rgb(0, 109, 50),
+                Color::Rgb(38, 166, 65),
+                Color::Rgb(57, 211, 83),
+            ],
+            legend: true,
+        }
+    }
+}
+
+impl Calendar {
+    /// Creates an empty calendar in the green shades of GitHub, with a legend.
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// Adds `value` to the value of `date`, so events can be counted one at a time. Dates that do
+    /// not exist, such as `(2024, 2, 30)` or `(2024, 13, 1)`, are ignored.
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// use bytescolor::calendar::Calendar;
+    ///
+    /// let mut calendar = Calendar::new();
+    /// calendar.push((2024, 13, 40), 1.0);
+    /// calendar.push((2023, 2, 29), 1.0);
+    /// assert_eq!(calendar.render(), "");
+    /// ```
+    pub fn push(&mut self, date: (i32, u32, u32), value: f64) {
+        let (year, month, day) = date;
+        if !(1..=12).contains(&month) {
+            return;
+        }
+        let days = days_from_civil(i64::from(year), month, day);
+        if civil_from_days(days) != (i64::from(year), month, day) {
+            return;
+        }
+        *self.days.entry(days).or_insert(0.0) += value;
+    }
+
+    /// Adds the value of a single day, see [`push`](Calendar::push).
+    pub fn day(mut self, date: (i32, u32, u32), value: f64) -> Self {
+        self.push(date, value);
+        self
+    }
+
+    /// Adds the values of `days`, such as a map from dates to values, ignoring dates that do not
+    /// exist.
+    pub fn days(mut self, days: impl IntoIterator<Item = ((i32, u32, u32), f64)>) -> Self {
+        self.extend(days);
+        self
+    }
+
+    /// Sets the background colors of the cells: the first for days without a positive value, the
+    /// others for increasing values, the last one for the busiest day. An empty palette is ignored.
+    pub fn palette(mut self, palette: impl IntoIterator<Item = Color>) -> Self {
+        let palette: Vec<Color> = palette.into_iter().collect();
+        if !palette.is_empty() {
+            self.palette = palette;
+        }
+        self
+    }
+
+    /// Shows or hides the `Less … More` legend of the palette below the calendar (shown by default).
+    pub fn legend(mut self, legend: bool) -> Self {
+        self.legend = legend;
+        self
+    }
+
+    /// Renders the calendar from the week of the first day with a value to the week of the last one,
+    /// without a final newline: a line of month names, a line per weekday from Sunday and the legend.
+    /// An empty calendar renders as an empty string.
+    pub fn render(&self) -> String {
+        let (first, last) = match (self.days.keys().next(), self.days.keys().next_back()) {
+            (Some(&first), Some(&last)) => (first, last),
+            _ => return String::new(),
+        };
+        let start = first - weekday(first);
+        let weeks = (last - start) / 7 + 1;
+        let max = self.days.values().copied().fold(0.0, f64::max);
+
+        let mut months = String::from("    ");
+        for week in 0..weeks {
+            let column = 4 + 2 * week as usize;
+            let month_start = (0..7)
+                .map(|offset| start + 7 * week + offset)
+                .filter(|&day| (first..=last).contains(&day))
+                .find(|&day| week == 0 || civil_from_days(day).2 == 1);
+            if let Some(day) = month_start {
+                if months.chars().count() < column || column == 4 {
+                    let padding = column.saturating_sub(months.chars().count());
+                    months.push_str(&" ".repeat(padding));
+                    months.push_str(MONTHS[civil_from_days(day).1 as usize - 1]);
+                    months.push(' ');
+                }
+            }
+        }
+        let mut lines = vec![LABEL.wrap(months.trim_end())];
+        for row in 0..7 {
+            let label = match row {
+                1 => "Mon ",
+                3 => "Wed ",
+                5 => "Fri ",
+                _ => "    ",
+            };
+            let mut line = StyledText::styled(label, LABEL);
+            for week in 0..weeks {
+                let day = start + 7 * week + row;
+                if (first..=last).contains(&day) {
+                    let value = self.days.get(&day).copied().unwrap_or(0.0);
+                    line.push("  ", Style::new().bg(self.color(value, max)));
+                } else {
+                    line.push("  ", Style::new());
+                }
+            }
+            lines.push(line.render().trim_end_matches(' ').to_string());
+        }
+        if self.legend {
+            let mut legend = StyledText::styled("    Less ", LABEL);
+            for &color in &self.palette {
+                legend.push("  ", Style::new().bg(color));
+            }
+            legend.push(" More", LABEL);
+            lines.push(legend.render());
+        }
+        lines.join("\n")
+    }
+
+    /// Returns the color of a day with `value`, the busiest day having `max`.
+    fn color(&self, value: f64, max: f64) -> Color {
+        let levels = self.palette.len() - 1;
+        if value <= 0.0 || max <= 0.0 || levels == 0 {
+            return self.palette[0];
+        }
+        let level = (value / max * levels as f64).ceil() as usize;
+        self.palette[level.clamp(1, levels)]
+    }
+}
+
+impl Extend<((i32, u32, u32), f64)> for Calendar {
+    fn extend<I: IntoIterator<Item = ((i32, u32, u32), f64)>>(&mut self, days: I) {
+        for (date, value) in days {
+            self.push(date, value);
+        }
+    }
+}
+
+impl FromIterator<((i32, u32, u32), f64)> for Calendar {
+    fn from_iter<I: IntoIterator<Item = ((i32, u32, u32), f64)>>(days: I) -> Self {
+        Calendar::new().days(days)
+    }
+}
+
+impl fmt::Display for Calendar {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.write_str(&self.render())
+    }
+}
+
+/// Returns the day of the week of a day since 1970-01-01, a Thursday, counting from Sunday.
+fn weekday(day: i64) -> i64 {
+    (day + 4).rem_euclid(7)
+}
@@ -0,0 +1,118 @@
+//! # Charts
+//!
+//! Instant terminal visualizations for numeric slices: [`Chart::sparkline`] squeezes a series into a single
+//! line of Unicode block characters, and [`Chart::bar_chart`] draws one horizontal bar per value. Both
+//! color each value along a heatmap [`Gradient`], low values blue and high values red.
+//!
+//! ```rust
+//! use bytescolor::chart::Chart;
+//!
+//! let latencies = [12.0, 15.5, 11.2, 40.1, 18.3, 13.9];
+//! println!("latency {}", latencies.sparkline());
+//! println!("{}", latencies.bar_chart(20));
+//! ```
+//!
+//! This module is available with the `widgets` feature, enabled by default.
+
+use crate::gradient::Gradient;
+use crate::Style;
+
+const LEVELS: [char; 8] = ['▁', '▂', '▃', '▄', '▅', '▆', '▇', '█'];
+const EIGHTHS: [&str; 8] = ["", "▏", "▎", "▍", "▌", "▋", "▊", "▉"];
+
+/// Extension trait rendering numeric slices as charts.
+pub trait Chart {
+    /// Renders the values as a one-line sparkline, scaled between the minimum and the maximum.
+    ///
+    /// Non-finite values (`NaN`, infinities) are rendered as blanks.
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// use bytescolor::{ansi, chart::Chart};
+    ///
+    /// let line = [0.0, 1.0, 2.0, 3.0, 4.0, 5.0, 6.0, 7.0].sparkline();
+    /// assert_eq!(ansi::strip(&line), "▁▂▃▄▅▆▇█");
+    /// ```
+    fn sparkline(&self) -> String;
+
+    /// Renders one horizontal bar per value, the largest value spanning `width` cells, followed by
+    /// the value itself. Negative and non-finite values get an empty bar.
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// use bytescolor::{ansi, chart::Chart};
+    ///
+    /// let chart = [4.0, 2.0, 1.0].bar_chart(4);
+    /// assert_eq!(ansi::strip(&chart), "████ 4\n██   2\n█    1");
+    ///
+    /// // An empty bar is left unstyled.
+    /// assert_eq!([0.0].bar_chart(2), "   0");
+    /// ```
+    fn bar_chart(&self, width: usize) -> String;
+}
+
+impl Chart for [f64] {
+    fn sparkline(&self) -> String {
+        let (min, max) = bounds(self);
+        let gradient = Gradient::heatmap();
+        let mut out = String::new();
+        for &value in self {
+            if !value.is_finite() {
+                out.push(' ');
+                continue;
+            }
+            let t = normalize(value, min, max);
+            let level = ((t * (LEVELS.len() - 1) as f64).round() as usize).min(LEVELS.len() - 1);
+            out.push_str(&Style::new().fg(gradient.at(t)).wrap(LEVELS[level]));
+        }
+        out
+    }
+
+    fn bar_chart(&self, width: usize) -> String {
+        let (_, max) = bounds(self);
+        let gradient = Gradient::heatmap();
+        let mut out = String::new();
+        for (index, &value) in self.iter().enumerate() {
+            if index > 0 {
+                out.push('\n');
+            }
+            let t = if value.is_finite() && max > 0.0 {
+                (value / max).clamp(0.0, 1.0)
+            } else {
+                0.0
+            };
+            let eighths = (t * width as f64 * 8.0).round() as usize;
+            let mut bar = "█".repeat(eighths / 8);
+            bar.push_str(EIGHTHS[eighths % 8]);
+            let cells = eighths / 8 + usize::from(eighths % 8 > 0);
+            if !bar.is_empty() {
+                out.push_str(&Style::new().fg(gradient.at(t)).wrap(bar));
+            }
+            out.extend(std::iter::repeat(' ').take(width.saturating_sub(cells)));
+            out.push(' ');
+            out.push_str(&value.to_string());
+        }
+        out
+    }
+}
+
+/// Returns the minimum and maximum of the finite values, or `(0.0, 0.0)` if there are none.
+fn bounds(values: &[f64]) -> (f64, f64) {
+    let mut finite = values.iter().copied().filter(|value| value.is_finite());
+    match finite.next() {
+        Some(first) => finite.fold((first, first), |(min, max), value| {
+            (min.min(value), max.max(value))
+        }),
+        None => (0.0, 0.0),
+    }
+}
+
+fn normalize(value: f64, min: f64, max: f64) -> f64 {
+    if max > min {
+        (value - min) / (max - min)
+    } else {
+        0.5
+    }
+}
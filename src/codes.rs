@@ -0,0 +1,266 @@
+//! # Escape Codes
+//!
+//! The raw SGR escape sequences behind the crate's styles, as `const` items for performance-sensitive
+//! code that wants to embed them at compile time rather than build strings at runtime.
+//!
+//! `concat!` only accepts literals, so the [`sgr!`](crate::sgr) macro is provided to splice codes into
+//! string literals; the `const fn` builders cover palette and RGB colors in `const` and `static` items.
+//! Unlike [`Style`](crate::Style), these codes are raw: they ignore the [`control`](crate::control)
+//! settings.
+//!
+//! ```rust
+//! use bytescolor::{codes, sgr};
+//!
+//! const ERROR: &str = concat!(sgr!(1, 31), "error", sgr!(0), ": ");
+//! assert_eq!(ERROR, "\x1b[1;31merror\x1b[0m: ");
+//!
+//! static AMBER: codes::Code = codes::fg_rgb(255, 191, 0);
+//! println!("{}{}careful{}", codes::BOLD, AMBER, codes::RESET);
+//! ```
+
+use std::fmt;
+
+/// Builds an SGR escape sequence literal from its numeric parameters, for use in `concat!`.
+///
+/// # Examples
+///
+/// ```rust
+/// use bytescolor::sgr;
+///
+/// assert_eq!(sgr!(38, 5, 208), "\x1b[38;5;208m");
+/// assert_eq!(concat!(sgr!(4), "link", sgr!(24)), "\x1b[4mlink\x1b[24m");
+/// ```
+#[macro_export]
+macro_rules! sgr {
+    ($first:literal $(, $rest:literal)* $(,)?) => {
+        concat!("\x1b[", $first $(, ";", $rest)*, "m")
+    };
+}
+
+/// Resets all colors and attributes.
+pub const RESET: &str = "\x1b[0m";
+
+/// Enables the bold attribute.
+pub const BOLD: &str = "\x1b[1m";
+
+/// Enables the dim attribute.
+pub const DIM: &str = "\x1b[2m";
+
+/// Enables the italic attribute.
+pub const ITALIC: &str = "\x1b[3m";
+
+/// Enables the underline attribute.
+pub const UNDERLINE: &str = "\x1b[4m";
+
+/// Enables the blink attribute.
+pub const BLINK: &str = "\x1b[5m";
+
+/// Enables the reverse video attribute.
+pub const REVERSE: &str = "\x1b[7m";
+
+/// Enables the hidden attribute.
+pub const HIDDEN: &str = "\x1b[8m";
+
+/// Enables the strikethrough attribute.
+pub const STRIKETHROUGH: &str = "\x1b[9m";
+
+/// Sets the foreground to black.
+pub const BLACK: &str = "\x1b[30m";
+
+/// Sets the foreground to red.
+pub const RED: &str = "\x1b[31m";
+
+/// Sets the foreground to green.
+pub const GREEN: &str = "\x1b[32m";
+
+/// Sets the foreground to yellow.
+pub const YELLOW: &str = "\x1b[33m";
+
+/// Sets the foreground to blue.
+pub const BLUE: &str = "\x1b[34m";
+
+/// Sets the foreground to magenta.
+pub const MAGENTA: &str = "\x1b[35m";
+
+/// Sets the foreground to cyan.
+pub const CYAN: &str = "\x1b[36m";
+
+/// Sets the foreground to white.
+pub const WHITE: &str = "\x1b[37m";
+
+/// Sets the foreground to bright black, the gray of most terminals.
+pub const BRIGHT_BLACK: &str = "\x1b[90m";
+
+/// Sets the foreground to bright red.
+pub const BRIGHT_RED: &str = "\x1b[91m";
+
+/// Sets the foreground to bright green.
+pub const BRIGHT_GREEN: &str = "\x1b[92m";
+
+/// Sets the foreground to bright yellow.
+pub const BRIGHT_YELLOW: &str = "\x1b[93m";
+
+/// Sets the foreground to bright blue.
+pub const BRIGHT_BLUE: &str = "\x1b[94m";
+
+/// Sets the foreground to bright magenta.
+pub const BRIGHT_MAGENTA: &str = "\x1b[95m";
+
+/// Sets the foreground to bright cyan.
+pub const BRIGHT_CYAN: &str = "\x1b[96m";
+
+/// Sets the foreground to bright white.
+pub const BRIGHT_WHITE: &str = "\x1b[97m";
+
+/// Sets the background to black.
+pub const BG_BLACK: &str = "\x1b[40m";
+
+/// Sets the background to red.
+pub const BG_RED: &str = "\x1b[41m";
+
+/// Sets the background to green.
+pub const BG_GREEN: &str = "\x1b[42m";
+
+/// Sets the background to yellow.
+pub const BG_YELLOW: &str = "\x1b[43m";
+
+/// Sets the background to blue.
+pub const BG_BLUE: &str = "\x1b[44m";
+
+/// Sets the background to magenta.
+pub const BG_MAGENTA: &str = "\x1b[45m";
+
+/// Sets the background to cyan.
+pub const BG_CYAN: &str = "\x1b[46m";
+
+/// Sets the background to white.
+pub const BG_WHITE: &str = "\x1b[47m";
+
+/// Sets the background to bright black, the gray of most terminals.
+pub const BG_BRIGHT_BLACK: &str = "\x1b[100m";
+
+/// Sets the background to bright red.
+pub const BG_BRIGHT_RED: &str = "\x1b[101m";
+
+/// Sets the background to bright green.
+pub const BG_BRIGHT_GREEN: &str = "\x1b[102m";
+
+/// Sets the background to bright yellow.
+pub const BG_BRIGHT_YELLOW: &str = "\x1b[103m";
+
+/// Sets the background to bright blue.
+pub const BG_BRIGHT_BLUE: &str = "\x1b[104m";
+
+/// Sets the background to bright magenta.
+pub const BG_BRIGHT_MAGENTA: &str = "\x1b[105m";
+
+/// Sets the background to bright cyan.
+pub const BG_BRIGHT_CYAN: &str = "\x1b[106m";
+
+/// Sets the background to bright white.
+pub const BG_BRIGHT_WHITE: &str = "\x1b[107m";
+
+/// An escape sequence built at compile time by [`fg_fixed`], [`bg_fixed`], [`fg_rgb`] or [`bg_rgb`].
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub struct Code {
+    bytes: [u8; 19],
+    len: u8,
+}
+
+impl Code {
+    const fn new(selector: u8, mode: u8) -> Self {
+        Code {
+            bytes: [0; 19],
+            len: 0,
+        }
+        .push(0x1b)
+        .push(b'[')
+        .number(selector)
+        .push(b';')
+        .number(mode)
+    }
+
+    const fn push(mut self, byte: u8) -> Self {
+        self.bytes[self.len as usize] = byte;
+        self.len += 1;
+        self
+    }
+
+    const fn number(self, n: u8) -> Self {
+        let code = if n >= 100 {
+            self.push(b'0' + n / 100)
+        } else {
+            self
+        };
+        let code = if n >= 10 {
+            code.push(b'0' + n / 10 % 10)
+        } else {
+            code
+        };
+        code.push(b'0' + n % 10)
+    }
+
+    const fn param(self, n: u8) -> Self {
+        self.push(b';').number(n)
+    }
+
+    /// Returns the escape sequence.
+    pub const fn as_str(&self) -> &str {
+        // SAFETY: only ASCII bytes are ever pushed, and `len` never exceeds the buffer.
+        unsafe {
+            std::str::from_utf8_unchecked(std::slice::from_raw_parts(
+                self.bytes.as_ptr(),
+                self.len as usize,
+            ))
+        }
+    }
+}
+
+impl fmt::Display for Code {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.write_str(self.as_str())
+    }
+}
+
+impl AsRef<str> for Code {
+    fn as_ref(&self) -> &str {
+        self.as_str()
+    }
+}
+
+/// Sets the foreground to an entry of the 256-color palette.
+///
+/// # Examples
+///
+/// ```rust
+/// use bytescolor::codes;
+///
+/// const ORANGE: codes::Code = codes::fg_fixed(208);
+/// assert_eq!(ORANGE.as_str(), "\x1b[38;5;208m");
+/// ```
+pub const fn fg_fixed(code: u8) -> Code {
+    Code::new(38, 5).param(code).push(b'm')
+}
+
+/// Sets the background to an entry of the 256-color palette.
+pub const fn bg_fixed(code: u8) -> Code {
+    Code::new(48, 5).param(code).push(b'm')
+}
+
+/// Sets the foreground to a 24-bit color.
+///
+/// # Examples
+///
+/// ```rust
+/// use bytescolor::codes;
+///
+/// assert_eq!(codes::fg_rgb(255, 191, 0).as_str(), "\x1b[38;2;255;191;0m");
+/// ```
+pub const fn fg_rgb(r: u8, g: u8, b: u8) -> Code {
+    Code::new(38, 2).param(r).param(g).param(b).push(b'm')
+}
+
+/// Sets the background to a 24-bit color.
+pub const fn bg_rgb(r: u8, g: u8, b: u8) -> Code {
+    Code::new(48, 2).param(r).param(g).param(b).push(b'm')
+}
@@ -0,0 +1,164 @@
+//! # Column Layout
+//!
+//! Lays out a list of items in as many columns as fit the terminal, the way `ls` prints file names.
+//! Items may already be styled: widths are measured on the visible content, so colored and plain items
+//! line up alike.
+//!
+//! ```rust
+//! use bytescolor::{columns, ByteColor};
+//!
+//! let files = ["Cargo.toml".to_string(), "src".blue().into_string(), "README.md".to_string()];
+//! println!("{}", columns::columns(files));
+//! ```
+//!
+//! This module is available with the `widgets` feature, enabled by default.
+
+use std::fmt::Display;
+
+use crate::ansi::visible_width;
+
+/// The order in which items fill the columns.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash, Default)]
+pub enum Direction {
+    /// Top to bottom, then left to right, like `ls`.
+    #[default]
+    Down,
+    /// Left to right, then top to bottom, like `ls -x`.
+    Across,
+}
+
+/// A configurable column layout.
+///
+/// # Examples
+///
+/// ```rust
+/// use bytescolor::columns::{Columns, Direction};
+///
+/// let items = ["one", "two", "three", "four", "five"];
+/// let down = Columns::new().width(12).render(items);
+/// assert_eq!(down, "one    four\ntwo    five\nthree");
+/// let across = Columns::new().width(12).direction(Direction::Across).render(items);
+/// assert_eq!(across, "one    two\nthree  four\nfive");
+/// ```
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub struct Columns {
+    width: Option<usize>,
+    spacing: usize,
+    direction: Direction,
+}
+
+impl Default for Columns {
+    fn default() -> Self {
+        Columns {
+            width: None,
+            spacing: 2,
+            direction: Direction::Down,
+        }
+    }
+}
+
+impl Columns {
+    /// Creates a layout filling columns downwards, two spaces apart, as wide as the terminal.
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// Sets the available width instead of detecting it.
+    ///
+    /// By default the width of the terminal is used, as reported by
+    /// [`terminal_size`](crate::terminal_size), falling back to 80.
+    pub fn width(mut self, width: usize) -> Self {
+        self.width = Some(width);
+        self
+    }
+
+    /// Sets the number of spaces between columns.
+    pub fn spacing(mut self, spacing: usize) -> Self {
+        self.spacing = spacing;
+        self
+    }
+
+    /// Sets the order in which items fill the columns.
+    pub fn direction(mut self, direction: Direction) -> Self {
+        self.direction = direction;
+        self
+    }
+
+    /// Renders `items` in columns, without trailing whitespace or a final newline.
+    ///
+    /// When even a single column is too wide, items are listed one per line.
+    pub fn render<I, T>(&self, items: I) -> String
+    where
+        I: IntoIterator<Item = T>,
+        T: Display,
+    {
+        let items: Vec<String> = items.into_iter().map(|item| item.to_string()).collect();
+        let widths: Vec<usize> = items.iter().map(|item| visible_width(item)).collect();
+        let available = self.width.unwrap_or_else(default_width);
+        let (rows, column_widths) = self.fit(&widths, available);
+        let cols = column_widths.len();
+
+        let mut out = String::new();
+        for row in 0..rows {
+            if row > 0 {
+                out.push('\n');
+            }
+            let mut pending = 0;
+            for (col, &column_width) in column_widths.iter().enumerate() {
+                let index = match self.direction {
+                    Direction::Down => col * rows + row,
+                    Direction::Across => row * cols + col,
+                };
+                let Some(item) = items.get(index) else { break };
+                out.extend(std::iter::repeat(' ').take(pending));
+                out.push_str(item);
+                pending = column_width - widths[index] + self.spacing;
+            }
+        }
+        out
+    }
+
+    /// Returns the smallest number of rows whose layout fits `available`, with the column widths.
+    fn fit(&self, widths: &[usize], available: usize) -> (usize, Vec<usize>) {
+        let count = widths.len();
+        for rows in 1..count {
+            let column_widths = self.column_widths(widths, rows);
+            let total =
+                column_widths.iter().sum::<usize>() + self.spacing * (column_widths.len() - 1);
+            if total <= available {
+                return (rows, column_widths);
+            }
+        }
+        let widest = widths.iter().copied().max().map_or(Vec::new(), |w| vec![w]);
+        (count, widest)
+    }
+
+    fn column_widths(&self, widths: &[usize], rows: usize) -> Vec<usize> {
+        let cols = (widths.len() + rows - 1) / rows;
+        (0..cols)
+            .map(|col| {
+                (0..rows)
+                    .filter_map(|row| match self.direction {
+                        Direction::Down => widths.get(col * rows + row),
+                        Direction::Across => widths.get(row * cols + col),
+                    })
+                    .copied()
+                    .max()
+                    .unwrap_or(0)
+            })
+            .collect()
+    }
+}
+
+/// Renders `items` with the default [`Columns`] settings.
+pub fn columns<I, T>(items: I) -> String
+where
+    I: IntoIterator<Item = T>,
+    T: Display,
+{
+    Columns::new().render(items)
+}
+
+fn default_width() -> usize {
+    crate::terminal_size().map_or(80, |(columns, _)| usize::from(columns))
+}
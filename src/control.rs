@@ -0,0 +1,356 @@
+//! # Color Control
+//!
+//! Global switch deciding whether the crate emits escape sequences at all. Every styled value — the
+//! [`ByteColor`](crate::ByteColor) methods, [`Style`](crate::Style)s, and the [`osc`](crate::osc) helpers — consults
+//! it before writing any escape byte.
+//!
+//! Deployments that must never emit colors can instead build the crate with the `no-color-build`
+//! feature: styling then compiles down to plain `to_string()` calls, with no runtime check at all.
+//!
+//! The default is [`ColorChoice::Always`], which keeps output identical regardless of the environment.
+//! Applications that want to honor the user's environment opt into detection with [`ColorChoice::Auto`]:
+//!
+//! ```rust
+//! use bytescolor::control::{self, ColorChoice};
+//!
+//! control::set_color_choice(ColorChoice::Auto);
+//! // Escape sequences are now only emitted when stdout is a terminal and neither
+//! // `NO_COLOR` nor `TERM=dumb` is set (or `CLICOLOR_FORCE` forces them on).
+//! # control::set_color_choice(ColorChoice::Always);
+//! ```
+//!
+//! The color choice, the color level, the [`Theme`] and the [OSC passthrough](crate::osc::Passthrough)
+//! mode together make up the global [`Config`], which
+//! is replaced as a whole with [`set_config`], so other threads never see half of an update. Code that
+//! only needs different settings for a while, such as a library embedded in a larger application or a
+//! function rendering for a file rather than the terminal, overrides them for the current thread with
+//! a scope instead of changing the globals under the feet of its host:
+//!
+//! ```rust
+//! # if cfg!(feature = "no-color-build") { return; }
+//! use bytescolor::control::{self, ColorChoice};
+//! use bytescolor::ByteColor;
+//!
+//! let plain = control::with_color_choice(ColorChoice::Never, || "saved".green().to_string());
+//! assert_eq!(plain, "saved");
+//! assert_eq!("saved".green().as_str(), "\x1b[32msaved\x1b[0m");
+//! ```
+
+use std::cell::Cell;
+use std::io::IsTerminal;
+use std::sync::{OnceLock, RwLock, RwLockReadGuard};
+
+use crate::osc::Passthrough;
+use crate::theme::{self, Theme};
+
+/// The richest kind of color a terminal supports, in increasing order.
+///
+/// Colors beyond the global level (see [`set_color_level`]) are downgraded to the closest color the
+/// terminal can display when styles are rendered.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, PartialOrd, Ord, Hash, Default)]
+pub enum ColorLevel {
+    /// No colors at all; text attributes such as bold are still emitted.
+    None,
+    /// The 16 standard ANSI colors.
+    Ansi16,
+    /// The 256-color palette.
+    Ansi256,
+    /// 24-bit RGB colors (the default).
+    #[default]
+    TrueColor,
+}
+
+/// When the crate emits escape sequences.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash, Default)]
+pub enum ColorChoice {
+    /// Always emit escape sequences (the default).
+    #[default]
+    Always,
+    /// Emit escape sequences only if the environment supports them, see [`detect`].
+    Auto,
+    /// Never emit escape sequences.
+    Never,
+}
+
+/// The settings deciding how styles are rendered.
+///
+/// # Examples
+///
+/// ```rust
+/// use bytescolor::control::{self, ColorLevel, Config};
+///
+/// control::set_config(Config {
+///     level: ColorLevel::Ansi256,
+///     ..control::config()
+/// });
+/// assert_eq!(control::color_level(), ColorLevel::Ansi256);
+/// # control::set_config(Config::default());
+/// ```
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub struct Config {
+    /// When escape sequences are emitted.
+    pub choice: ColorChoice,
+    /// The richest kind of color emitted.
+    pub level: ColorLevel,
+    /// The styles of the roles of the application, see [`theme::current`].
+    pub theme: Theme,
+    /// How OSC sequences are forwarded through terminal multiplexers, see
+    /// [`osc::set_passthrough`](crate::osc::set_passthrough).
+    pub passthrough: Passthrough,
+}
+
+const DEFAULT_CONFIG: Config = Config {
+    choice: ColorChoice::Always,
+    level: ColorLevel::TrueColor,
+    theme: theme::DEFAULT_THEME,
+    passthrough: Passthrough::Never,
+};
+
+impl Default for Config {
+    fn default() -> Self {
+        DEFAULT_CONFIG
+    }
+}
+
+static CONFIG: RwLock<Config> = RwLock::new(DEFAULT_CONFIG);
+
+thread_local! {
+    /// The configuration of the innermost override scope of the thread, if any.
+    static SCOPE: Cell<Option<Config>> = const { Cell::new(None) };
+}
+
+/// Returns the configuration in effect on the current thread: that of the innermost override scope
+/// (see [`with_config`]), or the global one.
+pub fn config() -> Config {
+    SCOPE.with(Cell::get).unwrap_or_else(|| {
+        *CONFIG
+            .read()
+            .unwrap_or_else(|poisoned| poisoned.into_inner())
+    })
+}
+
+/// Replaces the global configuration. Threads inside an override scope keep the configuration of
+/// their scope until it ends.
+pub fn set_config(config: Config) {
+    *CONFIG
+        .write()
+        .unwrap_or_else(|poisoned| poisoned.into_inner()) = config;
+}
+
+/// Updates the global configuration in place, leaving the settings of override scopes alone.
+pub(crate) fn update_config(update: impl FnOnce(&mut Config)) {
+    update(
+        &mut CONFIG
+            .write()
+            .unwrap_or_else(|poisoned| poisoned.into_inner()),
+    );
+}
+
+/// Runs `f` with `config` in effect on the current thread instead of the global configuration, and
+/// returns its result. Scopes nest; the previous configuration is restored when `f` returns or
+/// panics. Threads spawned by `f` get the global configuration.
+///
+/// # Examples
+///
+/// ```rust
+/// # if cfg!(feature = "no-color-build") { return; }
+/// use bytescolor::control::{self, ColorChoice, Config};
+///
+/// let config = Config { choice: ColorChoice::Never, ..Config::default() };
+/// control::with_config(config, || {
+///     assert!(!control::colors_enabled());
+///     std::thread::spawn(|| assert!(control::colors_enabled())).join().unwrap();
+/// });
+/// assert!(control::colors_enabled());
+/// ```
+pub fn with_config<R>(config: Config, f: impl FnOnce() -> R) -> R {
+    struct Restore(Option<Config>);
+
+    impl Drop for Restore {
+        fn drop(&mut self) {
+            SCOPE.with(|scope| scope.set(self.0));
+        }
+    }
+
+    let _restore = Restore(SCOPE.with(|scope| scope.replace(Some(config))));
+    f()
+}
+
+/// Runs `f` with `choice` as the color choice of the current thread; see [`with_config`].
+pub fn with_color_choice<R>(choice: ColorChoice, f: impl FnOnce() -> R) -> R {
+    with_config(Config { choice, ..config() }, f)
+}
+
+/// Runs `f` with `level` as the color level of the current thread; see [`with_config`].
+pub fn with_color_level<R>(level: ColorLevel, f: impl FnOnce() -> R) -> R {
+    with_config(Config { level, ..config() }, f)
+}
+
+/// Sets the global color choice.
+///
+/// # Examples
+///
+/// ```rust
+/// # if cfg!(feature = "no-color-build") { return; }
+/// use bytescolor::control::{self, ColorChoice};
+/// use bytescolor::ByteColor;
+///
+/// control::set_color_choice(ColorChoice::Never);
+/// assert_eq!("plain".red().as_str(), "plain");
+/// control::set_color_choice(ColorChoice::Always);
+/// assert_eq!("red".red().as_str(), "\x1b[31mred\x1b[0m");
+/// ```
+pub fn set_color_choice(choice: ColorChoice) {
+    update_config(|config| config.choice = choice);
+}
+
+/// Returns the color choice in effect on the current thread.
+pub fn color_choice() -> ColorChoice {
+    config().choice
+}
+
+/// Returns `true` if escape sequences should currently be emitted, according to the [`ColorChoice`]
+/// in effect on the current thread.
+///
+/// Always `false` when the crate is built with the `no-color-build` feature.
+pub fn colors_enabled() -> bool {
+    if cfg!(feature = "no-color-build") {
+        return false;
+    }
+    match color_choice() {
+        ColorChoice::Always => true,
+        ColorChoice::Never => false,
+        ColorChoice::Auto => detect(),
+    }
+}
+
+/// Inspects the environment to decide whether escape sequences are supported, following the usual
+/// conventions in order of precedence:
+///
+/// 1. `NO_COLOR` set to a non-empty value disables them.
+/// 2. `CLICOLOR_FORCE` set to a value other than `0` enables them.
+/// 3. `TERM=dumb` disables them.
+/// 4. Otherwise they are enabled if standard output is a terminal.
+///
+/// The environment is only inspected on the first call, which [`ColorChoice::Auto`] makes while
+/// rendering; later calls return the cached result until [`refresh`].
+pub fn detect() -> bool {
+    *detected().colors.get_or_init(detect_colors)
+}
+
+fn detect_colors() -> bool {
+    if std::env::var_os("NO_COLOR").is_some_and(|value| !value.is_empty()) {
+        return false;
+    }
+    if std::env::var_os("CLICOLOR_FORCE").is_some_and(|value| !value.is_empty() && value != "0") {
+        return true;
+    }
+    if std::env::var_os("TERM").is_some_and(|term| term == "dumb") {
+        return false;
+    }
+    std::io::stdout().is_terminal()
+}
+
+/// Sets the global color level. Colors richer than `level` are downgraded when rendered.
+///
+/// # Examples
+///
+/// ```rust
+/// # if cfg!(feature = "no-color-build") { return; }
+/// use bytescolor::control::{self, ColorLevel};
+/// use bytescolor::ByteColor;
+///
+/// control::set_color_level(ColorLevel::Ansi256);
+/// assert_eq!("sky".rgb((95, 175, 255)).as_str(), "\x1b[38;5;75msky\x1b[0m");
+/// control::set_color_level(ColorLevel::TrueColor);
+/// ```
+pub fn set_color_level(level: ColorLevel) {
+    update_config(|config| config.level = level);
+}
+
+/// Returns the color level in effect on the current thread.
+pub fn color_level() -> ColorLevel {
+    config().level
+}
+
+/// Guesses the color level of the terminal from the environment:
+///
+/// 1. `COLORTERM=truecolor` or `COLORTERM=24bit` means [`ColorLevel::TrueColor`].
+/// 2. With the `terminfo` feature, the `max_colors` and `RGB`/`Tc` capabilities of the terminfo entry
+///    for `$TERM` decide, when the entry can be found.
+/// 3. Otherwise `TERM=dumb` means [`ColorLevel::None`], a `TERM` mentioning `256color` means
+///    [`ColorLevel::Ansi256`], and anything else [`ColorLevel::Ansi16`].
+///
+/// The result is typically passed to [`set_color_level`]. Like [`detect`], it is computed on the
+/// first call and cached until [`refresh`].
+pub fn detect_level() -> ColorLevel {
+    *detected().level.get_or_init(detect_level_uncached)
+}
+
+fn detect_level_uncached() -> ColorLevel {
+    if std::env::var("COLORTERM").is_ok_and(|value| value == "truecolor" || value == "24bit") {
+        return ColorLevel::TrueColor;
+    }
+    #[cfg(feature = "terminfo")]
+    if let Ok(info) = crate::terminfo::Terminfo::from_env() {
+        return info.color_level();
+    }
+    level_from_term(&std::env::var("TERM").unwrap_or_default())
+}
+
+fn level_from_term(term: &str) -> ColorLevel {
+    if term == "dumb" {
+        ColorLevel::None
+    } else if term.contains("256color") {
+        ColorLevel::Ansi256
+    } else if term.contains("direct") {
+        ColorLevel::TrueColor
+    } else {
+        ColorLevel::Ansi16
+    }
+}
+
+/// The results of environment detection, each computed on first use.
+struct Detected {
+    colors: OnceLock<bool>,
+    level: OnceLock<ColorLevel>,
+}
+
+impl Detected {
+    const fn new() -> Self {
+        Detected {
+            colors: OnceLock::new(),
+            level: OnceLock::new(),
+        }
+    }
+}
+
+static DETECTED: RwLock<Detected> = RwLock::new(Detected::new());
+
+fn detected() -> RwLockReadGuard<'static, Detected> {
+    DETECTED
+        .read()
+        .unwrap_or_else(|poisoned| poisoned.into_inner())
+}
+
+/// Forgets the cached results of [`detect`] and [`detect_level`], so the environment is inspected
+/// again the next time colors are detected.
+///
+/// Long-running programs call it when their environment changes: after re-executing themselves
+/// with new variables, or when a daemon is reattached to a terminal. Settings chosen explicitly, such
+/// as [`set_color_level`], are left alone.
+///
+/// # Examples
+///
+/// ```rust
+/// use bytescolor::control;
+///
+/// let before = control::detect_level();
+/// bytescolor::refresh();
+/// assert_eq!(control::detect_level(), before);
+/// ```
+pub fn refresh() {
+    *DETECTED
+        .write()
+        .unwrap_or_else(|poisoned| poisoned.into_inner()) = Detected::new();
+}
@@ -0,0 +1,183 @@
+//! # Colored Debug Output
+//!
+//! [`DebugColored`] renders a value with its pretty `{:#?}` representation and runs the result through a
+//! lightweight colorizer, so large debug dumps become scannable: type and variant names are bold, field
+//! names cyan, strings and characters green, and numbers yellow.
+//!
+//! The [`dbg_colored!`](crate::dbg_colored) macro is a drop-in replacement for [`std::dbg!`] built on it.
+//!
+//! ```rust
+//! use bytescolor::debug::DebugColored;
+//!
+//! #[derive(Debug)]
+//! struct Config {
+//!     name: &'static str,
+//!     retries: u32,
+//! }
+//!
+//! let config = Config { name: "api", retries: 3 };
+//! println!("{}", DebugColored(&config));
+//! ```
+
+use std::fmt;
+
+use crate::{Color, Style};
+
+const TYPE_NAME: Style = Style::new().bold();
+const FIELD_NAME: Style = Style::new().fg(Color::Cyan);
+const STRING: Style = Style::new().fg(Color::Green);
+const NUMBER: Style = Style::new().fg(Color::Yellow);
+
+/// A wrapper whose `Display` implementation prints the wrapped value's colorized `{:#?}` output.
+///
+/// # Examples
+///
+/// ```rust
+/// # if cfg!(feature = "no-color-build") { return; }
+/// use bytescolor::debug::DebugColored;
+///
+/// let out = DebugColored(Some(42)).to_string();
+/// assert_eq!(out, "\x1b[1mSome\x1b[0m(\n    \x1b[33m42\x1b[0m,\n)");
+/// ```
+#[derive(Clone, Copy, PartialEq, Eq, Hash, Default)]
+pub struct DebugColored<T>(pub T);
+
+impl<T: fmt::Debug> fmt::Display for DebugColored<T> {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.write_str(&colorize(&format!("{:#?}", self.0)))
+    }
+}
+
+impl<T: fmt::Debug> fmt::Debug for DebugColored<T> {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        fmt::Display::fmt(self, f)
+    }
+}
+
+/// Colorizes text produced by a `Debug` implementation.
+///
+/// The input does not need to come from [`DebugColored`]: any `{:?}` or `{:#?}` output, for instance
+/// captured from a log, can be passed in.
+///
+/// # Examples
+///
+/// ```rust
+/// # if cfg!(feature = "no-color-build") { return; }
+/// use bytescolor::debug;
+///
+/// let out = debug::colorize(r#"Point { x: 1, label: "a" }"#);
+/// assert_eq!(
+///     out,
+///     "\x1b[1mPoint\x1b[0m { \x1b[36mx\x1b[0m: \x1b[33m1\x1b[0m, \x1b[36mlabel\x1b[0m: \x1b[32m\"a\"\x1b[0m }"
+/// );
+/// ```
+pub fn colorize(text: &str) -> String {
+    let bytes = text.as_bytes();
+    let mut out = String::with_capacity(text.len() * 2);
+    let mut pos = 0;
+
+    while pos < bytes.len() {
+        let start = pos;
+        match bytes[pos] {
+            quote @ (b'"' | b'\'') => {
+                pos += 1;
+                while pos < bytes.len() && bytes[pos] != quote {
+                    pos += if bytes[pos] == b'\\' { 2 } else { 1 };
+                }
+                pos = (pos + 1).min(bytes.len());
+                out.push_str(&STRING.wrap(&text[start..pos]));
+            }
+            b'0'..=b'9' => {
+                pos = token_end(bytes, pos);
+                out.push_str(&NUMBER.wrap(&text[start..pos]));
+            }
+            b'-' if bytes.get(pos + 1).is_some_and(u8::is_ascii_digit) => {
+                pos = token_end(bytes, pos + 1);
+                out.push_str(&NUMBER.wrap(&text[start..pos]));
+            }
+            b if b.is_ascii_alphabetic() || b == b'_' => {
+                pos = token_end(bytes, pos);
+                // Paths such as `std::io::Error` are part of the name.
+                while bytes[pos..].starts_with(b"::") {
+                    pos = token_end(bytes, pos + 2);
+                }
+                let ident = &text[start..pos];
+                let next = text[pos..].trim_start_matches(' ');
+                let style = if next.starts_with(':') && !next.starts_with("::") {
+                    FIELD_NAME
+                } else if next.starts_with('{')
+                    || next.starts_with('(')
+                    || ident.starts_with(|c: char| c.is_ascii_uppercase())
+                {
+                    TYPE_NAME
+                } else {
+                    Style::new()
+                };
+                out.push_str(&style.wrap(ident));
+            }
+            _ => {
+                pos += text[pos..].chars().next().map_or(1, char::len_utf8);
+                out.push_str(&text[start..pos]);
+            }
+        }
+    }
+    out
+}
+
+/// Returns the end of an identifier or number token starting at `pos`.
+fn token_end(bytes: &[u8], mut pos: usize) -> usize {
+    while pos < bytes.len()
+        && (bytes[pos].is_ascii_alphanumeric() || matches!(bytes[pos], b'_' | b'.'))
+    {
+        pos += 1;
+    }
+    pos
+}
+
+/// Prints and returns the value of an expression like [`std::dbg!`], with its `{:#?}` output colorized
+/// through [`DebugColored`] and its location dimmed, both following the settings of
+/// [`control`](crate::control).
+///
+/// # Examples
+///
+/// ```rust
+/// use bytescolor::dbg_colored;
+///
+/// let doubled = dbg_colored!(21 * 2);
+/// assert_eq!(doubled, 42);
+/// ```
+#[macro_export]
+macro_rules! dbg_colored {
+    () => {
+        ::std::eprintln!(
+            "{}",
+            $crate::Style::new().dim().paint(::std::format_args!(
+                "[{}:{}:{}]",
+                ::std::file!(),
+                ::std::line!(),
+                ::std::column!()
+            ))
+        )
+    };
+    ($val:expr $(,)?) => {
+        match $val {
+            tmp => {
+                ::std::eprintln!(
+                    "{} {} = {}",
+                    $crate::Style::new().dim().paint(::std::format_args!(
+                        "[{}:{}:{}]",
+                        ::std::file!(),
+                        ::std::line!(),
+                        ::std::column!()
+                    )),
+                    ::std::stringify!($val),
+                    $crate::debug::DebugColored(&tmp)
+                );
+                tmp
+            }
+        }
+    };
+    ($($val:expr),+ $(,)?) => {
+        ($($crate::dbg_colored!($val)),+,)
+    };
+}
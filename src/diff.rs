@@ -0,0 +1,515 @@
+//! # Diffs
+//!
+//! Line diffs of two texts, and renderers for showing them. [`lines`] computes the diff; [`SideBySide`]
+//! lays it out in two columns with line numbers, highlighting the changed characters within modified
+//! lines, for commands comparing configuration files.
+//!
+//! ```rust
+//! use bytescolor::diff::SideBySide;
+//!
+//! let old = "port = 80\nhost = \"a\"\n";
+//! let new = "port = 8080\nhost = \"a\"\n";
+//! println!("{}", SideBySide::new().width(60).render(old, new));
+//! ```
+//!
+//! Existing unified diffs, as printed by `git diff` or `diff -u`, are colorized with [`colorize_unified`].
+//!
+//! The diff is a longest-common-subsequence diff, quadratic in the number of lines that differ: it is
+//! meant for configuration-sized inputs, not for large files.
+//!
+//! This module is available with the `highlight` feature, enabled by default.
+
+use crate::{ansi, Color, Style, StyledString, StyledText};
+
+/// One line of a line diff.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub enum DiffLine<'a> {
+    /// A line present in both texts.
+    Equal(&'a str),
+    /// A line only present in the old text.
+    Delete(&'a str),
+    /// A line only present in the new text.
+    Insert(&'a str),
+}
+
+/// Computes the line diff turning `old` into `new`.
+///
+/// Lines are split like [`str::lines`], so a trailing newline does not produce an empty last line.
+///
+/// # Examples
+///
+/// ```rust
+/// use bytescolor::diff::{self, DiffLine};
+///
+/// let changes = diff::lines("a\nb\nc", "a\nc\nd");
+/// assert_eq!(
+///     changes,
+///     [
+///         DiffLine::Equal("a"),
+///         DiffLine::Delete("b"),
+///         DiffLine::Equal("c"),
+///         DiffLine::Insert("d"),
+///     ]
+/// );
+/// ```
+pub fn lines<'a>(old: &'a str, new: &'a str) -> Vec<DiffLine<'a>> {
+    let old: Vec<&str> = old.lines().collect();
+    let new: Vec<&str> = new.lines().collect();
+    let (mut i, mut j) = (0, 0);
+    edits(&old, &new)
+        .into_iter()
+        .map(|edit| match edit {
+            Edit::Equal => {
+                i += 1;
+                j += 1;
+                DiffLine::Equal(old[i - 1])
+            }
+            Edit::Delete => {
+                i += 1;
+                DiffLine::Delete(old[i - 1])
+            }
+            Edit::Insert => {
+                j += 1;
+                DiffLine::Insert(new[j - 1])
+            }
+        })
+        .collect()
+}
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+enum Edit {
+    Equal,
+    Delete,
+    Insert,
+}
+
+/// Returns the edit script turning `a` into `b`, deletions before insertions within a change.
+fn edits<T: PartialEq>(a: &[T], b: &[T]) -> Vec<Edit> {
+    let prefix = a.iter().zip(b).take_while(|(x, y)| x == y).count();
+    let suffix = a[prefix..]
+        .iter()
+        .rev()
+        .zip(b[prefix..].iter().rev())
+        .take_while(|(x, y)| x == y)
+        .count();
+    let (a_mid, b_mid) = (&a[prefix..a.len() - suffix], &b[prefix..b.len() - suffix]);
+    let (n, m) = (a_mid.len(), b_mid.len());
+
+    // lcs[i][j] is the length of the longest common subsequence of a_mid[i..] and b_mid[j..].
+    let mut lcs = vec![vec![0usize; m + 1]; n + 1];
+    for i in (0..n).rev() {
+        for j in (0..m).rev() {
+            lcs[i][j] = if a_mid[i] == b_mid[j] {
+                lcs[i + 1][j + 1] + 1
+            } else {
+                lcs[i + 1][j].max(lcs[i][j + 1])
+            };
+        }
+    }
+
+    let mut out = vec![Edit::Equal; prefix];
+    let (mut i, mut j) = (0, 0);
+    while i < n || j < m {
+        if i < n && j < m && a_mid[i] == b_mid[j] {
+            out.push(Edit::Equal);
+            i += 1;
+            j += 1;
+        } else if i < n && (j == m || lcs[i + 1][j] >= lcs[i][j + 1]) {
+            out.push(Edit::Delete);
+            i += 1;
+        } else {
+            out.push(Edit::Insert);
+            j += 1;
+        }
+    }
+    out.extend(std::iter::repeat(Edit::Equal).take(suffix));
+    out
+}
+
+/// Styles for the parts of a rendered diff.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub struct DiffStyles {
+    /// Lines present in both texts.
+    pub equal: Style,
+    /// Deleted lines.
+    pub delete: Style,
+    /// Inserted lines.
+    pub insert: Style,
+    /// The changed characters within a modified line, on the old side.
+    pub delete_emphasis: Style,
+    /// The changed characters within a modified line, on the new side.
+    pub insert_emphasis: Style,
+    /// Line numbers.
+    pub line_number: Style,
+    /// The column separator and change markers.
+    pub separator: Style,
+    /// File headers of a unified diff (`diff --git`, `index`, `---`, `+++`, mode changes).
+    pub header: Style,
+    /// Hunk headers of a unified diff (`@@ -1,3 +1,4 @@`).
+    pub hunk: Style,
+    /// Markers such as `\ No newline at end of file`.
+    pub meta: Style,
+}
+
+impl Default for DiffStyles {
+    fn default() -> Self {
+        DiffStyles {
+            equal: Style::new(),
+            delete: Style::new().fg(Color::Red),
+            insert: Style::new().fg(Color::Green),
+            delete_emphasis: Style::new().fg(Color::Red).bold().reverse(),
+            insert_emphasis: Style::new().fg(Color::Green).bold().reverse(),
+            line_number: Style::new().dim(),
+            separator: Style::new().dim(),
+            header: Style::new().bold(),
+            hunk: Style::new().fg(Color::Cyan),
+            meta: Style::new().dim(),
+        }
+    }
+}
+
+/// A two-column diff renderer: old text on the left, new text on the right.
+///
+/// Modified lines face each other, with the changed characters emphasized; the column between them
+/// holds `│` for unchanged lines, `|` for modified, `<` for deleted and `>` for inserted ones. Long lines
+/// are cut with `…` so both columns stay aligned whatever their styling.
+///
+/// # Examples
+///
+/// ```rust
+/// use bytescolor::{ansi, diff::SideBySide};
+///
+/// let view = SideBySide::new().width(30).render("a = 1\nb = 2\n", "a = 1\nb = 3\nc = 4\n");
+/// let rows: Vec<_> = ansi::strip(&view).lines().map(str::to_owned).collect();
+/// assert_eq!(rows[0], "1 a = 1       │ 1 a = 1");
+/// assert_eq!(rows[1], "2 b = 2       | 2 b = 3");
+/// assert_eq!(rows[2], "              > 3 c = 4");
+/// ```
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct SideBySide {
+    width: usize,
+    line_numbers: bool,
+    tab_width: usize,
+    styles: DiffStyles,
+}
+
+impl Default for SideBySide {
+    fn default() -> Self {
+        SideBySide::new()
+    }
+}
+
+impl SideBySide {
+    /// Creates a renderer 80 columns wide, with line numbers and the default [`DiffStyles`].
+    pub fn new() -> Self {
+        SideBySide {
+            width: 80,
+            line_numbers: true,
+            tab_width: 4,
+            styles: DiffStyles::default(),
+        }
+    }
+
+    /// Sets the total width of the output, both columns, line numbers and separator included.
+    pub fn width(mut self, width: usize) -> Self {
+        self.width = width;
+        self
+    }
+
+    /// Shows or hides the line numbers.
+    pub fn line_numbers(mut self, line_numbers: bool) -> Self {
+        self.line_numbers = line_numbers;
+        self
+    }
+
+    /// Sets the number of spaces tabs are expanded to, so they are measured like any other text.
+    pub fn tab_width(mut self, tab_width: usize) -> Self {
+        self.tab_width = tab_width;
+        self
+    }
+
+    /// Sets the styles of the output.
+    pub fn styles(mut self, styles: DiffStyles) -> Self {
+        self.styles = styles;
+        self
+    }
+
+    /// Renders the diff turning `old` into `new`, one row per line terminated by `\n`.
+    pub fn render(&self, old: &str, new: &str) -> String {
+        let tab = " ".repeat(self.tab_width);
+        let old = old.replace('\t', &tab);
+        let new = new.replace('\t', &tab);
+        let changes = lines(&old, &new);
+
+        let digits = if self.line_numbers {
+            let count = old.lines().count().max(new.lines().count());
+            count.to_string().len()
+        } else {
+            0
+        };
+        let gutter = if self.line_numbers { digits + 1 } else { 0 };
+        let column = (self.width.saturating_sub(3 + 2 * gutter) / 2).max(1);
+
+        let mut out = String::new();
+        let (mut old_number, mut new_number) = (0, 0);
+        let mut index = 0;
+        while index < changes.len() {
+            if let DiffLine::Equal(line) = changes[index] {
+                old_number += 1;
+                new_number += 1;
+                let cell = StyledText::styled(line, self.styles.equal);
+                let (left, right) = (Some((old_number, &cell)), Some((new_number, &cell)));
+                self.push_row(&mut out, digits, column, left, '│', right);
+                index += 1;
+                continue;
+            }
+            let deleted: Vec<&str> = changes[index..]
+                .iter()
+                .map_while(|change| match change {
+                    DiffLine::Delete(line) => Some(*line),
+                    _ => None,
+                })
+                .collect();
+            index += deleted.len();
+            let inserted: Vec<&str> = changes[index..]
+                .iter()
+                .map_while(|change| match change {
+                    DiffLine::Insert(line) => Some(*line),
+                    _ => None,
+                })
+                .collect();
+            index += inserted.len();
+
+            for row in 0..deleted.len().max(inserted.len()) {
+                let (left, right, marker) = match (deleted.get(row), inserted.get(row)) {
+                    (Some(before), Some(after)) => {
+                        let (left, right) = self.intra_line(before, after);
+                        (Some(left), Some(right), '|')
+                    }
+                    (Some(before), None) => (
+                        Some(StyledText::styled(*before, self.styles.delete)),
+                        None,
+                        '<',
+                    ),
+                    (None, Some(after)) => (
+                        None,
+                        Some(StyledText::styled(*after, self.styles.insert)),
+                        '>',
+                    ),
+                    (None, None) => unreachable!("row is within one of the runs"),
+                };
+                let left = left.map(|cell| {
+                    old_number += 1;
+                    (old_number, cell)
+                });
+                let right = right.map(|cell| {
+                    new_number += 1;
+                    (new_number, cell)
+                });
+                self.push_row(
+                    &mut out,
+                    digits,
+                    column,
+                    left.as_ref().map(|(number, cell)| (*number, cell)),
+                    marker,
+                    right.as_ref().map(|(number, cell)| (*number, cell)),
+                );
+            }
+        }
+        out
+    }
+
+    /// Styles a modified line pair, emphasizing the characters that differ.
+    fn intra_line(&self, before: &str, after: &str) -> (StyledText, StyledText) {
+        let old: Vec<char> = before.chars().collect();
+        let new: Vec<char> = after.chars().collect();
+        let (mut left, mut right) = (StyledText::new(), StyledText::new());
+        let (mut i, mut j) = (0, 0);
+        for edit in edits(&old, &new) {
+            match edit {
+                Edit::Equal => {
+                    left.push(old[i].to_string(), self.styles.delete);
+                    right.push(new[j].to_string(), self.styles.insert);
+                    i += 1;
+                    j += 1;
+                }
+                Edit::Delete => {
+                    left.push(old[i].to_string(), self.styles.delete_emphasis);
+                    i += 1;
+                }
+                Edit::Insert => {
+                    right.push(new[j].to_string(), self.styles.insert_emphasis);
+                    j += 1;
+                }
+            }
+        }
+        (left, right)
+    }
+
+    fn push_row(
+        &self,
+        out: &mut String,
+        digits: usize,
+        column: usize,
+        left: Option<(usize, &StyledText)>,
+        marker: char,
+        right: Option<(usize, &StyledText)>,
+    ) {
+        self.push_cell(out, digits, column, left, true);
+        out.push(' ');
+        out.push_str(&self.styles.separator.wrap(marker));
+        out.push(' ');
+        self.push_cell(out, digits, column, right, false);
+        out.push('\n');
+    }
+
+    fn push_cell(
+        &self,
+        out: &mut String,
+        digits: usize,
+        column: usize,
+        cell: Option<(usize, &StyledText)>,
+        pad: bool,
+    ) {
+        let (number, cell) = match cell {
+            Some((number, cell)) => (Some(number), Some(cell)),
+            None => (None, None),
+        };
+        if self.line_numbers {
+            match number {
+                Some(number) => out.push_str(&self.styles.line_number.wrap(format_args!(
+                    "{:>width$}",
+                    number,
+                    width = digits
+                ))),
+                None => out.push_str(&" ".repeat(digits)),
+            }
+            out.push(' ');
+        }
+        let mut width = 0;
+        if let Some(cell) = cell {
+            width = cell.width();
+            if width > column {
+                let mut used = 0;
+                let kept = cell
+                    .visible()
+                    .chars()
+                    .take_while(|&c| {
+                        used += ansi::char_width(c);
+                        used < column
+                    })
+                    .count();
+                let mut cut = cell.slice(..kept);
+                let style = cut.spans().last().map_or(Style::new(), |span| span.style());
+                cut.push("…", style);
+                width = cut.width();
+                out.push_str(&cut.render());
+            } else {
+                out.push_str(&cell.render());
+            }
+        }
+        if pad {
+            out.push_str(&" ".repeat(column - width));
+        }
+    }
+}
+
+/// Colorizes unified diff text with the default [`DiffStyles`].
+///
+/// # Examples
+///
+/// ```rust
+/// # if cfg!(feature = "no-color-build") { return; }
+/// use bytescolor::diff;
+///
+/// let patch = "--- a/port\n+++ b/port\n@@ -1 +1 @@\n-80\n+8080\n";
+/// let colored = diff::colorize_unified(patch);
+/// assert_eq!(colored, patch);
+/// assert!(colored.as_str().contains("\x1b[31m-80\x1b[0m\n\x1b[32m+8080\x1b[0m"));
+/// ```
+pub fn colorize_unified(text: &str) -> StyledString {
+    colorize_unified_with(text, &DiffStyles::default())
+}
+
+/// Colorizes unified diff text with `styles`: file headers, hunk headers (the function context after
+/// them left in the `equal` style), deleted, inserted and context lines.
+///
+/// Hunk line counts are tracked, so a deleted line reading `-- x` is not taken for a `---` header, and
+/// text outside of hunks that is not a file header (such as a commit message) keeps the `equal` style.
+/// Line terminators are preserved and never styled.
+pub fn colorize_unified_with(text: &str, styles: &DiffStyles) -> StyledString {
+    const HEADERS: [&str; 14] = [
+        "diff ",
+        "index ",
+        "--- ",
+        "+++ ",
+        "new file mode",
+        "deleted file mode",
+        "old mode",
+        "new mode",
+        "similarity index",
+        "dissimilarity index",
+        "rename from",
+        "rename to",
+        "copy from",
+        "copy to",
+    ];
+    let mut out = String::with_capacity(text.len() * 2);
+    // Lines left in the current hunk, on the old and the new side.
+    let (mut old_left, mut new_left) = (0u64, 0u64);
+    for line in text.split_inclusive('\n') {
+        let body = line
+            .strip_suffix("\r\n")
+            .or_else(|| line.strip_suffix('\n'))
+            .unwrap_or(line);
+        let terminator = &line[body.len()..];
+        if old_left > 0 || new_left > 0 {
+            let style = match body.as_bytes().first() {
+                Some(b'-') => {
+                    old_left = old_left.saturating_sub(1);
+                    styles.delete
+                }
+                Some(b'+') => {
+                    new_left = new_left.saturating_sub(1);
+                    styles.insert
+                }
+                Some(b'\\') => styles.meta,
+                _ => {
+                    old_left = old_left.saturating_sub(1);
+                    new_left = new_left.saturating_sub(1);
+                    styles.equal
+                }
+            };
+            out.push_str(&style.wrap(body));
+        } else if let Some((header, context, counts)) = parse_hunk_header(body) {
+            (old_left, new_left) = counts;
+            out.push_str(&styles.hunk.wrap(header));
+            if !context.is_empty() {
+                out.push_str(&styles.equal.wrap(context));
+            }
+        } else if body.starts_with('\\') {
+            out.push_str(&styles.meta.wrap(body));
+        } else if HEADERS.iter().any(|prefix| body.starts_with(prefix)) {
+            out.push_str(&styles.header.wrap(body));
+        } else {
+            out.push_str(&styles.equal.wrap(body));
+        }
+        out.push_str(terminator);
+    }
+    StyledString::from(out)
+}
+
+/// Splits a `@@ -a,b +c,d @@ context` line into the header, the context and the old and new line
+/// counts.
+fn parse_hunk_header(line: &str) -> Option<(&str, &str, (u64, u64))> {
+    let rest = line.strip_prefix("@@ -")?;
+    let end = rest.find(" @@")? + " @@".len();
+    let (ranges, context) = rest.split_at(end);
+    let (old, new) = ranges.strip_suffix(" @@")?.split_once(" +")?;
+    let count = |range: &str| match range.split_once(',') {
+        Some((_, count)) => count.parse().ok(),
+        None => range.parse::<u64>().ok().map(|_| 1),
+    };
+    let header_len = "@@ -".len() + end;
+    Some((&line[..header_len], context, (count(old)?, count(new)?)))
+}
@@ -0,0 +1,114 @@
+//! # Encoded Bytes
+//!
+//! [`EncodingColor`] encodes byte slices as hexadecimal or base64 text colored in fixed-size groups of
+//! alternating shades, so long encoded blobs such as keys, tokens and digests can be compared by eye.
+//! Only escape sequences are added: the visible text is the plain encoding, ready to be copied.
+//!
+//! ```rust
+//! use bytescolor::encoding::EncodingColor;
+//!
+//! let key = [0xde, 0xad, 0xbe, 0xef, 0x01, 0x02];
+//! println!("{}", key.hex_string_colored()); // Displays "deadbeef0102"
+//! println!("{}", key.base64_colored()); // Displays "3q2+7wEC"
+//! ```
+//!
+//! For digests that humans compare, [`fingerprint_colored`](EncodingColor::fingerprint_colored) colors
+//! each chunk by its value instead, so two fingerprints differing anywhere show different colors there.
+//!
+//! This module is available with the `basic` feature, enabled by default.
+
+use crate::number::SHADES;
+use crate::{base64, Color, Style, StyledString};
+
+/// The number of characters of a group.
+const GROUP: usize = 8;
+const PADDING: Style = Style::new().dim();
+const SEPARATOR: Style = Style::new().dim();
+
+/// Extension trait rendering bytes in colored text encodings.
+pub trait EncodingColor {
+    /// Renders the bytes in lowercase hexadecimal, in groups of eight digits.
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// use bytescolor::encoding::EncodingColor;
+    ///
+    /// assert_eq!(b"\x00\x01\xff".hex_string_colored(), "0001ff");
+    /// ```
+    fn hex_string_colored(&self) -> StyledString;
+
+    /// Renders the bytes in padded standard base64 (RFC 4648), in groups of eight characters, with
+    /// the padding dimmed.
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// use bytescolor::encoding::EncodingColor;
+    ///
+    /// assert_eq!(b"hello".base64_colored(), "aGVsbG8=");
+    /// ```
+    fn base64_colored(&self) -> StyledString;
+
+    /// Renders a hash or other digest as colon-separated chunks of two bytes in hexadecimal, each in
+    /// a color derived from its value, so equal chunks always look the same and differing ones stand
+    /// out. Colors are taken from the bright part of the 256-color palette.
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// use bytescolor::encoding::EncodingColor;
+    ///
+    /// let digest = [0x3f, 0xa2, 0x91, 0x0c, 0x7e];
+    /// assert_eq!(digest.fingerprint_colored(), "3fa2:910c:7e");
+    /// ```
+    fn fingerprint_colored(&self) -> StyledString;
+}
+
+impl EncodingColor for [u8] {
+    fn hex_string_colored(&self) -> StyledString {
+        let hex: String = self.iter().map(|byte| format!("{:02x}", byte)).collect();
+        groups(&hex)
+    }
+
+    fn base64_colored(&self) -> StyledString {
+        groups(&base64::encode(self))
+    }
+
+    fn fingerprint_colored(&self) -> StyledString {
+        let mut out = String::new();
+        for (index, chunk) in self.chunks(2).enumerate() {
+            if index > 0 {
+                out.push_str(&SEPARATOR.wrap(':'));
+            }
+            let hex: String = chunk.iter().map(|byte| format!("{:02x}", byte)).collect();
+            let value = chunk
+                .iter()
+                .fold(0u32, |acc, &byte| acc << 8 | u32::from(byte));
+            out.push_str(&chunk_style(value).wrap(&hex));
+        }
+        StyledString::from(out)
+    }
+}
+
+/// Returns the color of a fingerprint chunk: a color cube entry with every channel above the darkest
+/// level, so chunks stay readable on dark backgrounds. The value is mixed first, so that chunks
+/// differing in a single bit get unrelated colors.
+fn chunk_style(value: u32) -> Style {
+    let mixed = value.wrapping_mul(0x9e37_79b1) >> 16;
+    let (r, g, b) = (1 + mixed % 5, 1 + mixed / 5 % 5, 1 + mixed / 25 % 5);
+    let index = 16 + 36 * r + 6 * g + b;
+    Style::new().fg(Color::Fixed(index as u8))
+}
+
+/// Colors `text` in groups of alternating shades, with `=` padding dimmed.
+fn groups(text: &str) -> StyledString {
+    let data = text.trim_end_matches('=');
+    let mut out = String::new();
+    for (index, group) in data.as_bytes().chunks(GROUP).enumerate() {
+        let group = std::str::from_utf8(group).expect("encodings are ASCII");
+        out.push_str(&SHADES[index % 2].wrap(group));
+    }
+    out.push_str(&PADDING.wrap(&text[data.len()..]));
+    StyledString::from(out)
+}
@@ -0,0 +1,161 @@
+//! # Gauges
+//!
+//! [`Gauge`] shows where a value stands on a scale, `min──▣────max`, the track colored by threshold
+//! zones and the value in the color of its zone, the building block of resource monitors:
+//!
+//! ```rust
+//! use bytescolor::gauge::Gauge;
+//! use bytescolor::Style;
+//!
+//! let cpu = Gauge::new(0.0, 100.0)
+//!     .label("cpu")
+//!     .zone(0.0, Style::GREEN)
+//!     .zone(70.0, Style::YELLOW)
+//!     .zone(90.0, Style::RED)
+//!     .value(93.5);
+//! println!("{}", cpu); // cpu 0──────────────────▣─100 93.5, the value in red
+//! ```
+//!
+//! This module is available with the `widgets` feature, enabled by default.
+
+use std::fmt;
+
+use crate::Style;
+
+const BOUND: Style = Style::new().dim();
+
+/// A labeled value on a scale.
+///
+/// # Examples
+///
+/// ```rust
+/// use bytescolor::gauge::Gauge;
+/// use bytescolor::{ansi, Style};
+///
+/// let mut disk = Gauge::new(0.0, 100.0).label("disk").width(7).zone(80.0, Style::RED);
+/// disk.set(42.0);
+/// assert_eq!(ansi::strip(&disk.render()), "disk 0───▣───100 42");
+/// disk.set(250.0);
+/// assert_eq!(ansi::strip(&disk.render()), "disk 0──────▣100 250");
+/// ```
+#[derive(Debug, Clone, PartialEq)]
+pub struct Gauge {
+    min: f64,
+    max: f64,
+    value: f64,
+    width: usize,
+    label: Option<String>,
+    zones: Vec<(f64, Style)>,
+    track: Style,
+    marker: char,
+}
+
+impl Gauge {
+    /// Creates a gauge from `min` to `max` showing `min`, its track 20 cells wide and dimmed.
+    pub fn new(min: f64, max: f64) -> Self {
+        Gauge {
+            min,
+            max,
+            value: min,
+            width: 20,
+            label: None,
+            zones: Vec::new(),
+            track: Style::new().dim(),
+            marker: '▣',
+        }
+    }
+
+    /// Sets the value shown.
+    pub fn value(mut self, value: f64) -> Self {
+        self.set(value);
+        self
+    }
+
+    /// Sets the value shown, for gauges redrawn as the value changes. Values out of the scale are
+    /// shown as they are, with the marker at the end of the track they are beyond.
+    pub fn set(&mut self, value: f64) {
+        self.value = value;
+    }
+
+    /// Sets the width of the track in cells, marker included.
+    pub fn width(mut self, width: usize) -> Self {
+        self.width = width.max(1);
+        self
+    }
+
+    /// Sets a label printed before the scale.
+    pub fn label(mut self, label: impl Into<String>) -> Self {
+        self.label = Some(label.into());
+        self
+    }
+
+    /// Styles the part of the track from `from` upward, up to the next zone, and the value and the
+    /// marker when the value falls in it. The track below every zone keeps the track style.
+    pub fn zone(mut self, from: f64, style: Style) -> Self {
+        self.zones.push((from, style));
+        self.zones.sort_by(|(a, _), (b, _)| a.total_cmp(b));
+        self
+    }
+
+    /// Sets the style of the track outside of any zone.
+    pub fn track_style(mut self, style: Style) -> Self {
+        self.track = style;
+        self
+    }
+
+    /// Sets the character marking the value on the track.
+    pub fn marker(mut self, marker: char) -> Self {
+        self.marker = marker;
+        self
+    }
+
+    /// Renders the gauge: the label, the scale with its bounds, and the value.
+    pub fn render(&self) -> String {
+        let cells = self.width - 1;
+        let span = self.max - self.min;
+        let position = if span > 0.0 {
+            ((self.value - self.min) / span * cells as f64).round()
+        } else {
+            0.0
+        };
+        let position = position.clamp(0.0, cells as f64) as usize;
+        let style = self.zone_style(self.value);
+        let mut out = String::new();
+        if let Some(label) = &self.label {
+            out.push_str(label);
+            out.push(' ');
+        }
+        out.push_str(&BOUND.wrap(self.min));
+        for cell in 0..self.width {
+            if cell == position {
+                out.push_str(&style.unwrap_or_default().bold().wrap(self.marker));
+            } else {
+                let at = if cells == 0 {
+                    self.min
+                } else {
+                    self.min + span * cell as f64 / cells as f64
+                };
+                out.push_str(&self.zone_style(at).unwrap_or(self.track).wrap('─'));
+            }
+        }
+        out.push_str(&BOUND.wrap(self.max));
+        out.push(' ');
+        out.push_str(&style.unwrap_or_default().wrap(self.value));
+        out
+    }
+
+    /// Returns the style of the zone `value` falls in, if any.
+    fn zone_style(&self, value: f64) -> Option<Style> {
+        self.zones
+            .iter()
+            .rev()
+            .find(|&&(from, _)| value >= from)
+            .map(|&(_, style)| style)
+    }
+}
+
+impl fmt::Display for Gauge {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.write_str(&self.render())
+    }
+}
@@ -0,0 +1,109 @@
+//! # Gradients
+//!
+//! A [`Gradient`] maps a position in `0.0..=1.0` to an RGB [`Color`] by linear interpolation between
+//! evenly spaced color stops. Widgets use it to color bars and text along a scale, and
+//! [`Gradient::paint`] colors text with it one grapheme cluster at a time.
+//!
+//! ```rust
+//! use bytescolor::gradient::Gradient;
+//! use bytescolor::Color;
+//!
+//! let gradient = Gradient::new(vec![(0, 0, 0), (255, 255, 255)]);
+//! assert_eq!(gradient.at(0.5), Color::Rgb(128, 128, 128));
+//! println!("{}", Gradient::rainbow().paint("🎉 released!"));
+//! ```
+//!
+//! This module is available with the `rgb` feature, enabled by default.
+
+use crate::{Color, Style, StyledText};
+
+/// A multi-stop linear RGB gradient.
+#[derive(Debug, Clone, PartialEq, Eq, Hash)]
+pub struct Gradient {
+    stops: Vec<(u8, u8, u8)>,
+}
+
+impl Gradient {
+    /// Creates a gradient through `stops`, which are spread evenly over `0.0..=1.0`.
+    ///
+    /// # Panics
+    ///
+    /// Panics if `stops` is empty.
+    pub fn new(stops: Vec<(u8, u8, u8)>) -> Self {
+        assert!(
+            !stops.is_empty(),
+            "a gradient needs at least one color stop"
+        );
+        Gradient { stops }
+    }
+
+    /// The classic status gradient: red, through yellow, to green.
+    pub fn red_to_green() -> Self {
+        Gradient::new(vec![(220, 50, 47), (230, 200, 20), (60, 180, 75)])
+    }
+
+    /// A heatmap gradient for data visualization: blue, cyan, green, yellow, red.
+    pub fn heatmap() -> Self {
+        Gradient::new(vec![
+            (40, 70, 200),
+            (40, 190, 210),
+            (60, 180, 75),
+            (230, 200, 20),
+            (220, 50, 47),
+        ])
+    }
+
+    /// The colors of the rainbow: red, orange, yellow, green, blue, violet.
+    pub fn rainbow() -> Self {
+        Gradient::new(vec![
+            (230, 40, 40),
+            (240, 140, 20),
+            (230, 210, 20),
+            (50, 190, 70),
+            (40, 110, 230),
+            (150, 60, 210),
+        ])
+    }
+
+    /// Returns the color stops of the gradient.
+    pub fn stops(&self) -> &[(u8, u8, u8)] {
+        &self.stops
+    }
+
+    /// Returns the color at position `t`, clamped to `0.0..=1.0`.
+    pub fn at(&self, t: f64) -> Color {
+        let t = if t.is_nan() { 0.0 } else { t.clamp(0.0, 1.0) };
+        let segments = self.stops.len() - 1;
+        if segments == 0 {
+            let (r, g, b) = self.stops[0];
+            return Color::Rgb(r, g, b);
+        }
+        let scaled = t * segments as f64;
+        let index = (scaled.floor() as usize).min(segments - 1);
+        let local = scaled - index as f64;
+        let (from, to) = (self.stops[index], self.stops[index + 1]);
+        let mix = |a: u8, b: u8| (a as f64 + (b as f64 - a as f64) * local).round() as u8;
+        Color::Rgb(mix(from.0, to.0), mix(from.1, to.1), mix(from.2, to.2))
+    }
+
+    /// Colors the foreground of `text` along the gradient, from its first to its last grapheme cluster
+    /// (see [`StyledText::style_graphemes`]).
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// use bytescolor::gradient::Gradient;
+    /// use bytescolor::Color;
+    ///
+    /// let text = Gradient::new(vec![(255, 0, 0), (0, 0, 255)]).paint("a👍🏽b");
+    /// let spans = text.spans();
+    /// assert_eq!(spans.len(), 3);
+    /// assert_eq!(spans[1].text(), "👍🏽");
+    /// assert_eq!(spans[2].style().foreground(), Some(Color::Rgb(0, 0, 255)));
+    /// ```
+    pub fn paint(&self, text: &str) -> StyledText {
+        let text = StyledText::plain(text);
+        let last = crate::ansi::graphemes(&text.visible()).count().max(2) - 1;
+        text.style_graphemes(|index, _| Style::new().fg(self.at(index as f64 / last as f64)))
+    }
+}
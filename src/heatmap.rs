@@ -0,0 +1,120 @@
+//! # Byte Heatmaps
+//!
+//! Binwalk-style visualizations of binary data. [`Heatmap::value_heatmap`] draws one cell per byte colored by
+//! its value, from dark blue for `0x00` to red for `0xFF`, which makes padding, text and compressed regions
+//! stand out. [`Heatmap::entropy_heatmap`] draws one cell per block colored by its Shannon entropy, the
+//! classic way to spot encrypted or compressed sections in a firmware image.
+//!
+//! ```rust
+//! use bytescolor::heatmap::Heatmap;
+//!
+//! let blob: Vec<u8> = (0..=255).collect();
+//! println!("{}", blob.value_heatmap(32));
+//! ```
+//!
+//! This module is available with the `widgets` feature, enabled by default.
+
+use crate::gradient::Gradient;
+use crate::Style;
+
+const CELL: char = '█';
+
+fn value_gradient() -> Gradient {
+    Gradient::new(vec![
+        (0, 0, 96),
+        (0, 120, 255),
+        (0, 200, 80),
+        (255, 220, 0),
+        (220, 0, 0),
+    ])
+}
+
+/// Extension trait rendering byte slices as colored heatmaps.
+pub trait Heatmap {
+    /// Renders one cell per byte, colored by the byte value, `width` cells per line.
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// # if cfg!(feature = "no-color-build") { return; }
+    /// use bytescolor::heatmap::Heatmap;
+    ///
+    /// let map = [0x00u8, 0xff].value_heatmap(16);
+    /// assert_eq!(map, "\x1b[38;2;0;0;96m█\x1b[0m\x1b[38;2;220;0;0m█\x1b[0m");
+    /// ```
+    fn value_heatmap(&self, width: usize) -> String;
+
+    /// Splits the data into blocks of `block_size` bytes and renders one cell per block, colored by
+    /// the block entropy (0 bits: dark blue, 8 bits: red), `width` cells per line.
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// use bytescolor::{ansi, heatmap::Heatmap};
+    ///
+    /// let data = [0u8; 1024];
+    /// assert_eq!(ansi::strip(&data.entropy_heatmap(256, 64)), "████");
+    /// ```
+    fn entropy_heatmap(&self, block_size: usize, width: usize) -> String;
+}
+
+impl Heatmap for [u8] {
+    fn value_heatmap(&self, width: usize) -> String {
+        let gradient = value_gradient();
+        let cells: Vec<f64> = self.iter().map(|&b| b as f64 / 255.0).collect();
+        render(&cells, width, &gradient)
+    }
+
+    fn entropy_heatmap(&self, block_size: usize, width: usize) -> String {
+        let gradient = value_gradient();
+        let cells: Vec<f64> = self
+            .chunks(block_size.max(1))
+            .map(|block| entropy(block) / 8.0)
+            .collect();
+        render(&cells, width, &gradient)
+    }
+}
+
+/// Returns the Shannon entropy of `data` in bits per byte, from `0.0` (a single repeated value)
+/// to `8.0` (uniformly distributed bytes).
+///
+/// # Examples
+///
+/// ```rust
+/// use bytescolor::heatmap::entropy;
+///
+/// let uniform: Vec<u8> = (0..=255).collect();
+/// assert_eq!(entropy(&uniform), 8.0);
+/// assert_eq!(entropy(b"aaaa"), 0.0);
+/// ```
+pub fn entropy(data: &[u8]) -> f64 {
+    if data.is_empty() {
+        return 0.0;
+    }
+    let mut counts = [0usize; 256];
+    for &b in data {
+        counts[b as usize] += 1;
+    }
+    let len = data.len() as f64;
+    let bits: f64 = counts
+        .iter()
+        .filter(|&&count| count > 0)
+        .map(|&count| {
+            let p = count as f64 / len;
+            -p * p.log2()
+        })
+        .sum();
+    // Avoid reporting `-0.0` for single-valued data.
+    bits.max(0.0)
+}
+
+fn render(cells: &[f64], width: usize, gradient: &Gradient) -> String {
+    let mut out = String::new();
+    for (index, &t) in cells.iter().enumerate() {
+        if index > 0 && width > 0 && index % width == 0 {
+            out.push('\n');
+        }
+        out.push_str(&Style::new().fg(gradient.at(t)).wrap(CELL));
+    }
+    out
+}
@@ -0,0 +1,348 @@
+//! # Hex Dumps
+//!
+//! [`HexDump`] renders bytes the way `hexdump -C` does, offsets, hex and ASCII columns side by side,
+//! with bytes colored by class: NUL dimmed, printable ASCII cyan, whitespace green, other ASCII control
+//! characters magenta and everything else yellow.
+//!
+//! For binary records with a known structure, a [`Layout`] describes the fields and colors each one
+//! distinctly instead, with a legend underneath, which makes packets and file headers readable at a
+//! glance. [`HexDump::render_annotated`] does the same for labeled [`Field`]s of arbitrary bytes, such
+//! as the header, payload and checksum of a captured packet.
+//!
+//! ```rust
+//! use bytescolor::hexdump::{HexDump, Layout};
+//! use bytescolor::Style;
+//!
+//! println!("{}", HexDump::new().render(b"GIF89a\x01\x00\x01\x00"));
+//!
+//! let header = Layout::new()
+//!     .field("signature", 0, 6, Style::CYAN)
+//!     .field("width", 6, 2, Style::GREEN)
+//!     .field("height", 8, 2, Style::YELLOW);
+//! println!("{}", header.render(b"GIF89a\x01\x00\x01\x00"));
+//! ```
+//!
+//! This module is available with the `widgets` feature, enabled by default.
+
+use std::fmt::Write;
+use std::ops::Range;
+
+use crate::{Color, Style, StyledText};
+
+/// A configurable hex dump renderer.
+///
+/// # Examples
+///
+/// ```rust
+/// use bytescolor::hexdump::HexDump;
+/// use bytescolor::ansi;
+///
+/// let dump = HexDump::new().width(8).render(b"hi\n");
+/// assert_eq!(ansi::strip(&dump), "00000000  68 69 0a                 |hi.|");
+/// ```
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub struct HexDump {
+    width: usize,
+    offsets: bool,
+    ascii: bool,
+    start: usize,
+}
+
+impl Default for HexDump {
+    fn default() -> Self {
+        HexDump {
+            width: 16,
+            offsets: true,
+            ascii: true,
+            start: 0,
+        }
+    }
+}
+
+impl HexDump {
+    /// Creates a renderer of 16 bytes per line, with offsets and an ASCII column.
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// Sets the number of bytes per line. Lines are split in groups of 8 bytes.
+    ///
+    /// # Panics
+    ///
+    /// Panics if `width` is 0.
+    pub fn width(mut self, width: usize) -> Self {
+        assert!(width > 0, "a hex dump needs at least one byte per line");
+        self.width = width;
+        self
+    }
+
+    /// Shows the offset of each line, on by default.
+    pub fn offsets(mut self, offsets: bool) -> Self {
+        self.offsets = offsets;
+        self
+    }
+
+    /// Shows the ASCII column, on by default.
+    pub fn ascii(mut self, ascii: bool) -> Self {
+        self.ascii = ascii;
+        self
+    }
+
+    /// Sets the offset of the first byte, for dumping part of a larger buffer.
+    pub fn start(mut self, start: usize) -> Self {
+        self.start = start;
+        self
+    }
+
+    /// Renders `bytes`, without a final newline.
+    pub fn render(&self, bytes: &[u8]) -> String {
+        self.render_styled(bytes, |_, byte| class_style(byte))
+    }
+
+    /// Renders `bytes` with the bytes of each field in its style, followed by a blank line and the
+    /// list of fields with their ranges and sizes, without a final newline, for labeled ranges that
+    /// do not make up a [`Layout`].
+    ///
+    /// Bytes not covered by any field are dimmed; where fields overlap, the first one wins. Offsets
+    /// are indices into `bytes`, whatever the [`start`](HexDump::start) offset.
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// use bytescolor::hexdump::{Field, HexDump};
+    /// use bytescolor::{ansi, Style};
+    ///
+    /// let packet = b"\x45\x00\x00\x05ping\x9c";
+    /// let fields = [
+    ///     Field::new("header", 0, 4, Style::CYAN),
+    ///     Field::new("payload", 4, 4, Style::GREEN),
+    ///     Field::new("checksum", 8, 1, Style::MAGENTA),
+    /// ];
+    /// let out = HexDump::new().ascii(false).render_annotated(packet, &fields);
+    /// let out = ansi::strip(&out).into_owned();
+    /// let mut lines = out.lines();
+    /// assert_eq!(lines.next(), Some("00000000  45 00 00 05 70 69 6e 67  9c"));
+    /// assert_eq!(lines.next(), Some(""));
+    /// assert_eq!(lines.next(), Some("■ header    0..4  4 bytes"));
+    /// assert_eq!(lines.next(), Some("■ payload   4..8  4 bytes"));
+    /// assert_eq!(lines.next(), Some("■ checksum  8..9  1 byte"));
+    /// ```
+    pub fn render_annotated(&self, bytes: &[u8], fields: &[Field]) -> String {
+        self.render_fields(bytes, fields, |field| {
+            let len = field.len;
+            Style::DIM.wrap(format!("{} byte{}", len, if len == 1 { "" } else { "s" }))
+        })
+    }
+
+    /// Renders `bytes` with the bytes of each of `fields` in its style, followed by a blank line and a
+    /// legend line per field: its name, its range and the text returned by `value`.
+    fn render_fields(
+        &self,
+        bytes: &[u8],
+        fields: &[Field],
+        value: impl Fn(&Field) -> String,
+    ) -> String {
+        let mut out = self.render_styled(bytes, |index, _| {
+            fields
+                .iter()
+                .find(|field| field.range().contains(&index))
+                .map_or(Style::DIM, Field::style)
+        });
+        if fields.is_empty() {
+            return out;
+        }
+        out.push('\n');
+        let name_width = fields.iter().map(|f| f.name.chars().count()).max();
+        let ranges: Vec<String> = fields
+            .iter()
+            .map(|field| format!("{}..{}", field.offset, field.offset + field.len))
+            .collect();
+        let range_width = ranges.iter().map(String::len).max().unwrap_or(0);
+        for (field, range) in fields.iter().zip(&ranges) {
+            let _ = write!(
+                out,
+                "\n{} {:<name$}  {:<range$}  {}",
+                field.style.wrap("■"),
+                field.name,
+                range,
+                value(field),
+                name = name_width.unwrap_or(0),
+                range = range_width,
+            );
+        }
+        out
+    }
+
+    /// Renders `bytes` with each byte styled by `style`, called with its index in `bytes`.
+    fn render_styled(&self, bytes: &[u8], style: impl Fn(usize, u8) -> Style) -> String {
+        let mut out = String::new();
+        for (row, chunk) in bytes.chunks(self.width).enumerate() {
+            if row > 0 {
+                out.push('\n');
+            }
+            let first = row * self.width;
+            let mut line = StyledText::new();
+            if self.offsets {
+                line.push(format!("{:08x}  ", self.start + first), Style::DIM);
+            }
+            let mut previous = None;
+            let mut ascii = StyledText::new();
+            for (column, &byte) in chunk.iter().enumerate() {
+                let own = style(first + column, byte);
+                if column > 0 {
+                    let gap = if column % 8 == 0 { "  " } else { " " };
+                    let joined = if previous == Some(own) {
+                        own
+                    } else {
+                        Style::new()
+                    };
+                    line.push(gap, joined);
+                }
+                line.push(format!("{:02x}", byte), own);
+                let shown = if byte.is_ascii_graphic() || byte == b' ' {
+                    char::from(byte)
+                } else {
+                    '.'
+                };
+                ascii.push(shown, own);
+                previous = Some(own);
+            }
+            if self.ascii {
+                let missing = self.width - chunk.len();
+                let padding = 3 * missing + (self.width - 1) / 8 - (chunk.len().max(1) - 1) / 8;
+                line.push(" ".repeat(padding + 2), Style::new());
+                line.push("|", Style::DIM);
+                line.append(ascii);
+                line.push("|", Style::DIM);
+            }
+            out.push_str(&line.render());
+        }
+        out
+    }
+}
+
+/// Returns the style of a byte in a plain dump.
+fn class_style(byte: u8) -> Style {
+    match byte {
+        0 => Style::new().fg(Color::BrightBlack),
+        b' ' | b'\t' | b'\n' | b'\r' | 0x0b | 0x0c => Style::new().fg(Color::Green),
+        byte if byte.is_ascii_graphic() => Style::new().fg(Color::Cyan),
+        byte if byte.is_ascii() => Style::new().fg(Color::Magenta),
+        _ => Style::new().fg(Color::Yellow),
+    }
+}
+
+/// A named field of a binary record, see [`Layout`] and [`HexDump::render_annotated`].
+#[derive(Debug, Clone, PartialEq, Eq, Hash)]
+pub struct Field {
+    name: String,
+    offset: usize,
+    len: usize,
+    style: Style,
+}
+
+impl Field {
+    /// Creates a field of `len` bytes starting at `offset`.
+    pub fn new(name: impl Into<String>, offset: usize, len: usize, style: Style) -> Self {
+        Field {
+            name: name.into(),
+            offset,
+            len,
+            style,
+        }
+    }
+
+    /// Returns the name of the field.
+    pub fn name(&self) -> &str {
+        &self.name
+    }
+
+    /// Returns the byte range of the field in the record.
+    pub fn range(&self) -> Range<usize> {
+        self.offset..self.offset + self.len
+    }
+
+    /// Returns the style of the field.
+    pub fn style(&self) -> Style {
+        self.style
+    }
+}
+
+/// The structure of a binary record: a list of fields, rendered as a hex dump with each field in its
+/// own style and a legend listing them.
+///
+/// Bytes not covered by any field are dimmed; where fields overlap, the one added first wins.
+///
+/// # Examples
+///
+/// ```rust
+/// use bytescolor::hexdump::Layout;
+/// use bytescolor::{ansi, Style};
+///
+/// let packet = Layout::new()
+///     .field("type", 0, 1, Style::CYAN)
+///     .field("length", 1, 2, Style::GREEN)
+///     .field("payload", 3, 2, Style::YELLOW);
+/// let out = ansi::strip(&packet.render(b"\x01\x00\x02hi")).into_owned();
+/// let mut lines = out.lines();
+/// assert_eq!(lines.next(), Some("00000000  01 00 02 68 69                                    |...hi|"));
+/// assert_eq!(lines.next(), Some(""));
+/// assert_eq!(lines.next(), Some("■ type     0..1  01"));
+/// assert_eq!(lines.next(), Some("■ length   1..3  00 02"));
+/// assert_eq!(lines.next(), Some("■ payload  3..5  68 69"));
+/// ```
+#[derive(Debug, Clone, PartialEq, Eq, Hash, Default)]
+pub struct Layout {
+    fields: Vec<Field>,
+    dump: HexDump,
+}
+
+impl Layout {
+    /// Creates a layout without fields, rendered with the default [`HexDump`] settings.
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// Adds a field of `len` bytes starting at `offset`.
+    pub fn field(self, name: impl Into<String>, offset: usize, len: usize, style: Style) -> Self {
+        self.push(Field::new(name, offset, len, style))
+    }
+
+    /// Adds a field.
+    pub fn push(mut self, field: Field) -> Self {
+        self.fields.push(field);
+        self
+    }
+
+    /// Sets the hex dump settings.
+    pub fn hexdump(mut self, dump: HexDump) -> Self {
+        self.dump = dump;
+        self
+    }
+
+    /// Returns the fields of the layout.
+    pub fn fields(&self) -> &[Field] {
+        &self.fields
+    }
+
+    /// Renders `record` followed by a blank line and the legend, without a final newline. The legend
+    /// shows the range and the bytes of each field, at most 8 of them; fields past the end of the
+    /// record are marked as missing.
+    pub fn render(&self, record: &[u8]) -> String {
+        self.dump.render_fields(record, &self.fields, |field| {
+            let end = field.range().end;
+            match record.get(field.offset..end.min(record.len())) {
+                Some(bytes) if end <= record.len() => {
+                    let shown: Vec<String> =
+                        bytes.iter().take(8).map(|b| format!("{:02x}", b)).collect();
+                    let mut value = shown.join(" ");
+                    if bytes.len() > 8 {
+                        value.push_str(" …");
+                    }
+                    field.style.wrap(&value)
+                }
+                _ => Style::DIM.wrap("(missing)"),
+            }
+        })
+    }
+}
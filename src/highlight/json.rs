@@ -0,0 +1,359 @@
+//! # JSON Highlighting
+//!
+//! Pretty-prints and colorizes JSON text, turning compact API responses into readable terminal output.
+//! Keys, strings, numbers, booleans and `null` each get their own configurable [`Style`].
+//!
+//! This module is available with the `json` feature.
+//!
+//! ```rust
+//! use bytescolor::highlight::json;
+//!
+//! let response = r#"{"id":7,"name":"widget","tags":["a","b"],"stock":null}"#;
+//! println!("{}", json::pretty(response).unwrap());
+//! ```
+
+use std::error::Error;
+use std::fmt;
+
+use crate::{Color, Style};
+
+/// Maximum nesting depth accepted before giving up, protecting against stack exhaustion on hostile input.
+const MAX_DEPTH: usize = 512;
+
+/// The styles applied to each kind of JSON token.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub struct JsonStyles {
+    /// Object keys.
+    pub key: Style,
+    /// String values.
+    pub string: Style,
+    /// Number values.
+    pub number: Style,
+    /// `true` and `false`.
+    pub boolean: Style,
+    /// `null`.
+    pub null: Style,
+    /// Braces, brackets, colons and commas.
+    pub punctuation: Style,
+}
+
+impl Default for JsonStyles {
+    fn default() -> Self {
+        JsonStyles {
+            key: Style::new().fg(Color::Blue).bold(),
+            string: Style::new().fg(Color::Green),
+            number: Style::new().fg(Color::Yellow),
+            boolean: Style::new().fg(Color::Magenta),
+            null: Style::new().dim(),
+            punctuation: Style::new(),
+        }
+    }
+}
+
+/// An error describing why the input is not valid JSON.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct JsonError {
+    message: &'static str,
+    offset: usize,
+}
+
+impl JsonError {
+    /// Returns the byte offset in the input at which the error was detected.
+    pub fn offset(&self) -> usize {
+        self.offset
+    }
+}
+
+impl fmt::Display for JsonError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(f, "{} at byte {}", self.message, self.offset)
+    }
+}
+
+impl Error for JsonError {}
+
+/// A configurable JSON pretty-printer.
+///
+/// # Examples
+///
+/// ```rust
+/// use bytescolor::highlight::json::{JsonFormatter, JsonStyles};
+/// use bytescolor::Style;
+///
+/// let plain = JsonStyles {
+///     key: Style::new(),
+///     string: Style::new(),
+///     number: Style::new(),
+///     boolean: Style::new(),
+///     null: Style::new(),
+///     punctuation: Style::new(),
+/// };
+/// let out = JsonFormatter::new().styles(plain).indent(4).format(r#"{"a":[1,true]}"#).unwrap();
+/// assert_eq!(out, "{\n    \"a\": [\n        1,\n        true\n    ]\n}");
+/// ```
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub struct JsonFormatter {
+    styles: JsonStyles,
+    indent: usize,
+}
+
+impl Default for JsonFormatter {
+    fn default() -> Self {
+        JsonFormatter {
+            styles: JsonStyles::default(),
+            indent: 2,
+        }
+    }
+}
+
+impl JsonFormatter {
+    /// Creates a formatter with the default styles and an indentation of two spaces.
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// Sets the styles applied to each kind of token.
+    pub fn styles(mut self, styles: JsonStyles) -> Self {
+        self.styles = styles;
+        self
+    }
+
+    /// Sets the number of spaces per nesting level.
+    pub fn indent(mut self, indent: usize) -> Self {
+        self.indent = indent;
+        self
+    }
+
+    /// Pretty-prints and colorizes `input`.
+    ///
+    /// Strings and numbers are reproduced exactly as written in the input; only the whitespace between
+    /// tokens is rewritten.
+    pub fn format(&self, input: &str) -> Result<String, JsonError> {
+        let mut printer = Printer {
+            src: input,
+            pos: 0,
+            out: String::with_capacity(input.len() * 2),
+            formatter: self,
+        };
+        printer.skip_ws();
+        printer.value(0)?;
+        printer.skip_ws();
+        if printer.pos != input.len() {
+            return Err(printer.error("trailing characters"));
+        }
+        Ok(printer.out)
+    }
+}
+
+/// Pretty-prints and colorizes `input` with the default [`JsonFormatter`].
+///
+/// # Examples
+///
+/// ```rust
+/// # if cfg!(feature = "no-color-build") { return; }
+/// use bytescolor::highlight::json;
+///
+/// let out = json::pretty("[null]").unwrap();
+/// assert_eq!(out, "[\n  \x1b[2mnull\x1b[0m\n]");
+/// assert!(json::pretty("{").is_err());
+/// ```
+pub fn pretty(input: &str) -> Result<String, JsonError> {
+    JsonFormatter::new().format(input)
+}
+
+struct Printer<'a> {
+    src: &'a str,
+    pos: usize,
+    out: String,
+    formatter: &'a JsonFormatter,
+}
+
+impl<'a> Printer<'a> {
+    fn error(&self, message: &'static str) -> JsonError {
+        JsonError {
+            message,
+            offset: self.pos,
+        }
+    }
+
+    fn peek(&self) -> Option<u8> {
+        self.src.as_bytes().get(self.pos).copied()
+    }
+
+    fn skip_ws(&mut self) {
+        while let Some(b' ' | b'\t' | b'\n' | b'\r') = self.peek() {
+            self.pos += 1;
+        }
+    }
+
+    fn newline(&mut self, depth: usize) {
+        self.out.push('\n');
+        self.out
+            .extend(std::iter::repeat(' ').take(depth * self.formatter.indent));
+    }
+
+    fn punct(&mut self, text: &str) {
+        let styled = self.formatter.styles.punctuation.wrap(text);
+        self.out.push_str(&styled);
+    }
+
+    fn emit(&mut self, style: Style, start: usize) {
+        let styled = style.wrap(&self.src[start..self.pos]);
+        self.out.push_str(&styled);
+    }
+
+    fn value(&mut self, depth: usize) -> Result<(), JsonError> {
+        if depth > MAX_DEPTH {
+            return Err(self.error("nesting too deep"));
+        }
+        match self.peek() {
+            Some(b'{') => self.container(depth, b'}', true),
+            Some(b'[') => self.container(depth, b']', false),
+            Some(b'"') => {
+                let start = self.pos;
+                self.string()?;
+                self.emit(self.formatter.styles.string, start);
+                Ok(())
+            }
+            Some(b'-' | b'0'..=b'9') => {
+                let start = self.pos;
+                self.number()?;
+                self.emit(self.formatter.styles.number, start);
+                Ok(())
+            }
+            Some(b't') => self.literal("true", self.formatter.styles.boolean),
+            Some(b'f') => self.literal("false", self.formatter.styles.boolean),
+            Some(b'n') => self.literal("null", self.formatter.styles.null),
+            Some(_) => Err(self.error("expected a value")),
+            None => Err(self.error("unexpected end of input")),
+        }
+    }
+
+    fn container(&mut self, depth: usize, close: u8, object: bool) -> Result<(), JsonError> {
+        let open = &self.src[self.pos..self.pos + 1];
+        self.punct(open);
+        self.pos += 1;
+        self.skip_ws();
+        if self.peek() == Some(close) {
+            self.pos += 1;
+            self.punct(if object { "}" } else { "]" });
+            return Ok(());
+        }
+        loop {
+            self.newline(depth + 1);
+            if object {
+                if self.peek() != Some(b'"') {
+                    return Err(self.error("expected an object key"));
+                }
+                let start = self.pos;
+                self.string()?;
+                self.emit(self.formatter.styles.key, start);
+                self.skip_ws();
+                if self.peek() != Some(b':') {
+                    return Err(self.error("expected ':'"));
+                }
+                self.pos += 1;
+                self.punct(":");
+                self.out.push(' ');
+                self.skip_ws();
+            }
+            self.value(depth + 1)?;
+            self.skip_ws();
+            match self.peek() {
+                Some(b',') => {
+                    self.pos += 1;
+                    self.punct(",");
+                    self.skip_ws();
+                }
+                Some(c) if c == close => {
+                    self.pos += 1;
+                    self.newline(depth);
+                    self.punct(if object { "}" } else { "]" });
+                    return Ok(());
+                }
+                Some(_) => return Err(self.error("expected ',' or a closing bracket")),
+                None => return Err(self.error("unexpected end of input")),
+            }
+        }
+    }
+
+    fn string(&mut self) -> Result<(), JsonError> {
+        self.pos += 1;
+        loop {
+            match self.peek() {
+                Some(b'"') => {
+                    self.pos += 1;
+                    return Ok(());
+                }
+                Some(b'\\') => {
+                    self.pos += 1;
+                    match self.peek() {
+                        Some(b'"' | b'\\' | b'/' | b'b' | b'f' | b'n' | b'r' | b't') => {
+                            self.pos += 1
+                        }
+                        Some(b'u') => {
+                            self.pos += 1;
+                            for _ in 0..4 {
+                                match self.peek() {
+                                    Some(c) if c.is_ascii_hexdigit() => self.pos += 1,
+                                    _ => return Err(self.error("invalid unicode escape")),
+                                }
+                            }
+                        }
+                        _ => return Err(self.error("invalid escape sequence")),
+                    }
+                }
+                Some(0x00..=0x1f) => return Err(self.error("control character in string")),
+                Some(_) => self.pos += 1,
+                None => return Err(self.error("unterminated string")),
+            }
+        }
+    }
+
+    fn digits(&mut self) -> usize {
+        let start = self.pos;
+        while let Some(b'0'..=b'9') = self.peek() {
+            self.pos += 1;
+        }
+        self.pos - start
+    }
+
+    fn number(&mut self) -> Result<(), JsonError> {
+        if self.peek() == Some(b'-') {
+            self.pos += 1;
+        }
+        match self.peek() {
+            Some(b'0') => self.pos += 1,
+            Some(b'1'..=b'9') => {
+                self.digits();
+            }
+            _ => return Err(self.error("invalid number")),
+        }
+        if self.peek() == Some(b'.') {
+            self.pos += 1;
+            if self.digits() == 0 {
+                return Err(self.error("invalid number"));
+            }
+        }
+        if let Some(b'e' | b'E') = self.peek() {
+            self.pos += 1;
+            if let Some(b'+' | b'-') = self.peek() {
+                self.pos += 1;
+            }
+            if self.digits() == 0 {
+                return Err(self.error("invalid number"));
+            }
+        }
+        Ok(())
+    }
+
+    fn literal(&mut self, word: &'static str, style: Style) -> Result<(), JsonError> {
+        if !self.src[self.pos..].starts_with(word) {
+            return Err(self.error("expected a value"));
+        }
+        let start = self.pos;
+        self.pos += word.len();
+        self.emit(style, start);
+        Ok(())
+    }
+}
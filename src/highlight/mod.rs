@@ -0,0 +1,75 @@
+//! # Highlighting
+//!
+//! Automatic highlighting of text. Where the [`ByteColor`](crate::ByteColor) methods style a whole value,
+//! the highlighters in this module decide *which fragments* of a text get which [`Style`](crate::Style).
+//!
+//! - [`rules`]: grep/grc-like highlighting of pattern matches in lines of output, and of keywords
+//!   with [`HighlightWords`].
+//! - `json`: pretty-printing and colorizing of JSON text (requires the `json` feature).
+//! - `toml`: colorizing of TOML documents (requires the `toml` feature).
+//! - `yaml`: colorizing of YAML documents (requires the `yaml` feature).
+//!
+//! This module is available with the `highlight` feature, enabled by default.
+
+#[cfg(feature = "json")]
+pub mod json;
+pub mod rules;
+#[cfg(feature = "toml")]
+pub mod toml;
+#[cfg(feature = "yaml")]
+pub mod yaml;
+
+pub use rules::{ApplyRules, HighlightWords, Rule, Rules};
+
+#[cfg(any(feature = "toml", feature = "yaml"))]
+use crate::{Color, Style};
+
+/// The styles applied by the configuration file highlighters (`toml` and `yaml`).
+#[cfg(any(feature = "toml", feature = "yaml"))]
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub struct ConfigStyles {
+    /// Table headers (`[server]`) and document markers (`---`).
+    pub section: Style,
+    /// Keys.
+    pub key: Style,
+    /// Quoted and unquoted string values.
+    pub string: Style,
+    /// Numbers and dates.
+    pub number: Style,
+    /// Booleans and null values.
+    pub boolean: Style,
+    /// Comments.
+    pub comment: Style,
+    /// Brackets, braces, separators and other structural characters.
+    pub punctuation: Style,
+}
+
+#[cfg(any(feature = "toml", feature = "yaml"))]
+impl Default for ConfigStyles {
+    fn default() -> Self {
+        ConfigStyles {
+            section: Style::new().fg(Color::Blue).bold(),
+            key: Style::new().fg(Color::Cyan),
+            string: Style::new().fg(Color::Green),
+            number: Style::new().fg(Color::Yellow),
+            boolean: Style::new().fg(Color::Magenta),
+            comment: Style::new().dim(),
+            punctuation: Style::new(),
+        }
+    }
+}
+
+/// Returns `true` if an unquoted scalar looks like a number or a date.
+#[cfg(any(feature = "toml", feature = "yaml"))]
+fn is_number(token: &str) -> bool {
+    let unsigned = token.strip_prefix(['+', '-']).unwrap_or(token);
+    if matches!(
+        unsigned,
+        "inf" | "nan" | ".inf" | ".Inf" | ".INF" | ".nan" | ".NaN" | ".NAN"
+    ) {
+        return true;
+    }
+    let mut bytes = unsigned.bytes();
+    bytes.next().is_some_and(|b| b.is_ascii_digit())
+        && bytes.all(|b| b.is_ascii_alphanumeric() || matches!(b, b'_' | b'.' | b':' | b'-' | b'+'))
+}
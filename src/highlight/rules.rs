@@ -0,0 +1,459 @@
+//! # Rule Engine
+//!
+//! A GREP_COLORS/grc-style rule engine: register `pattern → style` [`Rule`]s in a [`Rules`] set, then run lines
+//! of text through it to get every matching fragment highlighted automatically.
+//!
+//! ```rust
+//! use bytescolor::highlight::{ApplyRules, Rules};
+//! use bytescolor::{Color, Style};
+//!
+//! let rules = Rules::new()
+//!     .rule("ERROR", Style::new().fg(Color::Red).bold())
+//!     .rule("WARN", Style::new().fg(Color::Yellow));
+//!
+//! println!("{}", "12:00:01 ERROR disk full".apply_rules(&rules)); // "ERROR" displayed in bold red
+//! ```
+//!
+//! When matches of different rules overlap, the match that starts first wins; for matches starting at the
+//! same position, the rule registered first wins.
+//!
+//! With the `regex` feature, [`Rule::regex`] matches regular expressions, and [`Rule::group`] styles each
+//! capture group separately.
+//!
+//! For the common case of a few keywords, [`HighlightWords`] styles whole words regardless of case,
+//! without building a rule set:
+//!
+//! ```rust
+//! # if cfg!(feature = "no-color-build") { return; }
+//! use bytescolor::highlight::HighlightWords;
+//! use bytescolor::{Color, Style};
+//!
+//! let line = "Error: 2 tests failed, 1 warning";
+//! let styled = line.highlight_words(&["error", "warn", "fail"], Style::new().fg(Color::Red));
+//! assert_eq!(styled.as_str(), "\x1b[31mError\x1b[0m: 2 tests failed, 1 warning");
+//! ```
+
+use std::io::{self, BufRead, Write};
+
+use crate::{Style, StyledString};
+
+/// A single `pattern → style` association.
+///
+/// With the `regex` feature, a rule can also match a regular expression and give each of its capture
+/// groups a style of its own, splitting a log line into timestamp, level and message in one pass.
+#[derive(Debug, Clone)]
+pub struct Rule {
+    matcher: Matcher,
+    style: Style,
+    groups: Vec<(usize, Style)>,
+}
+
+#[derive(Debug, Clone)]
+enum Matcher {
+    Literal(String),
+    #[cfg(feature = "regex")]
+    Regex(regex::Regex),
+}
+
+/// A match of a rule: its byte range and the styled byte ranges of its capture groups.
+struct Match {
+    start: usize,
+    end: usize,
+    groups: Vec<(usize, usize, Style)>,
+}
+
+impl Rule {
+    /// Creates a rule highlighting every occurrence of the literal `pattern` with `style`.
+    pub fn new(pattern: impl Into<String>, style: Style) -> Self {
+        Rule {
+            matcher: Matcher::Literal(pattern.into()),
+            style,
+            groups: Vec::new(),
+        }
+    }
+
+    /// Creates a rule highlighting every match of the regular expression `pattern` with `style`.
+    ///
+    /// Requires the `regex` feature.
+    ///
+    /// # Errors
+    ///
+    /// Returns the parse error if `pattern` is not a valid regular expression.
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// use bytescolor::highlight::Rule;
+    /// use bytescolor::{Color, Style};
+    ///
+    /// let rule = Rule::regex(r"\d+ms", Style::new().fg(Color::Cyan))?;
+    /// assert_eq!(rule.pattern(), r"\d+ms");
+    /// # Ok::<(), regex::Error>(())
+    /// ```
+    #[cfg(feature = "regex")]
+    pub fn regex(pattern: &str, style: Style) -> Result<Self, regex::Error> {
+        Ok(Rule {
+            matcher: Matcher::Regex(regex::Regex::new(pattern)?),
+            style,
+            groups: Vec::new(),
+        })
+    }
+
+    /// Styles capture group `index` of each match with `style`, returning the updated rule. The rest
+    /// of the match keeps the rule's own style.
+    ///
+    /// Group `0` is the whole match. Groups that do not take part in a match, and groups of literal
+    /// rules, are ignored; of nested groups, the outer one wins. Requires the `regex` feature.
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// # if cfg!(feature = "no-color-build") { return Ok(()); }
+    /// use bytescolor::highlight::{Rule, Rules};
+    /// use bytescolor::{Color, Style};
+    ///
+    /// let line = Rule::regex(r"^(\S+) (ERROR|WARN|INFO) ", Style::new())?
+    ///     .group(1, Style::new().dim())
+    ///     .group(2, Style::new().fg(Color::Red).bold());
+    /// let rules: Rules = [line].into_iter().collect();
+    /// assert_eq!(
+    ///     rules.apply("12:00:01 ERROR disk full").as_str(),
+    ///     "\x1b[2m12:00:01\x1b[0m \x1b[1;31mERROR\x1b[0m disk full"
+    /// );
+    /// # Ok::<(), regex::Error>(())
+    /// ```
+    #[cfg(feature = "regex")]
+    pub fn group(mut self, index: usize, style: Style) -> Self {
+        self.groups.push((index, style));
+        self
+    }
+
+    /// Returns the pattern matched by this rule.
+    pub fn pattern(&self) -> &str {
+        match &self.matcher {
+            Matcher::Literal(pattern) => pattern,
+            #[cfg(feature = "regex")]
+            Matcher::Regex(regex) => regex.as_str(),
+        }
+    }
+
+    /// Returns the style applied to matches of this rule.
+    pub fn style(&self) -> Style {
+        self.style
+    }
+
+    /// Returns every non-overlapping, non-empty match of the rule in `line`.
+    fn matches(&self, line: &str) -> Vec<Match> {
+        match &self.matcher {
+            Matcher::Literal(pattern) if pattern.is_empty() => Vec::new(),
+            Matcher::Literal(pattern) => line
+                .match_indices(pattern.as_str())
+                .map(|(start, m)| Match {
+                    start,
+                    end: start + m.len(),
+                    groups: Vec::new(),
+                })
+                .collect(),
+            #[cfg(feature = "regex")]
+            Matcher::Regex(regex) if self.groups.is_empty() => regex
+                .find_iter(line)
+                .filter(|m| !m.is_empty())
+                .map(|m| Match {
+                    start: m.start(),
+                    end: m.end(),
+                    groups: Vec::new(),
+                })
+                .collect(),
+            #[cfg(feature = "regex")]
+            Matcher::Regex(regex) => regex
+                .captures_iter(line)
+                .filter_map(|captures| {
+                    let whole = captures.get(0).filter(|m| !m.is_empty())?;
+                    let mut groups: Vec<_> = self
+                        .groups
+                        .iter()
+                        .filter_map(|&(index, style)| {
+                            captures.get(index).map(|m| (m.start(), m.end(), style))
+                        })
+                        .collect();
+                    groups.sort_by_key(|&(start, end, _)| (start, std::cmp::Reverse(end)));
+                    Some(Match {
+                        start: whole.start(),
+                        end: whole.end(),
+                        groups,
+                    })
+                })
+                .collect(),
+        }
+    }
+
+    /// Appends the styled text of `m` to `out`.
+    fn paint(&self, line: &str, m: &Match, out: &mut String) {
+        let mut pos = m.start;
+        for &(start, end, style) in &m.groups {
+            if start < pos {
+                continue;
+            }
+            if start > pos {
+                out.push_str(&self.style.wrap(&line[pos..start]));
+            }
+            out.push_str(&style.wrap(&line[start..end]));
+            pos = end;
+        }
+        if pos < m.end {
+            out.push_str(&self.style.wrap(&line[pos..m.end]));
+        }
+    }
+}
+
+impl PartialEq for Rule {
+    fn eq(&self, other: &Self) -> bool {
+        let is_literal = |rule: &Rule| matches!(rule.matcher, Matcher::Literal(_));
+        is_literal(self) == is_literal(other)
+            && self.pattern() == other.pattern()
+            && self.style == other.style
+            && self.groups == other.groups
+    }
+}
+
+impl Eq for Rule {}
+
+/// An ordered set of highlighting [`Rule`]s.
+///
+/// # Examples
+///
+/// ```rust
+/// # if cfg!(feature = "no-color-build") { return; }
+/// use bytescolor::highlight::Rules;
+/// use bytescolor::{Color, Style};
+///
+/// let rules = Rules::new().rule("ok", Style::new().fg(Color::Green));
+/// assert_eq!(rules.apply("all ok").as_str(), "all \x1b[32mok\x1b[0m");
+/// ```
+#[derive(Debug, Clone, Default, PartialEq, Eq)]
+pub struct Rules {
+    rules: Vec<Rule>,
+}
+
+impl Rules {
+    /// Creates an empty rule set.
+    pub fn new() -> Self {
+        Rules { rules: Vec::new() }
+    }
+
+    /// Registers a rule highlighting every occurrence of `pattern` with `style`, returning the updated set.
+    pub fn rule(mut self, pattern: impl Into<String>, style: Style) -> Self {
+        self.push(Rule::new(pattern, style));
+        self
+    }
+
+    /// Appends an already constructed rule.
+    pub fn push(&mut self, rule: Rule) {
+        self.rules.push(rule);
+    }
+
+    /// Returns the number of registered rules.
+    pub fn len(&self) -> usize {
+        self.rules.len()
+    }
+
+    /// Returns `true` if no rule is registered.
+    pub fn is_empty(&self) -> bool {
+        self.rules.is_empty()
+    }
+
+    /// Returns an iterator over the registered rules, in registration order.
+    pub fn iter(&self) -> std::slice::Iter<'_, Rule> {
+        self.rules.iter()
+    }
+
+    /// Highlights every fragment of `line` matched by a rule.
+    pub fn apply(&self, line: &str) -> StyledString {
+        let mut matches: Vec<(usize, usize, Match)> = Vec::new();
+        for (index, rule) in self.rules.iter().enumerate() {
+            matches.extend(rule.matches(line).into_iter().map(|m| (m.start, index, m)));
+        }
+        matches.sort_unstable_by_key(|&(start, index, _)| (start, index));
+
+        let mut out = String::with_capacity(line.len());
+        let mut pos = 0;
+        for (start, index, m) in matches {
+            if start < pos {
+                continue;
+            }
+            out.push_str(&line[pos..start]);
+            self.rules[index].paint(line, &m, &mut out);
+            pos = m.end;
+        }
+        out.push_str(&line[pos..]);
+        StyledString::from(out)
+    }
+
+    /// Highlights a stream line by line, writing the result to `writer` as soon as each line is read.
+    ///
+    /// Line terminators are preserved and never styled, and invalid UTF-8 is replaced with `U+FFFD`,
+    /// so this can sit directly on the output of another program.
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// # if cfg!(feature = "no-color-build") { return; }
+    /// use bytescolor::highlight::Rules;
+    /// use bytescolor::{Color, Style};
+    ///
+    /// let rules = Rules::new().rule("FAIL", Style::new().fg(Color::Red));
+    /// let mut out = Vec::new();
+    /// rules.apply_stream(&b"test a ... ok\ntest b ... FAIL\n"[..], &mut out).unwrap();
+    /// assert_eq!(out, b"test a ... ok\ntest b ... \x1b[31mFAIL\x1b[0m\n");
+    /// ```
+    pub fn apply_stream<R: BufRead, W: Write>(
+        &self,
+        mut reader: R,
+        mut writer: W,
+    ) -> io::Result<()> {
+        let mut buf = Vec::new();
+        loop {
+            buf.clear();
+            if reader.read_until(b'\n', &mut buf)? == 0 {
+                return writer.flush();
+            }
+            let body_len = buf
+                .strip_suffix(b"\r\n")
+                .or_else(|| buf.strip_suffix(b"\n"))
+                .map_or(buf.len(), <[u8]>::len);
+            let line = String::from_utf8_lossy(&buf[..body_len]);
+            writer.write_all(self.apply(&line).as_bytes())?;
+            writer.write_all(&buf[body_len..])?;
+        }
+    }
+}
+
+impl<'a> IntoIterator for &'a Rules {
+    type Item = &'a Rule;
+    type IntoIter = std::slice::Iter<'a, Rule>;
+
+    fn into_iter(self) -> Self::IntoIter {
+        self.iter()
+    }
+}
+
+impl FromIterator<Rule> for Rules {
+    fn from_iter<I: IntoIterator<Item = Rule>>(iter: I) -> Self {
+        Rules {
+            rules: iter.into_iter().collect(),
+        }
+    }
+}
+
+/// Extension trait running text through a [`Rules`] set.
+pub trait ApplyRules {
+    /// Highlights every fragment matched by one of `rules`.
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// use bytescolor::highlight::{ApplyRules, Rules};
+    /// use bytescolor::{Color, Style};
+    ///
+    /// let rules = Rules::new().rule("GET", Style::new().fg(Color::Cyan));
+    /// println!("{}", "GET /index.html 200".apply_rules(&rules)); // "GET" displayed in cyan
+    /// ```
+    fn apply_rules(&self, rules: &Rules) -> StyledString;
+}
+
+impl ApplyRules for str {
+    fn apply_rules(&self, rules: &Rules) -> StyledString {
+        rules.apply(self)
+    }
+}
+
+impl ApplyRules for [u8] {
+    fn apply_rules(&self, rules: &Rules) -> StyledString {
+        rules.apply(&String::from_utf8_lossy(self))
+    }
+}
+
+/// Extension trait highlighting keywords, a simpler alternative to [`Rules`] for log viewers.
+pub trait HighlightWords {
+    /// Styles every whole-word occurrence of one of `words`, compared case-insensitively, keeping the
+    /// case of the text. A word is a run of alphanumeric characters and underscores, so `fail` does not
+    /// match `failed`; at a position where several words match, the longest wins.
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// # if cfg!(feature = "no-color-build") { return; }
+    /// use bytescolor::highlight::HighlightWords;
+    /// use bytescolor::{ansi, Color, Style};
+    ///
+    /// let style = Style::new().fg(Color::Yellow);
+    /// let styled = "WARN: warnings are not WARN_ONCE".highlight_words(&["warn"], style);
+    /// assert_eq!(styled.as_str(), "\x1b[33mWARN\x1b[0m: warnings are not WARN_ONCE");
+    /// assert_eq!(
+    ///     "timed out, Timed Out".highlight_words(&["timed out"], style).as_str(),
+    ///     "\x1b[33mtimed out\x1b[0m, \x1b[33mTimed Out\x1b[0m"
+    /// );
+    /// ```
+    fn highlight_words(&self, words: &[&str], style: Style) -> StyledString;
+}
+
+impl HighlightWords for str {
+    fn highlight_words(&self, words: &[&str], style: Style) -> StyledString {
+        let mut out = String::with_capacity(self.len());
+        let mut pos = 0;
+        let mut previous = None;
+        for (start, c) in self.char_indices() {
+            if start < pos {
+                previous = Some(c);
+                continue;
+            }
+            let matched = if previous.map_or(true, |p| !is_word(p)) {
+                words
+                    .iter()
+                    .filter_map(|word| word_match(&self[start..], word))
+                    .max()
+            } else {
+                None
+            };
+            if let Some(len) = matched {
+                out.push_str(&self[pos..start]);
+                out.push_str(&style.wrap(&self[start..start + len]));
+                pos = start + len;
+            }
+            previous = Some(c);
+        }
+        out.push_str(&self[pos..]);
+        StyledString::from(out)
+    }
+}
+
+impl HighlightWords for [u8] {
+    fn highlight_words(&self, words: &[&str], style: Style) -> StyledString {
+        String::from_utf8_lossy(self).highlight_words(words, style)
+    }
+}
+
+/// Returns `true` for the characters words are made of.
+fn is_word(c: char) -> bool {
+    c.is_alphanumeric() || c == '_'
+}
+
+/// Returns the byte length of the start of `text` matching `word` case-insensitively, if it is not
+/// followed by another word character.
+fn word_match(text: &str, word: &str) -> Option<usize> {
+    if word.is_empty() {
+        return None;
+    }
+    let mut chars = text.char_indices();
+    for expected in word.chars() {
+        let (_, c) = chars.next()?;
+        if !c.to_lowercase().eq(expected.to_lowercase()) {
+            return None;
+        }
+    }
+    match chars.next() {
+        Some((_, c)) if is_word(c) => None,
+        Some((end, _)) => Some(end),
+        None => Some(text.len()),
+    }
+}
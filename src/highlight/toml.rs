@@ -0,0 +1,174 @@
+//! # TOML Highlighting
+//!
+//! Colorizes TOML documents: table headers, keys, strings, numbers (including dates), booleans and comments.
+//! The input layout is preserved byte for byte, and malformed input is highlighted on a best-effort basis
+//! rather than rejected, which makes it suitable for dumping configuration files as they are.
+//!
+//! This module is available with the `toml` feature.
+//!
+//! ```rust
+//! use bytescolor::highlight::toml;
+//!
+//! let config = "[server]\nport = 8080 # default\nhost = \"localhost\"\n";
+//! println!("{}", toml::highlight(config));
+//! ```
+
+use super::{is_number, ConfigStyles};
+
+/// Highlights `input` with the default [`ConfigStyles`].
+///
+/// # Examples
+///
+/// ```rust
+/// # if cfg!(feature = "no-color-build") { return; }
+/// use bytescolor::highlight::toml;
+///
+/// assert_eq!(toml::highlight("on = true"), "\x1b[36mon\x1b[0m = \x1b[35mtrue\x1b[0m");
+/// ```
+pub fn highlight(input: &str) -> String {
+    highlight_with(input, &ConfigStyles::default())
+}
+
+/// Highlights `input` with custom `styles`.
+pub fn highlight_with(input: &str, styles: &ConfigStyles) -> String {
+    let mut out = String::with_capacity(input.len() * 2);
+    // Open `[` and `{` of the value currently being scanned.
+    let mut stack: Vec<u8> = Vec::new();
+    let mut expect_key = true;
+    let bytes = input.as_bytes();
+    let mut pos = 0;
+
+    while pos < bytes.len() {
+        let start = pos;
+        match bytes[pos] {
+            b'\n' => {
+                pos += 1;
+                out.push('\n');
+                if stack.is_empty() {
+                    expect_key = true;
+                }
+            }
+            b' ' | b'\t' | b'\r' => {
+                pos += 1;
+                out.push_str(&input[start..pos]);
+            }
+            b'#' => {
+                pos = line_end(bytes, pos);
+                out.push_str(&styles.comment.wrap(&input[start..pos]));
+            }
+            b'[' if expect_key && stack.is_empty() => {
+                let end = line_end(bytes, pos);
+                pos = input[pos..end]
+                    .find(']')
+                    .map_or(end, |close| pos + close + 1);
+                if bytes.get(pos) == Some(&b']') {
+                    pos += 1;
+                }
+                out.push_str(&styles.section.wrap(&input[start..pos]));
+                expect_key = false;
+            }
+            b'"' | b'\'' => {
+                pos = string_end(bytes, pos);
+                let style = if expect_key {
+                    styles.key
+                } else {
+                    styles.string
+                };
+                out.push_str(&style.wrap(&input[start..pos]));
+            }
+            b'=' => {
+                pos += 1;
+                out.push_str(&styles.punctuation.wrap("="));
+                expect_key = false;
+            }
+            open @ (b'[' | b'{') => {
+                pos += 1;
+                stack.push(open);
+                expect_key = open == b'{';
+                out.push_str(&styles.punctuation.wrap(&input[start..pos]));
+            }
+            b']' | b'}' => {
+                pos += 1;
+                stack.pop();
+                out.push_str(&styles.punctuation.wrap(&input[start..pos]));
+            }
+            b',' => {
+                pos += 1;
+                expect_key = stack.last() == Some(&b'{');
+                out.push_str(&styles.punctuation.wrap(","));
+            }
+            b'.' if expect_key => {
+                pos += 1;
+                out.push_str(&styles.punctuation.wrap("."));
+            }
+            _ => {
+                while pos < bytes.len() && is_bare(bytes[pos], expect_key) {
+                    pos += 1;
+                }
+                if pos == start {
+                    // Not part of any token, copy the whole character.
+                    pos += input[pos..].chars().next().map_or(1, char::len_utf8);
+                    out.push_str(&input[start..pos]);
+                    continue;
+                }
+                let token = &input[start..pos];
+                let style = if expect_key {
+                    styles.key
+                } else if token == "true" || token == "false" {
+                    styles.boolean
+                } else if is_number(token) {
+                    styles.number
+                } else {
+                    styles.string
+                };
+                out.push_str(&style.wrap(token));
+            }
+        }
+    }
+    out
+}
+
+/// Returns `true` for bytes that may appear in a bare key (`in_key`) or in an unquoted value.
+fn is_bare(b: u8, in_key: bool) -> bool {
+    b.is_ascii_alphanumeric()
+        || b == b'_'
+        || b == b'-'
+        || (!in_key && matches!(b, b'.' | b'+' | b':'))
+}
+
+fn line_end(bytes: &[u8], pos: usize) -> usize {
+    bytes[pos..]
+        .iter()
+        .position(|&b| b == b'\n')
+        .map_or(bytes.len(), |n| pos + n)
+}
+
+/// Returns the position just past the string starting at `pos`, handling multi-line `"""`/`'''` strings
+/// and backslash escapes in basic strings.
+fn string_end(bytes: &[u8], pos: usize) -> usize {
+    let quote = bytes[pos];
+    let multi = bytes[pos..].starts_with(&[quote; 3]);
+    let mut i = pos + if multi { 3 } else { 1 };
+    while i < bytes.len() {
+        match bytes[i] {
+            b'\\' if quote == b'"' => i += 2,
+            b'\n' if !multi => return i,
+            b if b == quote => {
+                if !multi {
+                    return i + 1;
+                }
+                if bytes[i..].starts_with(&[quote; 3]) {
+                    // Up to two extra quotes right before the delimiter belong to the content.
+                    let mut end = i + 3;
+                    while end < bytes.len() && end < i + 5 && bytes[end] == quote {
+                        end += 1;
+                    }
+                    return end;
+                }
+                i += 1;
+            }
+            _ => i += 1,
+        }
+    }
+    bytes.len().min(i)
+}
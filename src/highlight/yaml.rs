@@ -0,0 +1,216 @@
+//! # YAML Highlighting
+//!
+//! Colorizes YAML documents: document markers, mapping keys, scalars (strings, numbers, booleans and null),
+//! block scalars and comments. Like the TOML highlighter, the input layout is preserved exactly and
+//! unrecognized constructs are passed through unstyled.
+//!
+//! Document markers (`---`, `...`) use the `section` style, and `null`/`~` use the `boolean` style.
+//!
+//! This module is available with the `yaml` feature.
+//!
+//! ```rust
+//! use bytescolor::highlight::yaml;
+//!
+//! let config = "---\nserver:\n  port: 8080 # default\n  hosts: [a, b]\n";
+//! println!("{}", yaml::highlight(config));
+//! ```
+
+use super::{is_number, ConfigStyles};
+
+/// Highlights `input` with the default [`ConfigStyles`].
+///
+/// # Examples
+///
+/// ```rust
+/// # if cfg!(feature = "no-color-build") { return; }
+/// use bytescolor::highlight::yaml;
+///
+/// assert_eq!(yaml::highlight("- on: yes"), "- \x1b[36mon\x1b[0m: \x1b[35myes\x1b[0m");
+/// ```
+pub fn highlight(input: &str) -> String {
+    highlight_with(input, &ConfigStyles::default())
+}
+
+/// Highlights `input` with custom `styles`.
+pub fn highlight_with(input: &str, styles: &ConfigStyles) -> String {
+    let mut out = String::with_capacity(input.len() * 2);
+    // Indentation of the line that introduced the block scalar currently being read, if any.
+    let mut block_scalar: Option<usize> = None;
+
+    for line in input.split_inclusive('\n') {
+        let (body, eol) = match line.strip_suffix('\n') {
+            Some(body) => (body.strip_suffix('\r').unwrap_or(body), &line[body.len()..]),
+            None => (line, ""),
+        };
+        let indent = body.len() - body.trim_start_matches(' ').len();
+
+        if let Some(parent) = block_scalar {
+            if body.trim().is_empty() || indent > parent {
+                out.push_str(&body[..indent]);
+                out.push_str(&styles.string.wrap(&body[indent..]));
+                out.push_str(eol);
+                continue;
+            }
+            block_scalar = None;
+        }
+
+        let rest = &body[indent..];
+        out.push_str(&body[..indent]);
+        if rest == "---" || rest == "..." || rest.starts_with("--- ") {
+            out.push_str(&styles.section.wrap(&rest[..3]));
+            if rest.len() > 3 {
+                value(&mut out, &rest[3..], styles);
+            }
+        } else if line_starts_block(rest, &mut out, styles) {
+            block_scalar = Some(indent);
+        }
+        out.push_str(eol);
+    }
+    out
+}
+
+/// Highlights the content of a line after its indentation. Returns `true` if the line opens a block scalar.
+fn line_starts_block(mut rest: &str, out: &mut String, styles: &ConfigStyles) -> bool {
+    // Sequence entry markers, possibly nested ("- - item").
+    while rest == "-" || rest.starts_with("- ") {
+        out.push_str(&styles.punctuation.wrap("-"));
+        let after = &rest[1..];
+        let trimmed = after.trim_start_matches(' ');
+        out.push_str(&after[..after.len() - trimmed.len()]);
+        rest = trimmed;
+    }
+    if let Some(colon) = key_end(rest) {
+        out.push_str(&styles.key.wrap(&rest[..colon]));
+        out.push_str(&styles.punctuation.wrap(":"));
+        rest = &rest[colon + 1..];
+    }
+    value(out, rest, styles)
+}
+
+/// Finds the `:` terminating a mapping key at the start of `text`.
+fn key_end(text: &str) -> Option<usize> {
+    if text.starts_with('#') {
+        return None;
+    }
+    let bytes = text.as_bytes();
+    let search_from = match bytes.first() {
+        Some(&q @ (b'"' | b'\'')) => quoted_end(bytes, 0, q),
+        Some(b'[' | b'{') => return None,
+        _ => 0,
+    };
+    let mut i = search_from;
+    while i < bytes.len() {
+        match bytes[i] {
+            b':' if i + 1 == bytes.len() || bytes[i + 1] == b' ' => return Some(i),
+            b'#' if i > 0 && bytes[i - 1] == b' ' => return None,
+            _ => i += 1,
+        }
+    }
+    None
+}
+
+fn quoted_end(bytes: &[u8], start: usize, quote: u8) -> usize {
+    let mut i = start + 1;
+    while i < bytes.len() {
+        match bytes[i] {
+            b'\\' if quote == b'"' => i += 2,
+            b'\'' if quote == b'\'' && bytes.get(i + 1) == Some(&b'\'') => i += 2,
+            b if b == quote => return i + 1,
+            _ => i += 1,
+        }
+    }
+    bytes.len()
+}
+
+/// Highlights a value (everything after `key:` or a sequence marker). Returns `true` if it is a block
+/// scalar indicator (`|`, `>`, with optional chomping/indentation modifiers).
+fn value(out: &mut String, text: &str, styles: &ConfigStyles) -> bool {
+    let trimmed = text.trim_start_matches(' ');
+    out.push_str(&text[..text.len() - trimmed.len()]);
+    let (content, comment) = split_comment(trimmed);
+    let scalar = content.trim_end();
+    let trailing = &content[scalar.len()..];
+
+    let block = matches!(scalar.as_bytes().first(), Some(b'|' | b'>'))
+        && scalar[1..]
+            .bytes()
+            .all(|b| matches!(b, b'+' | b'-' | b'0'..=b'9'));
+    if block {
+        out.push_str(&styles.punctuation.wrap(scalar));
+    } else if scalar.starts_with('[') || scalar.starts_with('{') {
+        flow(out, scalar, styles);
+    } else if !scalar.is_empty() {
+        out.push_str(&scalar_style(scalar, styles).wrap(scalar));
+    }
+    out.push_str(trailing);
+    if !comment.is_empty() {
+        out.push_str(&styles.comment.wrap(comment));
+    }
+    block
+}
+
+/// Splits `text` into content and a trailing `# comment`, ignoring `#` inside quotes.
+fn split_comment(text: &str) -> (&str, &str) {
+    let bytes = text.as_bytes();
+    let mut i = 0;
+    while i < bytes.len() {
+        match bytes[i] {
+            q @ (b'"' | b'\'') if i == 0 || matches!(bytes[i - 1], b' ' | b'[' | b'{' | b',') => {
+                i = quoted_end(bytes, i, q);
+            }
+            b'#' if i == 0 || bytes[i - 1] == b' ' => return (&text[..i], &text[i..]),
+            _ => i += 1,
+        }
+    }
+    (text, "")
+}
+
+/// Highlights a flow collection such as `[a, b]` or `{k: v}`.
+fn flow(out: &mut String, text: &str, styles: &ConfigStyles) {
+    let bytes = text.as_bytes();
+    let mut i = 0;
+    while i < bytes.len() {
+        let start = i;
+        match bytes[i] {
+            b'[' | b']' | b'{' | b'}' | b',' | b':' => {
+                i += 1;
+                out.push_str(&styles.punctuation.wrap(&text[start..i]));
+            }
+            b' ' => {
+                i += 1;
+                out.push(' ');
+            }
+            q @ (b'"' | b'\'') => {
+                i = quoted_end(bytes, i, q);
+                let is_key = bytes.get(i) == Some(&b':');
+                let style = if is_key { styles.key } else { styles.string };
+                out.push_str(&style.wrap(&text[start..i]));
+            }
+            _ => {
+                while i < bytes.len() && !matches!(bytes[i], b'[' | b']' | b'{' | b'}' | b',') {
+                    if bytes[i] == b':' && matches!(bytes.get(i + 1), None | Some(b' ')) {
+                        break;
+                    }
+                    i += 1;
+                }
+                let token = text[start..i].trim_end();
+                let style = if bytes.get(i) == Some(&b':') {
+                    styles.key
+                } else {
+                    scalar_style(token, styles)
+                };
+                out.push_str(&style.wrap(token));
+                out.push_str(&text[start + token.len()..i]);
+            }
+        }
+    }
+}
+
+fn scalar_style(scalar: &str, styles: &ConfigStyles) -> crate::Style {
+    match scalar {
+        "true" | "false" | "True" | "False" | "TRUE" | "FALSE" | "yes" | "no" | "on" | "off"
+        | "null" | "Null" | "NULL" | "~" => styles.boolean,
+        _ if is_number(scalar) => styles.number,
+        _ => styles.string,
+    }
+}
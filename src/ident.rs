@@ -0,0 +1,156 @@
+//! # Identifiers
+//!
+//! Colors the structural segments of UUIDs and MAC addresses, so logs full of identifiers become
+//! scannable: in a UUID the time fields are cyan, with the version digit bold, the clock sequence yellow
+//! and the node magenta; in a MAC address the OUI (vendor) octets are blue and the NIC octets green.
+//! Separators are dimmed.
+//!
+//! ```rust
+//! use bytescolor::ident;
+//!
+//! let line = "session 123e4567-e89b-12d3-a456-426614174000 from 00:1a:2b:3c:4d:5e";
+//! println!("{}", ident::highlight(line));
+//! ```
+//!
+//! This module is available with the `basic` feature, enabled by default.
+
+use crate::{Color, Style, StyledString};
+
+const TIME: Style = Style::new().fg(Color::Cyan);
+const VERSION: Style = Style::new().fg(Color::Cyan).bold();
+const CLOCK: Style = Style::new().fg(Color::Yellow);
+const NODE: Style = Style::new().fg(Color::Magenta);
+const OUI: Style = Style::new().fg(Color::Blue);
+const NIC: Style = Style::new().fg(Color::Green);
+const SEPARATOR: Style = Style::new().dim();
+
+/// The length of a hyphenated UUID.
+const UUID_LEN: usize = 36;
+/// The length of a MAC address written as six separated octets.
+const MAC_LEN: usize = 17;
+
+/// Colors an identifier, if the text is one.
+type Colorizer = fn(&str) -> Option<StyledString>;
+
+/// The identifiers [`highlight`] looks for, with their length.
+const MATCHERS: [(usize, Colorizer); 2] = [(UUID_LEN, uuid), (MAC_LEN, mac)];
+
+/// Colors a hyphenated UUID, or returns `None` if `text` is not one. Both cases of hex digits are
+/// accepted.
+///
+/// # Examples
+///
+/// ```rust
+/// use bytescolor::ident;
+///
+/// let id = ident::uuid("123e4567-e89b-12d3-a456-426614174000").unwrap();
+/// assert_eq!(id, "123e4567-e89b-12d3-a456-426614174000");
+/// assert!(ident::uuid("123e4567").is_none());
+/// ```
+pub fn uuid(text: &str) -> Option<StyledString> {
+    if !is_uuid(text.as_bytes()) {
+        return None;
+    }
+    let dash = SEPARATOR.wrap('-');
+    let out = [
+        TIME.wrap(&text[0..8]),
+        TIME.wrap(&text[9..13]),
+        VERSION.wrap(&text[14..15]) + &TIME.wrap(&text[15..18]),
+        CLOCK.wrap(&text[19..23]),
+        NODE.wrap(&text[24..36]),
+    ]
+    .join(&dash);
+    Some(StyledString::from(out))
+}
+
+/// Colors a MAC address written as six octets separated by `:` or `-`, or returns `None` if `text` is
+/// not one.
+///
+/// # Examples
+///
+/// ```rust
+/// use bytescolor::ident;
+///
+/// assert_eq!(ident::mac("00-1A-2B-3C-4D-5E").unwrap(), "00-1A-2B-3C-4D-5E");
+/// assert!(ident::mac("00:1a:2b").is_none());
+/// ```
+pub fn mac(text: &str) -> Option<StyledString> {
+    if !is_mac(text.as_bytes()) {
+        return None;
+    }
+    let separator = SEPARATOR.wrap(&text[2..3]);
+    let octets: Vec<String> = (0..6)
+        .map(|index| {
+            let octet = &text[3 * index..3 * index + 2];
+            if index < 3 {
+                OUI.wrap(octet)
+            } else {
+                NIC.wrap(octet)
+            }
+        })
+        .collect();
+    Some(StyledString::from(octets.join(&separator)))
+}
+
+/// Colors every UUID and MAC address found in `text`, leaving the rest untouched. Identifiers must not
+/// be directly preceded or followed by a letter, digit or underscore.
+///
+/// # Examples
+///
+/// ```rust
+/// # if cfg!(feature = "no-color-build") { return; }
+/// use bytescolor::{ansi, ident};
+///
+/// let line = "lease 00:1a:2b:3c:4d:5e renewed";
+/// let out = ident::highlight(line);
+/// assert_ne!(out.as_str(), line);
+/// assert_eq!(ansi::strip(&out), line);
+/// ```
+pub fn highlight(text: &str) -> StyledString {
+    let bytes = text.as_bytes();
+    let mut out = String::with_capacity(text.len());
+    let (mut pos, mut copied) = (0, 0);
+    while pos < bytes.len() {
+        let boundary = pos == 0 || !is_word(bytes[pos - 1]);
+        if boundary && bytes[pos].is_ascii_hexdigit() {
+            let found = MATCHERS.into_iter().find_map(|(len, color)| {
+                let candidate = text.get(pos..pos + len)?;
+                if bytes.get(pos + len).is_some_and(|&b| is_word(b)) {
+                    return None;
+                }
+                color(candidate).map(|styled| (len, styled))
+            });
+            if let Some((len, styled)) = found {
+                out.push_str(&text[copied..pos]);
+                out.push_str(&styled);
+                pos += len;
+                copied = pos;
+                continue;
+            }
+        }
+        pos += 1;
+    }
+    out.push_str(&text[copied..]);
+    StyledString::from(out)
+}
+
+fn is_uuid(bytes: &[u8]) -> bool {
+    bytes.len() == UUID_LEN
+        && bytes.iter().enumerate().all(|(index, &b)| match index {
+            8 | 13 | 18 | 23 => b == b'-',
+            _ => b.is_ascii_hexdigit(),
+        })
+}
+
+fn is_mac(bytes: &[u8]) -> bool {
+    bytes.len() == MAC_LEN
+        && matches!(bytes[2], b':' | b'-')
+        && bytes.iter().enumerate().all(|(index, &b)| match index % 3 {
+            2 => b == bytes[2],
+            _ => b.is_ascii_hexdigit(),
+        })
+}
+
+fn is_word(byte: u8) -> bool {
+    byte.is_ascii_alphanumeric() || byte == b'_'
+}
@@ -0,0 +1,187 @@
+//! # Iterator Styling
+//!
+//! The [`ByteColorIter`] extension trait brings the [`ByteColor`](crate::ByteColor) colors to iterators
+//! of displayable items, styling every element lazily, and joins the results in one call.
+//!
+//! ```rust
+//! use bytescolor::ByteColorIter;
+//!
+//! let failing = ["parse::empty", "parse::nested"];
+//! println!("failed: {}", failing.iter().map_red().join_colored(", "));
+//! ```
+//!
+//! Slices get [`JoinStyled::join_styled`], which styles items and separators separately.
+
+use std::fmt::{Display, Write};
+
+use crate::{Color, Style, StyledString};
+
+/// Extension methods styling each element of an iterator of [`Display`] items.
+pub trait ByteColorIter: Iterator + Sized
+where
+    Self::Item: Display,
+{
+    /// Renders every element in `style`.
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// # if cfg!(feature = "no-color-build") { return; }
+    /// use bytescolor::{ByteColorIter, Style};
+    ///
+    /// let cells: Vec<_> = [1, 2, 3].iter().styled(Style::new().bold()).collect();
+    /// assert_eq!(cells[0].as_str(), "\x1b[1m1\x1b[0m");
+    /// ```
+    fn styled(self, style: Style) -> StyledIter<Self> {
+        StyledIter { iter: self, style }
+    }
+
+    /// Renders every element in red.
+    fn map_red(self) -> StyledIter<Self> {
+        self.styled(Style::new().fg(Color::Red))
+    }
+
+    /// Renders every element in green.
+    fn map_green(self) -> StyledIter<Self> {
+        self.styled(Style::new().fg(Color::Green))
+    }
+
+    /// Renders every element in yellow.
+    fn map_yellow(self) -> StyledIter<Self> {
+        self.styled(Style::new().fg(Color::Yellow))
+    }
+
+    /// Renders every element in magenta.
+    fn map_magenta(self) -> StyledIter<Self> {
+        self.styled(Style::new().fg(Color::Magenta))
+    }
+
+    /// Renders every element in cyan.
+    fn map_cyan(self) -> StyledIter<Self> {
+        self.styled(Style::new().fg(Color::Cyan))
+    }
+
+    /// Renders every element in blue.
+    fn map_blue(self) -> StyledIter<Self> {
+        self.styled(Style::new().fg(Color::Blue))
+    }
+
+    /// Renders every element in bold.
+    fn map_bold(self) -> StyledIter<Self> {
+        self.styled(Style::new().bold())
+    }
+
+    /// Renders every element underlined.
+    fn map_underline(self) -> StyledIter<Self> {
+        self.styled(Style::new().underline())
+    }
+
+    /// Concatenates the elements, placing the plain `separator` between them.
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// # if cfg!(feature = "no-color-build") { return; }
+    /// use bytescolor::ByteColorIter;
+    ///
+    /// let joined = ["a", "b"].iter().map_green().join_colored(", ");
+    /// assert_eq!(joined, "a, b");
+    /// assert_eq!(joined.as_str(), "\x1b[32ma\x1b[0m, \x1b[32mb\x1b[0m");
+    /// ```
+    fn join_colored(self, separator: &str) -> StyledString {
+        let mut out = String::new();
+        for (index, item) in self.enumerate() {
+            if index > 0 {
+                out.push_str(separator);
+            }
+            write!(out, "{}", item).expect("writing to a String cannot fail");
+        }
+        StyledString::from(out)
+    }
+}
+
+impl<I> ByteColorIter for I
+where
+    I: Iterator,
+    I::Item: Display,
+{
+}
+
+/// An iterator rendering each element of another iterator in a style, created by
+/// [`ByteColorIter::styled`] and the `map_*` methods.
+#[derive(Debug, Clone)]
+pub struct StyledIter<I> {
+    iter: I,
+    style: Style,
+}
+
+impl<I> Iterator for StyledIter<I>
+where
+    I: Iterator,
+    I::Item: Display,
+{
+    type Item = StyledString;
+
+    fn next(&mut self) -> Option<StyledString> {
+        self.iter
+            .next()
+            .map(|item| StyledString::from(self.style.wrap(item)))
+    }
+
+    fn size_hint(&self) -> (usize, Option<usize>) {
+        self.iter.size_hint()
+    }
+}
+
+impl<I> DoubleEndedIterator for StyledIter<I>
+where
+    I: DoubleEndedIterator,
+    I::Item: Display,
+{
+    fn next_back(&mut self) -> Option<StyledString> {
+        self.iter
+            .next_back()
+            .map(|item| StyledString::from(self.style.wrap(item)))
+    }
+}
+
+impl<I> ExactSizeIterator for StyledIter<I>
+where
+    I: ExactSizeIterator,
+    I::Item: Display,
+{
+}
+
+/// Joins slices of [`Display`] items with distinct styles for the items and the separators.
+pub trait JoinStyled {
+    /// Concatenates the items rendered in `item_style`, with `separator` rendered in `sep_style`
+    /// between them, into a single buffer.
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// use bytescolor::iter::JoinStyled;
+    /// use bytescolor::{Color, Style};
+    ///
+    /// let path = ["usr", "local", "bin"].join_styled("/", Style::new().bold(), Style::new().fg(Color::Blue));
+    /// assert_eq!(path, "usr/local/bin");
+    /// ```
+    fn join_styled(&self, separator: &str, item_style: Style, sep_style: Style) -> StyledString;
+}
+
+impl<T: Display> JoinStyled for [T] {
+    fn join_styled(&self, separator: &str, item_style: Style, sep_style: Style) -> StyledString {
+        let mut out = String::with_capacity(self.len() * (separator.len() + 16));
+        for (index, item) in self.iter().enumerate() {
+            if index > 0 {
+                sep_style
+                    .write_wrapped(&mut out, separator)
+                    .expect("writing to a String cannot fail");
+            }
+            item_style
+                .write_wrapped(&mut out, item)
+                .expect("writing to a String cannot fail");
+        }
+        StyledString::from(out)
+    }
+}
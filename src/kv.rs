@@ -0,0 +1,181 @@
+//! # Key-Value Listings
+//!
+//! Renders any sequence of `(key, value)` pairs as an aligned, colored listing, the typical output of
+//! `--show-config` or `info` style commands.
+//!
+//! ```rust
+//! use bytescolor::kv;
+//!
+//! let settings = [("name", "api".to_string()), ("retries", 3.to_string()), ("verbose", true.to_string())];
+//! println!("{}", kv::key_values(settings));
+//! // name    : api
+//! // retries : 3
+//! // verbose : true
+//! ```
+//!
+//! This module is available with the `highlight` feature, enabled by default.
+
+use std::fmt::Display;
+
+use crate::ansi::visible_width;
+use crate::highlight::Rules;
+use crate::{Color, Style};
+
+/// How the values of a listing are styled.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum ValueStyle {
+    /// Every value gets the same style.
+    Fixed(Style),
+    /// Values are styled by what they look like: numbers yellow, booleans magenta, empty and
+    /// null-like values dimmed, anything else green.
+    ByType,
+    /// Values are run through a highlighting rule set.
+    Rules(Rules),
+}
+
+/// A configurable key-value listing renderer.
+///
+/// # Examples
+///
+/// ```rust
+/// use bytescolor::kv::{KeyValues, ValueStyle};
+/// use bytescolor::Style;
+///
+/// let listing = KeyValues::new()
+///     .key_style(Style::new())
+///     .separator(" = ")
+///     .separator_style(Style::new())
+///     .values(ValueStyle::Fixed(Style::new()))
+///     .render([("a", 1), ("long", 2)]);
+/// assert_eq!(listing, "a    = 1\nlong = 2");
+/// ```
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct KeyValues {
+    key_style: Style,
+    separator: String,
+    separator_style: Style,
+    values: ValueStyle,
+    align: bool,
+}
+
+impl Default for KeyValues {
+    fn default() -> Self {
+        KeyValues {
+            key_style: Style::new().fg(Color::Cyan),
+            separator: " : ".to_string(),
+            separator_style: Style::new().dim(),
+            values: ValueStyle::ByType,
+            align: true,
+        }
+    }
+}
+
+impl KeyValues {
+    /// Creates a renderer with cyan keys, a dimmed `" : "` separator and values styled by type.
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// Sets the style of the keys.
+    pub fn key_style(mut self, style: Style) -> Self {
+        self.key_style = style;
+        self
+    }
+
+    /// Sets the text placed between keys and values.
+    pub fn separator(mut self, separator: impl Into<String>) -> Self {
+        self.separator = separator.into();
+        self
+    }
+
+    /// Sets the style of the separator.
+    pub fn separator_style(mut self, style: Style) -> Self {
+        self.separator_style = style;
+        self
+    }
+
+    /// Sets how values are styled.
+    pub fn values(mut self, values: ValueStyle) -> Self {
+        self.values = values;
+        self
+    }
+
+    /// Enables or disables padding keys to a common width (enabled by default).
+    pub fn align(mut self, align: bool) -> Self {
+        self.align = align;
+        self
+    }
+
+    /// Renders `items`, one pair per line.
+    ///
+    /// Keys may already contain escape sequences; alignment is computed on their visible width.
+    pub fn render<I, K, V>(&self, items: I) -> String
+    where
+        I: IntoIterator<Item = (K, V)>,
+        K: Display,
+        V: Display,
+    {
+        let rows: Vec<(String, String)> = items
+            .into_iter()
+            .map(|(key, value)| (key.to_string(), value.to_string()))
+            .collect();
+        let width = if self.align {
+            rows.iter()
+                .map(|(key, _)| visible_width(key))
+                .max()
+                .unwrap_or(0)
+        } else {
+            0
+        };
+        let separator = self.separator_style.wrap(&self.separator);
+
+        let mut out = String::new();
+        for (index, (key, value)) in rows.iter().enumerate() {
+            if index > 0 {
+                out.push('\n');
+            }
+            out.push_str(&self.key_style.wrap(key));
+            let padding = width.saturating_sub(visible_width(key));
+            out.extend(std::iter::repeat(' ').take(padding));
+            out.push_str(&separator);
+            out.push_str(&self.style_value(value));
+        }
+        out
+    }
+
+    fn style_value(&self, value: &str) -> String {
+        match &self.values {
+            ValueStyle::Fixed(style) => style.wrap(value),
+            ValueStyle::ByType => type_style(value).wrap(value),
+            ValueStyle::Rules(rules) => rules.apply(value).into_string(),
+        }
+    }
+}
+
+/// Renders `items` with the default [`KeyValues`] settings.
+pub fn key_values<I, K, V>(items: I) -> String
+where
+    I: IntoIterator<Item = (K, V)>,
+    K: Display,
+    V: Display,
+{
+    KeyValues::new().render(items)
+}
+
+fn type_style(value: &str) -> Style {
+    let trimmed = value.trim();
+    if trimmed.is_empty()
+        || matches!(
+            trimmed,
+            "none" | "None" | "null" | "nil" | "-" | "(none)" | "<unset>"
+        )
+    {
+        Style::new().dim()
+    } else if matches!(trimmed, "true" | "false") {
+        Style::new().fg(Color::Magenta)
+    } else if trimmed.parse::<f64>().is_ok() {
+        Style::new().fg(Color::Yellow)
+    } else {
+        Style::new().fg(Color::Green)
+    }
+}
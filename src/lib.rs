@@ -13,6 +13,7 @@
 //! - [Usage](#usage)
 //!   - [Colorizing Primitive Types](#colorizing-primitive-types)
 //!   - [Colorizing Strings and Byte Arrays](#colorizing-strings-and-byte-arrays)
+//!   - [Colorizing Formatted Arguments](#colorizing-formatted-arguments)
 //! - [Trait Definition](#trait-definition)
 //! - [Implementation Details](#implementation-details)
 //!   - [Macro Usage](#macro-usage)
@@ -36,8 +37,84 @@
 //! - **Text Styling:** Enhance text with styles like bold, underline, and blink.
 //! - **Custom RGB Colors:** Utilize custom RGB tuples for precise color control.
 //! - **256-Color Support:** Apply colors from the 256-color ANSI palette using color codes.
-//! - **Broad Type Support:** Implementations available for primitive numeric types, string slices (`&str`), `String`, byte slices (`&[u8]`), and byte vectors (`Vec<u8>`).
+//! - **Broad Type Support:** Implementations available for primitive numeric types, string slices (`&str`), `String`, byte slices (`&[u8]`), byte vectors (`Vec<u8>`), and `format_args!` output (`fmt::Arguments`).
 //! - **Efficient Implementations:** Utilize Rust's macro system to minimize boilerplate and ensure consistency across implementations.
+//! - **Test-friendly Results:** Colored values are [`StyledString`]s that compare equal to plain text with the same visible content.
+//! - **Styled Documents:** Build, slice and restyle span-based [`StyledText`] without reparsing escape sequences.
+//! - **Iterator Styling:** Color every element of an iterator and join the results with [`ByteColorIter`], or join slices with [`JoinStyled`].
+//! - **Number Views:** Render integers as grouped, shaded hexadecimal, binary and octal with [`number::IntegerColor`], and highlight named bit flags with [`number::BitsColor`].
+//! - **Human-readable Sizes:** Format byte counts as `1.5 KiB` with threshold-based coloring using [`number::HumanBytes`].
+//! - **Human-readable Durations:** Format latencies as `2m 13s`, colored by speed, with [`time::HumanDuration`], and tone down timestamps with [`time::timestamp`].
+//! - **Colored Percentages:** Render `87.5%` along a red → yellow → green gradient with [`number::Percent`].
+//! - **Control Character Visualization:** Reveal hidden whitespace and embedded escapes in untrusted text with [`sanitize::ShowControls`], and neutralize them before styling with [`sanitize::Sanitize`].
+//! - **Side-by-side Diffs:** Compare two texts in aligned columns with line numbers and intra-line change highlighting using [`diff::SideBySide`], or colorize existing unified diffs with [`diff::colorize_unified`].
+//! - **Column Layout:** Lay out styled items in terminal-width columns like `ls` with [`columns::columns`].
+//! - **Terminal Size:** Query the terminal dimensions with [`terminal_size`] (`terminal-size` feature), falling back to `COLUMNS`.
+//! - **Option and Result Rendering:** Style present values and dim missing ones, or show `Ok` green and `Err` red, with [`status::OptionColor`] and [`status::ResultColor`].
+//! - **Interactive Prompts:** Ask styled questions with defaults and validation, redrawing the answered line, with [`prompt::Prompt`].
+//! - **Shell Prompt Export:** Emit styles in bash (`\[ \]`), zsh (`%{ %}`) or fish prompt syntax with [`shell::Shell`], keeping prompt widths correct.
+//! - **tmux Export:** Convert styles to tmux `#[fg=...,bold]` status-line directives with [`tmux::format`].
+//! - **SVG Screenshots:** Render styled output as an SVG terminal image for docs and bug reports with [`svg::Svg`].
+//! - **Recording:** Capture styled output with timestamps, replay it, or export an asciinema cast with [`record::Recorder`].
+//! - **Functional API:** Style any `Display` value without importing a trait using [`paint`] or [`Style::paint`].
+//! - **Streaming Style Stack:** Push and pop nested styles around streamed output, writing escape sequences only where the style changes, with [`writer::StyledWriter`].
+//! - **Unicode Width:** Measure CJK wide characters, emoji and zero-width joiners like a terminal in every layout helper (`unicode-width` feature).
+//! - **Grapheme Clusters:** Gradients and per-character styling never split an emoji or a combining mark, with full UAX #29 segmentation behind the `unicode-segmentation` feature.
+//! - **Serialization:** Serialize styled text to a structured form of text and style attributes, for web frontends and log aggregators (`serde` feature).
+//! - **Rendering Targets:** Render one styled message as ANSI, plain text, HTML or JSON, one per sink.
+//! - **Delimited Text:** View CSV and TSV data with aligned columns colored from a rotating palette.
+//! - **Hex Dumps:** Dump bytes with colored byte classes, or color the fields of a binary record from a layout with a legend.
+//! - **Encoded Bytes:** Render byte slices as hexadecimal or base64 in alternating shades, easy to compare by eye.
+//! - **Identifiers:** Color the segments of UUIDs and MAC addresses, alone or wherever they appear in a log line.
+//! - **TLV Structures:** Walk tag-length-value bytes such as ASN.1 DER, with tags, lengths and values in distinct colors and nested elements indented.
+//! - **Themes:** Define the styles of an application by role once, let end users override them with `BYTESCOLOR_THEME=error=bold red:warn=yellow` or a TOML or JSON file (`toml` and `json` features), lint them for unreadable color combinations, and share them with `clap`'s help output (`clap` feature).
+//! - **Usage Errors:** Format argument parsing errors with `error:` and `usage:` labels and "did you mean" suggestions.
+//! - **Step Reporters:** Report numbered steps as `[1/5] … Building`, redrawn with a ✔ or ✖ and the elapsed time once each is over.
+//! - **Line Prefixes:** Indent multi-line styled text or prepend a styled gutter to each line while preserving inner colors, for quoting subprocess output.
+//! - **Relabeled Output:** Forward the lines of a child process tagged with a label in a color derived from its name, for running tasks side by side.
+//! - **Throttled Logging:** Collapse runs of identical log lines into a dimmed `… repeated N times` summary.
+//! - **Slim Builds:** The optional parts of the crate are default features that minimal builds can leave out with `default-features = false`: `basic` (number, duration, record, shell and identifier formatters), `rgb` (gradients and gradient-colored values), `detect` (background detection), `widgets` (spinners, progress bars, panels, tables, dumps and other layouts), `highlight` (rule, diff, key-value and document highlighting) and `html` (rendering to HTML, JSON and SVG). Styles, styled strings and text, escape sequence handling, terminal control and themes are always available.
+//! - **Property Testing Kit:** Check that custom formatters only add escape sequences, and that styled text survives rendering and parsing, with the `proptest` strategies and invariants of [`testkit`] (`testkit` feature).
+//! - **Ledger Numbers:** Render floats in fixed notation with the integer part, decimal separator and fraction styled apart and negative amounts in red with [`number::FloatColor`].
+//! - **Digit Grouping:** Render large counters as `1,234,567` with dimmed, locale-selectable separators using [`number::ThousandsColor`].
+//! - **Counts:** Render `1 file` or `3 files` with a bold count and the matching noun using [`number::count_styled`].
+//! - **Trees:** Render dependency trees and file hierarchies with dimmed `├─` and `└─` guides and per-depth label colors using [`tree::Tree`].
+//! - **Source Snippets:** Point at a span of source text with line numbers in a dimmed gutter, highlighted spans in distinct colors, labeled caret underlines and `note:` and `help:` lines, like a compiler diagnostic, with [`snippet::Snippet`].
+//! - **Per-target Rendering:** Render one styled value in 24-bit color for the terminal and in 16 colors for a CI log or an email from the same process with [`StyledText::render_for`].
+//! - **Concatenation:** Build styled strings and documents with `+`, `+=` and `collect()` instead of `format!` glue, styles intact.
+//! - **Range Styling:** Style a character or byte range of a string, such as a search match, without slicing and joining it by hand, with [`text::StyleRange`].
+//! - **Keyword Highlighting:** Style whole-word, case-insensitive matches of a few keywords such as `error` and `warn` in log lines, without regular expressions, with [`highlight::HighlightWords`].
+//! - **Middle Truncation:** Shorten long file paths and URLs to a column budget, keeping their start and end around a dimmed `…`, with [`StyledText::truncate_middle_visible`].
+//! - **Style-preserving Splitting:** Split styled text on a separator or into lines with [`StyledText::split_visible`] and [`StyledText::lines_styled`], each piece reopening the styles active at its start and closing them at its end.
+//! - **Prefixed Writers:** Tag every line written through a writer with a styled prefix and an optional timestamp, however the lines are split across writes, with [`relabel::PrefixedWriter`].
+//! - **Rules and Section Headers:** Separate the phases of CLI output with terminal-wide rules and titled `── Build ──` headers using [`rule::hr`], [`rule::section`] and [`rule::Rule`].
+//! - **Badges:** Render ` PASS ` and ` FAIL ` chips on a colored background, with the foreground switched to black or white when it would be unreadable, using [`badge::Badge`].
+//! - **Powerline Segments:** Join colored status-line and prompt segments with powerline arrows whose colors blend into the neighboring segments with [`powerline::Powerline`].
+//! - **Tables:** Lay out rows under a bold header with [`table::Table`], numeric columns aligned on the decimal point and colored by threshold zones, cells spanning several columns and long text word-wrapped within its column, exported unstyled as plain text, CSV, TSV or a GitHub-flavored markdown table from the same definition.
+//! - **Calendar Heatmaps:** Draw daily values as a GitHub-style contribution calendar with month names and a configurable background palette using [`calendar::Calendar`].
+//! - **Gauges:** Show where a value stands on a `min──▣────max` scale colored by threshold zones, for resource monitors, with [`gauge::Gauge`].
+//! - **Reusable Styles:** Describe colors and attributes once as a [`Style`] value, compose them with `|` (`Style::RED | Style::BOLD`), and apply them with `styled`.
+//! - **Rule-based Highlighting:** Highlight matching fragments of streamed output with the [`highlight`] rule engine.
+//! - **Capture Group Styling:** Give each capture group of a regular expression its own style, e.g. timestamp, level and message of a log line (`regex` feature).
+//! - **JSON Highlighting:** Pretty-print and colorize JSON documents (`json` feature).
+//! - **Config Highlighting:** Colorize TOML and YAML documents (`toml` and `yaml` features).
+//! - **Colored Debug Dumps:** Make `{:#?}` output scannable with [`debug::DebugColored`] and [`dbg_colored!`].
+//! - **Key-Value Listings:** Render aligned, colored `key : value` listings with [`kv::key_values`].
+//! - **Color Control:** Force, disable, or auto-detect (`NO_COLOR`, TTY) escape output globally with the [`control`] module, environment detection running once and cached until [`refresh`], or for the current thread only with override scopes such as [`control::with_color_choice`] and [`theme::with_theme`].
+//! - **Background Detection:** Tell light from dark terminals via `COLORFGBG` or an OSC 11 query (`background-query` feature) with [`background::Background`].
+//! - **Color Downgrading:** Approximate RGB and 256-palette colors on less capable terminals with [`control::set_color_level`], detected from the terminfo database with the `terminfo` feature.
+//! - **Pluggable Backends:** Encode styles for targets other than ANSI terminals by installing a [`backend::ColorBackend`].
+//! - **Colorless Builds:** Compile every styling method down to plain `to_string()` with the `no-color-build` feature, for deployments that must never emit ANSI.
+//! - **Escape Code Constants:** Embed raw SGR sequences at compile time with the [`codes`] constants, `const fn` builders and [`sgr!`].
+//! - **OSC Helpers:** Set the window title, copy to the clipboard and send notifications with the [`osc`] helpers, optionally forwarded through tmux or screen.
+//! - **Terminal Control:** Move the cursor and clear lines or the screen with the [`term`] helpers, or rewrite a status line in place with [`LiveLine`](term::LiveLine).
+//! - **ANSI Utilities:** Strip escape sequences and measure visible width with the [`ansi`] helpers.
+//! - **Panels:** Draw borders with titles and padding around styled, multi-line text with [`panel::Panel`].
+//! - **Progress Bars:** Show colored, in-place updating progress with [`progress::ProgressBar`].
+//! - **Charts:** Render numeric slices as heatmap-colored sparklines and bar charts with [`chart::Chart`].
+//! - **Banners:** Render large-letter headers in solid colors or gradients with [`banner::banner`].
+//! - **Byte Heatmaps:** Spot structure in binary blobs with value and entropy heatmaps from [`heatmap::Heatmap`].
+//! - **Spinners:** Animate activity feedback with [`spinner::Spinner`], optionally on a background thread (`spinner-thread` feature).
 //!
 //! ## Getting Started
 //!
@@ -109,55 +186,78 @@
 //! - "Byte Slice" displayed in cyan.
 //! - "Byte Vec" displayed in bold.
 //!
+//! ### Colorizing Formatted Arguments
+//!
+//! `format_args!` output can be colored directly, without formatting an intermediate `String` first:
+//!
+//! ```rust
+//! use bytescolor::ByteColor;
+//!
+//! let (done, total) = (3, 5);
+//! println!("{}", format_args!("{} of {}", done, total).green());
+//! ```
+//!
+//! **Output:**
+//! - "3 of 5" displayed in green.
+//!
 //! ## Trait Definition
 //!
 //! The `ByteColor` trait defines a suite of methods for applying ANSI color codes and text styles to various types.
-//! Each method returns a `String` with the appropriate ANSI escape sequences encapsulating the original value.
+//! Each method returns a [`StyledString`] with the appropriate ANSI escape sequences encapsulating the original value.
+//! It prints like a `String`, dereferences to the raw `str`, and compares equal to plain text with the same visible
+//! content, so `assert_eq!("error".red(), "error")` holds.
 //!
 //! ```plaintext
 //! /// The `ByteColor` trait provides methods to apply ANSI colors and text styles to various types.
-//! /// Each method returns a `String` with the corresponding ANSI escape codes applied.
+//! /// Each method returns a `StyledString` with the corresponding ANSI escape codes applied.
 //! pub trait ByteColor {
 //!     /// Applies red color to the text.
-//!     fn red(&self) -> String;
+//!     fn red(&self) -> StyledString;
 //!
 //!     /// Applies green color to the text.
-//!     fn green(&self) -> String;
+//!     fn green(&self) -> StyledString;
 //!
 //!     /// Applies yellow color to the text.
-//!     fn yellow(&self) -> String;
+//!     fn yellow(&self) -> StyledString;
 //!
 //!     /// Applies magenta color to the text.
-//!     fn magenta(&self) -> String;
+//!     fn magenta(&self) -> StyledString;
 //!
 //!     /// Applies cyan color to the text.
-//!     fn cyan(&self) -> String;
+//!     fn cyan(&self) -> StyledString;
 //!
 //!     /// Applies blue color to the text.
-//!     fn blue(&self) -> String;
+//!     fn blue(&self) -> StyledString;
 //!
 //!     /// Makes the text bold.
-//!     fn bold(&self) -> String;
+//!     fn bold(&self) -> StyledString;
 //!
 //!     /// Underlines the text.
-//!     fn underline(&self) -> String;
+//!     fn underline(&self) -> StyledString;
 //!
 //!     /// Makes the text blink.
-//!     fn blink(&self) -> String;
+//!     fn blink(&self) -> StyledString;
 //!
 //!     /// Applies a custom RGB color to the text.
 //!     ///
 //!     /// # Parameters
 //!     ///
 //!     /// - `rgb`: A tuple representing the red, green, and blue components of the color.
-//!     fn rgb(&self, rgb: (u8, u8, u8)) -> String;
+//!     fn rgb(&self, rgb: (u8, u8, u8)) -> StyledString;
 //!
 //!     /// Applies a custom 256-color palette color to the text using a color code.
 //!     ///
 //!     /// # Parameters
 //!     ///
 //!     /// - `code`: An ANSI color code ranging from 0 to 255.
-//!     fn color(&self, code: u8) -> String;
+//!     fn color(&self, code: u8) -> StyledString;
+//!
+//!     /// Applies a `Style` to the text, combining colors and attributes in a single escape sequence.
+//!     ///
+//!     /// # Parameters
+//!     ///
+//!     /// - `style`: The style to apply.
+//!     fn styled(&self, style: Style) -> StyledString;
 //! }
 //! ```
 //!
@@ -178,48 +278,48 @@
 //!     ($($t:ty),*) => {
 //!         $(
 //!             impl ByteColor for $t {
-//!                 fn red(&self) -> String {
-//!                     format!("\x1b[31m{}\x1b[0m", self)
+//!                 fn red(&self) -> StyledString {
+//!                     Style::new().fg(Color::Red).paint(self)
 //!                 }
 //!
-//!                 fn green(&self) -> String {
-//!                     format!("\x1b[32m{}\x1b[0m", self)
+//!                 fn green(&self) -> StyledString {
+//!                     Style::new().fg(Color::Green).paint(self)
 //!                 }
 //!
-//!                 fn yellow(&self) -> String {
-//!                     format!("\x1b[33m{}\x1b[0m", self)
+//!                 fn yellow(&self) -> StyledString {
+//!                     Style::new().fg(Color::Yellow).paint(self)
 //!                 }
 //!
-//!                 fn magenta(&self) -> String {
-//!                     format!("\x1b[35m{}\x1b[0m", self)
+//!                 fn magenta(&self) -> StyledString {
+//!                     Style::new().fg(Color::Magenta).paint(self)
 //!                 }
 //!
-//!                 fn cyan(&self) -> String {
-//!                     format!("\x1b[36m{}\x1b[0m", self)
+//!                 fn cyan(&self) -> StyledString {
+//!                     Style::new().fg(Color::Cyan).paint(self)
 //!                 }
 //!
-//!                 fn blue(&self) -> String {
-//!                     format!("\x1b[34m{}\x1b[0m", self)
+//!                 fn blue(&self) -> StyledString {
+//!                     Style::new().fg(Color::Blue).paint(self)
 //!                 }
 //!
-//!                 fn bold(&self) -> String {
-//!                     format!("\x1b[1m{}\x1b[0m", self)
+//!                 fn bold(&self) -> StyledString {
+//!                     Style::new().bold().paint(self)
 //!                 }
 //!
-//!                 fn underline(&self) -> String {
-//!                     format!("\x1b[4m{}\x1b[0m", self)
+//!                 fn underline(&self) -> StyledString {
+//!                     Style::new().underline().paint(self)
 //!                 }
 //!
-//!                 fn blink(&self) -> String {
-//!                     format!("\x1b[5m{}\x1b[0m", self)
+//!                 fn blink(&self) -> StyledString {
+//!                     Style::new().blink().paint(self)
 //!                 }
 //!
-//!                 fn rgb(&self, color: (u8, u8, u8)) -> String {
-//!                     format!("\x1b[38;2;{};{};{}m{}\x1b[0m", color.0, color.1, color.2, self)
+//!                 fn rgb(&self, color: (u8, u8, u8)) -> StyledString {
+//!                     Style::new().fg(Color::Rgb(color.0, color.1, color.2)).paint(self)
 //!                 }
 //!
-//!                 fn color(&self, color_code: u8) -> String {
-//!                     format!("\x1b[38;5;{}m{}\x1b[0m", color_code, self)
+//!                 fn color(&self, color_code: u8) -> StyledString {
+//!                     Style::new().fg(Color::Fixed(color_code)).paint(self)
 //!                 }
 //!             }
 //!         )*
@@ -233,7 +333,8 @@
 //! **Explanation:**
 //!
 //! - The `impl_colorize_for_primitive!` macro takes a list of primitive types and implements the `ByteColor` trait for each.
-//! - Each method within the trait is implemented to wrap the original value with the appropriate ANSI escape codes.
+//! - Each method within the trait is implemented to wrap the original value in the escape codes of the matching [`Style`].
+//! - Because every method goes through `Style`, all of them honor the global settings of the [`control`] module.
 //! - This approach eliminates repetitive code and ensures consistency across different type implementations.
 //!
 //! ### Handling Byte Slices and Vectors
@@ -246,58 +347,52 @@
 //!
 //! ```plaintext
 //! impl ByteColor for &[u8] {
-//!     fn red(&self) -> String {
-//!         format!("\x1b[31m{}\x1b[0m", String::from_utf8_lossy(self))
+//!     fn red(&self) -> StyledString {
+//!         Style::new().fg(Color::Red).paint(String::from_utf8_lossy(self))
 //!     }
 //!
-//!     fn green(&self) -> String {
-//!         format!("\x1b[32m{}\x1b[0m", String::from_utf8_lossy(self))
+//!     fn green(&self) -> StyledString {
+//!         Style::new().fg(Color::Green).paint(String::from_utf8_lossy(self))
 //!     }
 //!
-//!     fn yellow(&self) -> String {
-//!         format!("\x1b[33m{}\x1b[0m", String::from_utf8_lossy(self))
+//!     fn yellow(&self) -> StyledString {
+//!         Style::new().fg(Color::Yellow).paint(String::from_utf8_lossy(self))
 //!     }
 //!
-//!     fn magenta(&self) -> String {
-//!         format!("\x1b[35m{}\x1b[0m", String::from_utf8_lossy(self))
+//!     fn magenta(&self) -> StyledString {
+//!         Style::new().fg(Color::Magenta).paint(String::from_utf8_lossy(self))
 //!     }
 //!
-//!     fn cyan(&self) -> String {
-//!         format!("\x1b[36m{}\x1b[0m", String::from_utf8_lossy(self))
+//!     fn cyan(&self) -> StyledString {
+//!         Style::new().fg(Color::Cyan).paint(String::from_utf8_lossy(self))
 //!     }
 //!
-//!     fn blue(&self) -> String {
-//!         format!("\x1b[34m{}\x1b[0m", String::from_utf8_lossy(self))
+//!     fn blue(&self) -> StyledString {
+//!         Style::new().fg(Color::Blue).paint(String::from_utf8_lossy(self))
 //!     }
 //!
-//!     fn bold(&self) -> String {
-//!         format!("\x1b[1m{}\x1b[0m", String::from_utf8_lossy(self))
+//!     fn bold(&self) -> StyledString {
+//!         Style::new().bold().paint(String::from_utf8_lossy(self))
 //!     }
 //!
-//!     fn underline(&self) -> String {
-//!         format!("\x1b[4m{}\x1b[0m", String::from_utf8_lossy(self))
+//!     fn underline(&self) -> StyledString {
+//!         Style::new().underline().paint(String::from_utf8_lossy(self))
 //!     }
 //!
-//!     fn blink(&self) -> String {
-//!         format!("\x1b[5m{}\x1b[0m", String::from_utf8_lossy(self))
+//!     fn blink(&self) -> StyledString {
+//!         Style::new().blink().paint(String::from_utf8_lossy(self))
 //!     }
 //!
-//!     fn rgb(&self, color: (u8, u8, u8)) -> String {
-//!         format!(
-//!             "\x1b[38;2;{};{};{}m{}\x1b[0m",
-//!             color.0,
-//!             color.1,
-//!             color.2,
-//!             String::from_utf8_lossy(self)
-//!         )
+//!     fn rgb(&self, color: (u8, u8, u8)) -> StyledString {
+//!         Style::new()
+//!             .fg(Color::Rgb(color.0, color.1, color.2))
+//!             .paint(String::from_utf8_lossy(self))
 //!     }
 //!
-//!     fn color(&self, color_code: u8) -> String {
-//!         format!(
-//!             "\x1b[38;5;{}m{}\x1b[0m",
-//!             color_code,
-//!             String::from_utf8_lossy(self)
-//!         )
+//!     fn color(&self, color_code: u8) -> StyledString {
+//!         Style::new()
+//!             .fg(Color::Fixed(color_code))
+//!             .paint(String::from_utf8_lossy(self))
 //!     }
 //! }
 //! ```
@@ -306,58 +401,52 @@
 //!
 //! ```plaintext
 //! impl ByteColor for Vec<u8> {
-//!     fn red(&self) -> String {
-//!         format!("\x1b[31m{}\x1b[0m", String::from_utf8_lossy(&self))
+//!     fn red(&self) -> StyledString {
+//!         Style::new().fg(Color::Red).paint(String::from_utf8_lossy(self))
 //!     }
 //!
-//!     fn green(&self) -> String {
-//!         format!("\x1b[32m{}\x1b[0m", String::from_utf8_lossy(&self))
+//!     fn green(&self) -> StyledString {
+//!         Style::new().fg(Color::Green).paint(String::from_utf8_lossy(self))
 //!     }
 //!
-//!     fn yellow(&self) -> String {
-//!         format!("\x1b[33m{}\x1b[0m", String::from_utf8_lossy(&self))
+//!     fn yellow(&self) -> StyledString {
+//!         Style::new().fg(Color::Yellow).paint(String::from_utf8_lossy(self))
 //!     }
 //!
-//!     fn magenta(&self) -> String {
-//!         format!("\x1b[35m{}\x1b[0m", String::from_utf8_lossy(&self))
+//!     fn magenta(&self) -> StyledString {
+//!         Style::new().fg(Color::Magenta).paint(String::from_utf8_lossy(self))
 //!     }
 //!
-//!     fn cyan(&self) -> String {
-//!         format!("\x1b[36m{}\x1b[0m", String::from_utf8_lossy(&self))
+//!     fn cyan(&self) -> StyledString {
+//!         Style::new().fg(Color::Cyan).paint(String::from_utf8_lossy(self))
 //!     }
 //!
-//!     fn blue(&self) -> String {
-//!         format!("\x1b[34m{}\x1b[0m", String::from_utf8_lossy(&self))
+//!     fn blue(&self) -> StyledString {
+//!         Style::new().fg(Color::Blue).paint(String::from_utf8_lossy(self))
 //!     }
 //!
-//!     fn bold(&self) -> String {
-//!         format!("\x1b[1m{}\x1b[0m", String::from_utf8_lossy(&self))
+//!     fn bold(&self) -> StyledString {
+//!         Style::new().bold().paint(String::from_utf8_lossy(self))
 //!     }
 //!
-//!     fn underline(&self) -> String {
-//!         format!("\x1b[4m{}\x1b[0m", String::from_utf8_lossy(&self))
+//!     fn underline(&self) -> StyledString {
+//!         Style::new().underline().paint(String::from_utf8_lossy(self))
 //!     }
 //!
-//!     fn blink(&self) -> String {
-//!         format!("\x1b[5m{}\x1b[0m", String::from_utf8_lossy(&self))
+//!     fn blink(&self) -> StyledString {
+//!         Style::new().blink().paint(String::from_utf8_lossy(self))
 //!     }
 //!
-//!     fn rgb(&self, color: (u8, u8, u8)) -> String {
-//!         format!(
-//!             "\x1b[38;2;{};{};{}m{}\x1b[0m",
-//!             color.0,
-//!             color.1,
-//!             color.2,
-//!             String::from_utf8_lossy(&self)
-//!         )
+//!     fn rgb(&self, color: (u8, u8, u8)) -> StyledString {
+//!         Style::new()
+//!             .fg(Color::Rgb(color.0, color.1, color.2))
+//!             .paint(String::from_utf8_lossy(self))
 //!     }
 //!
-//!     fn color(&self, color_code: u8) -> String {
-//!         format!(
-//!             "\x1b[38;5;{}m{}\x1b[0m",
-//!             color_code,
-//!             String::from_utf8_lossy(&self)
-//!         )
+//!     fn color(&self, color_code: u8) -> StyledString {
+//!         Style::new()
+//!             .fg(Color::Fixed(color_code))
+//!             .paint(String::from_utf8_lossy(self))
 //!     }
 //! }
 //! ```
@@ -425,6 +514,128 @@
 //! ## License
 //!
 //! This project is licensed under the Apache-2.0.
+
+pub mod ansi;
+pub mod backend;
+#[cfg(feature = "detect")]
+pub mod background;
+#[cfg(feature = "widgets")]
+pub mod badge;
+#[cfg(feature = "widgets")]
+pub mod banner;
+mod base64;
+#[cfg(feature = "widgets")]
+pub mod calendar;
+#[cfg(feature = "widgets")]
+pub mod chart;
+pub mod codes;
+#[cfg(feature = "widgets")]
+pub mod columns;
+pub mod control;
+#[cfg(feature = "widgets")]
+pub mod csv;
+pub mod debug;
+#[cfg(feature = "highlight")]
+pub mod diff;
+#[cfg(feature = "basic")]
+pub mod encoding;
+#[cfg(feature = "widgets")]
+pub mod gauge;
+#[cfg(feature = "rgb")]
+pub mod gradient;
+#[cfg(feature = "widgets")]
+pub mod heatmap;
+#[cfg(feature = "widgets")]
+pub mod hexdump;
+#[cfg(feature = "highlight")]
+pub mod highlight;
+#[cfg(feature = "basic")]
+pub mod ident;
+pub mod iter;
+#[cfg(feature = "highlight")]
+pub mod kv;
+mod markup;
+#[cfg(feature = "basic")]
+pub mod number;
+pub mod osc;
+#[cfg(feature = "widgets")]
+pub mod panel;
+#[cfg(feature = "widgets")]
+pub mod powerline;
+#[cfg(feature = "widgets")]
+pub mod progress;
+#[cfg(feature = "widgets")]
+pub mod prompt;
+#[cfg(feature = "basic")]
+pub mod record;
+#[cfg(feature = "widgets")]
+pub mod relabel;
+#[cfg(feature = "html")]
+pub mod render;
+#[cfg(feature = "widgets")]
+pub mod rule;
+pub mod sanitize;
+#[cfg(feature = "serde")]
+mod serialize;
+#[cfg(feature = "basic")]
+pub mod shell;
+#[cfg(feature = "widgets")]
+pub mod snippet;
+#[cfg(feature = "widgets")]
+pub mod spinner;
+#[cfg(feature = "widgets")]
+pub mod status;
+#[cfg(feature = "widgets")]
+pub mod steps;
+pub mod style;
+pub mod styled;
+#[cfg(feature = "html")]
+pub mod svg;
+#[cfg(feature = "widgets")]
+pub mod table;
+pub mod term;
+#[cfg(feature = "terminfo")]
+pub mod terminfo;
+#[cfg(feature = "testkit")]
+pub mod testkit;
+pub mod text;
+pub mod theme;
+#[cfg(feature = "widgets")]
+pub mod throttle;
+#[cfg(feature = "basic")]
+pub mod time;
+#[cfg(feature = "widgets")]
+pub mod tlv;
+pub mod tmux;
+#[cfg(feature = "widgets")]
+pub mod tree;
+pub mod usage;
+pub mod writer;
+
+pub use control::refresh;
+pub use iter::{ByteColorIter, JoinStyled};
+pub use style::{Color, Style};
+pub use styled::StyledString;
+pub use term::terminal_size;
+pub use text::{Span, StyledText};
+
+/// Renders `value` in `style`, the free-function form of [`Style::paint`] for code that prefers not to
+/// bring [`ByteColor`] into scope.
+///
+/// # Examples
+///
+/// ```rust
+/// # if cfg!(feature = "no-color-build") { return; }
+/// use bytescolor::{paint, Style};
+///
+/// let total = paint(Style::GREEN | Style::BOLD, 128);
+/// assert_eq!(total, "128");
+/// assert_eq!(total.as_str(), "\x1b[1;32m128\x1b[0m");
+/// ```
+pub fn paint(style: Style, value: impl std::fmt::Display) -> StyledString {
+    style.paint(value)
+}
+
 pub trait ByteColor {
     /// Applies red color to the text.
     ///
@@ -436,7 +647,7 @@ pub trait ByteColor {
     /// let number: u32 = 42;
     /// println!("{}", number.red()); // Displays "42" in red
     /// ```
-    fn red(&self) -> String;
+    fn red(&self) -> StyledString;
 
     /// Applies green color to the text.
     ///
@@ -448,7 +659,7 @@ pub trait ByteColor {
     /// let message: &str = "Success!";
     /// println!("{}", message.green()); // Displays "Success!" in green
     /// ```
-    fn green(&self) -> String;
+    fn green(&self) -> StyledString;
 
     /// Applies yellow color to the text.
     ///
@@ -460,7 +671,7 @@ pub trait ByteColor {
     /// let warning: &str = "Warning!";
     /// println!("{}", warning.yellow()); // Displays "Warning!" in yellow
     /// ```
-    fn yellow(&self) -> String;
+    fn yellow(&self) -> StyledString;
 
     /// Applies magenta color to the text.
     ///
@@ -472,7 +683,7 @@ pub trait ByteColor {
     /// let info: &str = "Information";
     /// println!("{}", info.magenta()); // Displays "Information" in magenta
     /// ```
-    fn magenta(&self) -> String;
+    fn magenta(&self) -> StyledString;
 
     /// Applies cyan color to the text.
     ///
@@ -484,7 +695,7 @@ pub trait ByteColor {
     /// let data: &str = "Cyan Data";
     /// println!("{}", data.cyan()); // Displays "Cyan Data" in cyan
     /// ```
-    fn cyan(&self) -> String;
+    fn cyan(&self) -> StyledString;
 
     /// Applies blue color to the text.
     ///
@@ -496,7 +707,7 @@ pub trait ByteColor {
     /// let message: &str = "Blue Message";
     /// println!("{}", message.blue()); // Displays "Blue Message" in blue
     /// ```
-    fn blue(&self) -> String;
+    fn blue(&self) -> StyledString;
 
     /// Makes the text bold.
     ///
@@ -508,7 +719,7 @@ pub trait ByteColor {
     /// let emphasized: &str = "Important!";
     /// println!("{}", emphasized.bold()); // Displays "Important!" in bold
     /// ```
-    fn bold(&self) -> String;
+    fn bold(&self) -> StyledString;
 
     /// Underlines the text.
     ///
@@ -520,7 +731,7 @@ pub trait ByteColor {
     /// let underlined: &str = "Underlined Text";
     /// println!("{}", underlined.underline()); // Displays "Underlined Text" underlined
     /// ```
-    fn underline(&self) -> String;
+    fn underline(&self) -> StyledString;
 
     /// Makes the text blink.
     ///
@@ -532,7 +743,7 @@ pub trait ByteColor {
     /// let blinking: &str = "Blinking Text";
     /// println!("{}", blinking.blink()); // Displays "Blinking Text" with a blinking effect
     /// ```
-    fn blink(&self) -> String;
+    fn blink(&self) -> StyledString;
 
     /// Applies a custom RGB color to the text.
     ///
@@ -548,7 +759,7 @@ pub trait ByteColor {
     /// let custom_rgb: &str = "RGB Colored Text";
     /// println!("{}", custom_rgb.rgb((70, 130, 180))); // Displays the text in Steel Blue
     /// ```
-    fn rgb(&self, rgb: (u8, u8, u8)) -> String;
+    fn rgb(&self, rgb: (u8, u8, u8)) -> StyledString;
 
     /// Applies a custom 256-color palette color to the text using a color code.
     ///
@@ -564,55 +775,75 @@ pub trait ByteColor {
     /// let custom_color: &str = "Custom Color";
     /// println!("{}", custom_color.color(202)); // Displays "Custom Color" in a specific shade of orange
     /// ```
-    fn color(&self, code: u8) -> String;
+    fn color(&self, code: u8) -> StyledString;
+
+    /// Applies a [`Style`] to the text, combining colors and attributes in a single escape sequence.
+    ///
+    /// # Parameters
+    ///
+    /// - `style`: The style to apply.
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// use bytescolor::{ByteColor, Color, Style};
+    ///
+    /// let alert: &str = "Alert";
+    /// println!("{}", alert.styled(Style::new().fg(Color::White).bg(Color::Red).bold())); // Displays "Alert" in bold white on red
+    /// ```
+    fn styled(&self, style: Style) -> StyledString;
 }
 
 macro_rules! impl_colorize_for_primitive {
     ($($t:ty),*) => {
         $(
             impl ByteColor for $t {
-                fn red(&self) -> String {
-                    format!("\x1b[31m{}\x1b[0m", self)
+                fn red(&self) -> StyledString {
+                    Style::new().fg(Color::Red).paint(self)
+                }
+
+                fn green(&self) -> StyledString {
+                    Style::new().fg(Color::Green).paint(self)
                 }
 
-                fn green(&self) -> String {
-                    format!("\x1b[32m{}\x1b[0m", self)
+                fn yellow(&self) -> StyledString {
+                    Style::new().fg(Color::Yellow).paint(self)
                 }
 
-                fn yellow(&self) -> String {
-                    format!("\x1b[33m{}\x1b[0m", self)
+                fn magenta(&self) -> StyledString {
+                    Style::new().fg(Color::Magenta).paint(self)
                 }
 
-                fn magenta(&self) -> String {
-                    format!("\x1b[35m{}\x1b[0m", self)
+                fn cyan(&self) -> StyledString {
+                    Style::new().fg(Color::Cyan).paint(self)
                 }
 
-                fn cyan(&self) -> String {
-                    format!("\x1b[36m{}\x1b[0m", self)
+                fn blue(&self) -> StyledString {
+                    Style::new().fg(Color::Blue).paint(self)
                 }
 
-                fn blue(&self) -> String {
-                    format!("\x1b[34m{}\x1b[0m", self)
+                fn bold(&self) -> StyledString {
+                    Style::new().bold().paint(self)
                 }
 
-                fn bold(&self) -> String {
-                    format!("\x1b[1m{}\x1b[0m", self)
+                fn underline(&self) -> StyledString {
+                    Style::new().underline().paint(self)
                 }
 
-                fn underline(&self) -> String {
-                    format!("\x1b[4m{}\x1b[0m", self)
+                fn blink(&self) -> StyledString {
+                    Style::new().blink().paint(self)
                 }
 
-                fn blink(&self) -> String {
-                    format!("\x1b[5m{}\x1b[0m", self)
+                fn rgb(&self, color: (u8, u8, u8)) -> StyledString {
+                    Style::new().fg(Color::Rgb(color.0, color.1, color.2)).paint(self)
                 }
 
-                fn rgb(&self, color: (u8, u8, u8)) -> String {
-                    format!("\x1b[38;2;{};{};{}m{}\x1b[0m", color.0, color.1, color.2, self)
+                fn color(&self, color_code: u8) -> StyledString {
+                    Style::new().fg(Color::Fixed(color_code)).paint(self)
                 }
 
-                fn color(&self, color_code: u8) -> String {
-                    format!("\x1b[38;5;{}m{}\x1b[0m", color_code, self)
+                fn styled(&self, style: Style) -> StyledString {
+                    style.paint(self)
                 }
             }
         )*
@@ -622,257 +853,299 @@ macro_rules! impl_colorize_for_primitive {
 // Apply the macro to primitive types
 impl_colorize_for_primitive!(u8, u16, u32, u64, i8, i16, i32, i64, usize);
 
+// Implement ByteColor for fmt::Arguments, formatted straight into the styled output
+impl_colorize_for_primitive!(std::fmt::Arguments<'_>);
+
+// Implement ByteColor for Cow<str>, as returned by ansi::strip and sanitize::Sanitize
+impl_colorize_for_primitive!(std::borrow::Cow<'_, str>);
+
 // Implement ByteColor for &str
 impl ByteColor for &str {
-    fn red(&self) -> String {
-        format!("\x1b[31m{}\x1b[0m", self)
+    fn red(&self) -> StyledString {
+        Style::new().fg(Color::Red).paint(self)
     }
 
-    fn green(&self) -> String {
-        format!("\x1b[32m{}\x1b[0m", self)
+    fn green(&self) -> StyledString {
+        Style::new().fg(Color::Green).paint(self)
     }
 
-    fn yellow(&self) -> String {
-        format!("\x1b[33m{}\x1b[0m", self)
+    fn yellow(&self) -> StyledString {
+        Style::new().fg(Color::Yellow).paint(self)
     }
 
-    fn magenta(&self) -> String {
-        format!("\x1b[35m{}\x1b[0m", self)
+    fn magenta(&self) -> StyledString {
+        Style::new().fg(Color::Magenta).paint(self)
     }
 
-    fn cyan(&self) -> String {
-        format!("\x1b[36m{}\x1b[0m", self)
+    fn cyan(&self) -> StyledString {
+        Style::new().fg(Color::Cyan).paint(self)
     }
 
-    fn blue(&self) -> String {
-        format!("\x1b[34m{}\x1b[0m", self)
+    fn blue(&self) -> StyledString {
+        Style::new().fg(Color::Blue).paint(self)
     }
 
-    fn bold(&self) -> String {
-        format!("\x1b[1m{}\x1b[0m", self)
+    fn bold(&self) -> StyledString {
+        Style::new().bold().paint(self)
     }
 
-    fn underline(&self) -> String {
-        format!("\x1b[4m{}\x1b[0m", self)
+    fn underline(&self) -> StyledString {
+        Style::new().underline().paint(self)
     }
 
-    fn blink(&self) -> String {
-        format!("\x1b[5m{}\x1b[0m", self)
+    fn blink(&self) -> StyledString {
+        Style::new().blink().paint(self)
     }
 
-    fn rgb(&self, rgb: (u8, u8, u8)) -> String {
-        format!("\x1b[38;2;{};{};{}m{}\x1b[0m", rgb.0, rgb.1, rgb.2, self)
+    fn rgb(&self, rgb: (u8, u8, u8)) -> StyledString {
+        Style::new().fg(Color::Rgb(rgb.0, rgb.1, rgb.2)).paint(self)
     }
 
-    fn color(&self, code: u8) -> String {
-        format!("\x1b[38;5;{}m{}\x1b[0m", code, self)
+    fn color(&self, code: u8) -> StyledString {
+        Style::new().fg(Color::Fixed(code)).paint(self)
+    }
+
+    fn styled(&self, style: Style) -> StyledString {
+        style.paint(self)
     }
 }
 
 // Implement ByteColor for String
 impl ByteColor for String {
-    fn red(&self) -> String {
-        format!("\x1b[31m{}\x1b[0m", self)
+    fn red(&self) -> StyledString {
+        Style::new().fg(Color::Red).paint(self)
     }
 
-    fn green(&self) -> String {
-        format!("\x1b[32m{}\x1b[0m", self)
+    fn green(&self) -> StyledString {
+        Style::new().fg(Color::Green).paint(self)
     }
 
-    fn yellow(&self) -> String {
-        format!("\x1b[33m{}\x1b[0m", self)
+    fn yellow(&self) -> StyledString {
+        Style::new().fg(Color::Yellow).paint(self)
     }
 
-    fn magenta(&self) -> String {
-        format!("\x1b[35m{}\x1b[0m", self)
+    fn magenta(&self) -> StyledString {
+        Style::new().fg(Color::Magenta).paint(self)
     }
 
-    fn cyan(&self) -> String {
-        format!("\x1b[36m{}\x1b[0m", self)
+    fn cyan(&self) -> StyledString {
+        Style::new().fg(Color::Cyan).paint(self)
     }
 
-    fn blue(&self) -> String {
-        format!("\x1b[34m{}\x1b[0m", self)
+    fn blue(&self) -> StyledString {
+        Style::new().fg(Color::Blue).paint(self)
     }
 
-    fn bold(&self) -> String {
-        format!("\x1b[1m{}\x1b[0m", self)
+    fn bold(&self) -> StyledString {
+        Style::new().bold().paint(self)
     }
 
-    fn underline(&self) -> String {
-        format!("\x1b[4m{}\x1b[0m", self)
+    fn underline(&self) -> StyledString {
+        Style::new().underline().paint(self)
     }
 
-    fn blink(&self) -> String {
-        format!("\x1b[5m{}\x1b[0m", self)
+    fn blink(&self) -> StyledString {
+        Style::new().blink().paint(self)
     }
 
-    fn rgb(&self, rgb: (u8, u8, u8)) -> String {
-        format!("\x1b[38;2;{};{};{}m{}\x1b[0m", rgb.0, rgb.1, rgb.2, self)
+    fn rgb(&self, rgb: (u8, u8, u8)) -> StyledString {
+        Style::new().fg(Color::Rgb(rgb.0, rgb.1, rgb.2)).paint(self)
     }
 
-    fn color(&self, code: u8) -> String {
-        format!("\x1b[38;5;{}m{}\x1b[0m", code, self)
+    fn color(&self, code: u8) -> StyledString {
+        Style::new().fg(Color::Fixed(code)).paint(self)
+    }
+
+    fn styled(&self, style: Style) -> StyledString {
+        style.paint(self)
     }
 }
 
 // Implement ByteColor for &[u8]
 impl ByteColor for &[u8] {
-    fn red(&self) -> String {
-        format!("\x1b[31m{}\x1b[0m", String::from_utf8_lossy(self))
+    fn red(&self) -> StyledString {
+        Style::new()
+            .fg(Color::Red)
+            .paint(String::from_utf8_lossy(self))
+    }
+
+    fn green(&self) -> StyledString {
+        Style::new()
+            .fg(Color::Green)
+            .paint(String::from_utf8_lossy(self))
     }
 
-    fn green(&self) -> String {
-        format!("\x1b[32m{}\x1b[0m", String::from_utf8_lossy(self))
+    fn yellow(&self) -> StyledString {
+        Style::new()
+            .fg(Color::Yellow)
+            .paint(String::from_utf8_lossy(self))
     }
 
-    fn yellow(&self) -> String {
-        format!("\x1b[33m{}\x1b[0m", String::from_utf8_lossy(self))
+    fn magenta(&self) -> StyledString {
+        Style::new()
+            .fg(Color::Magenta)
+            .paint(String::from_utf8_lossy(self))
     }
 
-    fn magenta(&self) -> String {
-        format!("\x1b[35m{}\x1b[0m", String::from_utf8_lossy(self))
+    fn cyan(&self) -> StyledString {
+        Style::new()
+            .fg(Color::Cyan)
+            .paint(String::from_utf8_lossy(self))
     }
 
-    fn cyan(&self) -> String {
-        format!("\x1b[36m{}\x1b[0m", String::from_utf8_lossy(self))
+    fn blue(&self) -> StyledString {
+        Style::new()
+            .fg(Color::Blue)
+            .paint(String::from_utf8_lossy(self))
     }
 
-    fn blue(&self) -> String {
-        format!("\x1b[34m{}\x1b[0m", String::from_utf8_lossy(self))
+    fn bold(&self) -> StyledString {
+        Style::new().bold().paint(String::from_utf8_lossy(self))
     }
 
-    fn bold(&self) -> String {
-        format!("\x1b[1m{}\x1b[0m", String::from_utf8_lossy(self))
+    fn underline(&self) -> StyledString {
+        Style::new()
+            .underline()
+            .paint(String::from_utf8_lossy(self))
     }
 
-    fn underline(&self) -> String {
-        format!("\x1b[4m{}\x1b[0m", String::from_utf8_lossy(self))
+    fn blink(&self) -> StyledString {
+        Style::new().blink().paint(String::from_utf8_lossy(self))
     }
 
-    fn blink(&self) -> String {
-        format!("\x1b[5m{}\x1b[0m", String::from_utf8_lossy(self))
+    fn rgb(&self, rgb: (u8, u8, u8)) -> StyledString {
+        Style::new()
+            .fg(Color::Rgb(rgb.0, rgb.1, rgb.2))
+            .paint(String::from_utf8_lossy(self))
     }
 
-    fn rgb(&self, rgb: (u8, u8, u8)) -> String {
-        format!(
-            "\x1b[38;2;{};{};{}m{}\x1b[0m",
-            rgb.0,
-            rgb.1,
-            rgb.2,
-            String::from_utf8_lossy(self)
-        )
+    fn color(&self, code: u8) -> StyledString {
+        Style::new()
+            .fg(Color::Fixed(code))
+            .paint(String::from_utf8_lossy(self))
     }
 
-    fn color(&self, code: u8) -> String {
-        format!(
-            "\x1b[38;5;{}m{}\x1b[0m",
-            code,
-            String::from_utf8_lossy(self)
-        )
+    fn styled(&self, style: Style) -> StyledString {
+        style.paint(String::from_utf8_lossy(self))
     }
 }
 
 // Implement ByteColor for Vec<u8>
 impl ByteColor for Vec<u8> {
-    fn red(&self) -> String {
-        format!("\x1b[31m{}\x1b[0m", String::from_utf8_lossy(&self))
+    fn red(&self) -> StyledString {
+        Style::new()
+            .fg(Color::Red)
+            .paint(String::from_utf8_lossy(self))
+    }
+
+    fn green(&self) -> StyledString {
+        Style::new()
+            .fg(Color::Green)
+            .paint(String::from_utf8_lossy(self))
     }
 
-    fn green(&self) -> String {
-        format!("\x1b[32m{}\x1b[0m", String::from_utf8_lossy(&self))
+    fn yellow(&self) -> StyledString {
+        Style::new()
+            .fg(Color::Yellow)
+            .paint(String::from_utf8_lossy(self))
     }
 
-    fn yellow(&self) -> String {
-        format!("\x1b[33m{}\x1b[0m", String::from_utf8_lossy(&self))
+    fn magenta(&self) -> StyledString {
+        Style::new()
+            .fg(Color::Magenta)
+            .paint(String::from_utf8_lossy(self))
     }
 
-    fn magenta(&self) -> String {
-        format!("\x1b[35m{}\x1b[0m", String::from_utf8_lossy(&self))
+    fn cyan(&self) -> StyledString {
+        Style::new()
+            .fg(Color::Cyan)
+            .paint(String::from_utf8_lossy(self))
     }
 
-    fn cyan(&self) -> String {
-        format!("\x1b[36m{}\x1b[0m", String::from_utf8_lossy(&self))
+    fn blue(&self) -> StyledString {
+        Style::new()
+            .fg(Color::Blue)
+            .paint(String::from_utf8_lossy(self))
     }
 
-    fn blue(&self) -> String {
-        format!("\x1b[34m{}\x1b[0m", String::from_utf8_lossy(&self))
+    fn bold(&self) -> StyledString {
+        Style::new().bold().paint(String::from_utf8_lossy(self))
     }
 
-    fn bold(&self) -> String {
-        format!("\x1b[1m{}\x1b[0m", String::from_utf8_lossy(&self))
+    fn underline(&self) -> StyledString {
+        Style::new()
+            .underline()
+            .paint(String::from_utf8_lossy(self))
     }
 
-    fn underline(&self) -> String {
-        format!("\x1b[4m{}\x1b[0m", String::from_utf8_lossy(&self))
+    fn blink(&self) -> StyledString {
+        Style::new().blink().paint(String::from_utf8_lossy(self))
     }
 
-    fn blink(&self) -> String {
-        format!("\x1b[5m{}\x1b[0m", String::from_utf8_lossy(&self))
+    fn rgb(&self, rgb: (u8, u8, u8)) -> StyledString {
+        Style::new()
+            .fg(Color::Rgb(rgb.0, rgb.1, rgb.2))
+            .paint(String::from_utf8_lossy(self))
     }
 
-    fn rgb(&self, rgb: (u8, u8, u8)) -> String {
-        format!(
-            "\x1b[38;2;{};{};{}m{}\x1b[0m",
-            rgb.0,
-            rgb.1,
-            rgb.2,
-            String::from_utf8_lossy(&self)
-        )
+    fn color(&self, code: u8) -> StyledString {
+        Style::new()
+            .fg(Color::Fixed(code))
+            .paint(String::from_utf8_lossy(self))
     }
 
-    fn color(&self, code: u8) -> String {
-        format!(
-            "\x1b[38;5;{}m{}\x1b[0m",
-            code,
-            String::from_utf8_lossy(&self)
-        )
+    fn styled(&self, style: Style) -> StyledString {
+        style.paint(String::from_utf8_lossy(self))
     }
 }
 
 // Implement ByteColor for &[u8; N]
 impl<const N: usize> ByteColor for &[u8; N] {
-    fn red(&self) -> String {
+    fn red(&self) -> StyledString {
         self.as_ref().red()
     }
 
-    fn green(&self) -> String {
+    fn green(&self) -> StyledString {
         self.as_ref().green()
     }
 
-    fn yellow(&self) -> String {
+    fn yellow(&self) -> StyledString {
         self.as_ref().yellow()
     }
 
-    fn magenta(&self) -> String {
+    fn magenta(&self) -> StyledString {
         self.as_ref().magenta()
     }
 
-    fn cyan(&self) -> String {
+    fn cyan(&self) -> StyledString {
         self.as_ref().cyan()
     }
 
-    fn blue(&self) -> String {
+    fn blue(&self) -> StyledString {
         self.as_ref().blue()
     }
 
-    fn bold(&self) -> String {
+    fn bold(&self) -> StyledString {
         self.as_ref().bold()
     }
 
-    fn underline(&self) -> String {
+    fn underline(&self) -> StyledString {
         self.as_ref().underline()
     }
 
-    fn blink(&self) -> String {
+    fn blink(&self) -> StyledString {
         self.as_ref().blink()
     }
 
-    fn rgb(&self, rgb: (u8, u8, u8)) -> String {
+    fn rgb(&self, rgb: (u8, u8, u8)) -> StyledString {
         self.as_ref().rgb(rgb)
     }
 
-    fn color(&self, code: u8) -> String {
+    fn color(&self, code: u8) -> StyledString {
         self.as_ref().color(code)
     }
+
+    fn styled(&self, style: Style) -> StyledString {
+        self.as_ref().styled(style)
+    }
 }
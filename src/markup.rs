@@ -0,0 +1,107 @@
+//! Helpers shared by the modules writing styles in other formats: HTML, SVG, JSON, tmux and shells.
+
+#[cfg(any(feature = "html", feature = "serde"))]
+use std::borrow::Cow;
+
+#[cfg(any(feature = "html", feature = "serde"))]
+use crate::style::color_name;
+#[cfg(any(feature = "html", feature = "serde"))]
+use crate::Color;
+use crate::Style;
+
+/// Returns the names of the attributes set in `style`, in SGR order: `bold`, `dim`, `italic`,
+/// `underline`, `blink`, `reverse`, `hidden` and `strikethrough`.
+pub(crate) fn attributes(style: Style) -> impl Iterator<Item = &'static str> {
+    [
+        (style.is_bold(), "bold"),
+        (style.is_dim(), "dim"),
+        (style.is_italic(), "italic"),
+        (style.is_underline(), "underline"),
+        (style.is_blink(), "blink"),
+        (style.is_reverse(), "reverse"),
+        (style.is_hidden(), "hidden"),
+        (style.is_strikethrough(), "strikethrough"),
+    ]
+    .into_iter()
+    .filter_map(|(set, name)| set.then_some(name))
+}
+
+/// Formats an RGB color as `#rrggbb`.
+pub(crate) fn hex((r, g, b): (u8, u8, u8)) -> String {
+    format!("#{:02x}{:02x}{:02x}", r, g, b)
+}
+
+/// Appends `text` to `out`, escaped for HTML and XML text and attribute values.
+#[cfg(feature = "html")]
+pub(crate) fn escape_html(out: &mut String, text: &str) {
+    for c in text.chars() {
+        match c {
+            '&' => out.push_str("&amp;"),
+            '<' => out.push_str("&lt;"),
+            '>' => out.push_str("&gt;"),
+            '"' => out.push_str("&quot;"),
+            c => out.push(c),
+        }
+    }
+}
+
+/// Appends `text` to `out` as a quoted JSON string.
+#[cfg(any(feature = "basic", feature = "html"))]
+pub(crate) fn escape_json(out: &mut String, text: &str) {
+    use std::fmt::Write;
+
+    out.push('"');
+    for c in text.chars() {
+        match c {
+            '"' => out.push_str("\\\""),
+            '\\' => out.push_str("\\\\"),
+            '\n' => out.push_str("\\n"),
+            '\r' => out.push_str("\\r"),
+            '\t' => out.push_str("\\t"),
+            c if c.is_control() => {
+                let _ = write!(out, "\\u{:04x}", c as u32);
+            }
+            c => out.push(c),
+        }
+    }
+    out.push('"');
+}
+
+/// The value of a color in the map form of a style.
+#[cfg(any(feature = "html", feature = "serde"))]
+pub(crate) enum ColorValue {
+    /// A palette index.
+    Index(u8),
+    /// The snake case name of a standard color, or `#rrggbb` for RGB colors.
+    Text(Cow<'static, str>),
+}
+
+#[cfg(any(feature = "html", feature = "serde"))]
+impl From<Color> for ColorValue {
+    fn from(color: Color) -> Self {
+        match color {
+            Color::Fixed(index) => ColorValue::Index(index),
+            Color::Rgb(..) => ColorValue::Text(hex(color.to_rgb()).into()),
+            _ => ColorValue::Text(color_name(color).into()),
+        }
+    }
+}
+
+/// A value of the map form of a style.
+#[cfg(any(feature = "html", feature = "serde"))]
+pub(crate) enum Entry {
+    Color(Color),
+    /// `true`, for the attributes that are set.
+    Set,
+}
+
+/// Returns the entries of the map form of `style`, as serialized with the `serde` feature and
+/// rendered for [`Target::Json`](crate::render::Target::Json): the `fg` and `bg` colors that are set,
+/// then the attributes that are set.
+#[cfg(any(feature = "html", feature = "serde"))]
+pub(crate) fn style_entries(style: Style) -> impl Iterator<Item = (&'static str, Entry)> {
+    [("fg", style.foreground()), ("bg", style.background())]
+        .into_iter()
+        .filter_map(|(key, color)| Some((key, Entry::Color(color?))))
+        .chain(attributes(style).map(|name| (name, Entry::Set)))
+}
@@ -0,0 +1,563 @@
+//! # Number Formatting
+//!
+//! Colored views of integers for register and flag dumps: [`IntegerColor`] renders hexadecimal, binary
+//! and octal representations at the full width of the type, with the radix prefix dimmed and digit
+//! groups alternating between two shades so long values stay readable.
+//!
+//! ```rust
+//! use bytescolor::number::IntegerColor;
+//!
+//! let status: u32 = 0xdead_beef;
+//! println!("status {}", status.hex_colored()); // Displays "0xdead_beef"
+//! println!("flags  {}", 0b1010_0101u8.bin_colored()); // Displays "0b1010_0101"
+//! ```
+//!
+//! [`count_styled`] renders counts with the singular or plural noun, as in `1 file` and `3 files`.
+//!
+//! [`ThousandsColor`] groups the digits of large counters with dimmed separators, and [`FloatColor`]
+//! renders amounts in fixed notation like a ledger, negative values in red.
+//!
+//! For protocol and driver debugging, [`BitsColor`] highlights the set bits of unsigned integers and can
+//! name them with a [`Flags`] legend.
+//!
+//! [`HumanBytes`] formats sizes for disk and network reports, optionally coloring them by size with
+//! [`Thresholds`], and [`Percent`] colors percentages along a [`Gradient`](crate::gradient::Gradient) for
+//! health and coverage summaries (`rgb` feature).
+//!
+//! This module is available with the `basic` feature, enabled by default.
+
+use std::fmt;
+
+#[cfg(feature = "rgb")]
+use crate::gradient::Gradient;
+use crate::{Color, Style, StyledString};
+
+const PREFIX: Style = Style::new().dim();
+const SEPARATOR: Style = Style::new().dim();
+pub(crate) const SHADES: [Style; 2] = [
+    Style::new().fg(Color::Cyan),
+    Style::new().fg(Color::BrightCyan),
+];
+
+/// Extension trait rendering integers in colored radix notations.
+///
+/// Signed integers are rendered in two's complement, like the `{:x}` format specifier does.
+pub trait IntegerColor {
+    /// Renders the value in hexadecimal, `0x`-prefixed, in groups of four digits.
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// use bytescolor::number::IntegerColor;
+    ///
+    /// assert_eq!(0xbeef_u16.hex_colored(), "0xbeef");
+    /// assert_eq!(0x1234_5678_u32.hex_colored(), "0x1234_5678");
+    /// assert_eq!((-1_i8).hex_colored(), "0xff");
+    /// ```
+    fn hex_colored(&self) -> StyledString;
+
+    /// Renders the value in binary, `0b`-prefixed, in groups of four bits.
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// use bytescolor::number::IntegerColor;
+    ///
+    /// assert_eq!(5_u8.bin_colored(), "0b0000_0101");
+    /// ```
+    fn bin_colored(&self) -> StyledString;
+
+    /// Renders the value in octal, `0o`-prefixed, in groups of three digits.
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// use bytescolor::number::IntegerColor;
+    ///
+    /// assert_eq!(0o755_u16.oct_colored(), "0o000_755");
+    /// ```
+    fn oct_colored(&self) -> StyledString;
+}
+
+macro_rules! impl_integer_color {
+    ($($t:ty => $unsigned:ty),*) => {
+        $(
+            impl IntegerColor for $t {
+                fn hex_colored(&self) -> StyledString {
+                    radix(*self as $unsigned as u128, <$t>::BITS, 4, "0x", 4)
+                }
+
+                fn bin_colored(&self) -> StyledString {
+                    radix(*self as $unsigned as u128, <$t>::BITS, 1, "0b", 4)
+                }
+
+                fn oct_colored(&self) -> StyledString {
+                    radix(*self as $unsigned as u128, <$t>::BITS, 3, "0o", 3)
+                }
+            }
+        )*
+    };
+}
+
+impl_integer_color!(
+    u8 => u8, u16 => u16, u32 => u32, u64 => u64, u128 => u128, usize => usize,
+    i8 => u8, i16 => u16, i32 => u32, i64 => u64, i128 => u128, isize => usize
+);
+
+/// Extension trait rendering integers with dimmed digit group separators.
+pub trait ThousandsColor {
+    /// Renders the value with a `,` between groups of three digits, the separators dimmed, so large
+    /// counters read at a glance.
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// use bytescolor::number::ThousandsColor;
+    ///
+    /// assert_eq!(1_234_567_u32.thousands_colored(), "1,234,567");
+    /// assert_eq!((-98_765_i64).thousands_colored(), "-98,765");
+    /// assert_eq!(999_u16.thousands_colored(), "999");
+    /// ```
+    fn thousands_colored(&self) -> StyledString {
+        self.thousands_colored_with(',')
+    }
+
+    /// Renders the value like [`thousands_colored`](ThousandsColor::thousands_colored), with
+    /// `separator` between digit groups, such as `.` or a narrow no-break space (`'\u{202f}'`) for
+    /// locales that use them.
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// use bytescolor::number::ThousandsColor;
+    ///
+    /// assert_eq!(1_048_576_u64.thousands_colored_with('.'), "1.048.576");
+    /// ```
+    fn thousands_colored_with(&self, separator: char) -> StyledString;
+}
+
+macro_rules! impl_thousands_color {
+    ($($t:ty),*) => {
+        $(
+            impl ThousandsColor for $t {
+                fn thousands_colored_with(&self, separator: char) -> StyledString {
+                    thousands(&self.to_string(), separator)
+                }
+            }
+        )*
+    };
+}
+
+impl_thousands_color!(u8, u16, u32, u64, u128, usize, i8, i16, i32, i64, i128, isize);
+
+/// Inserts dimmed separators between the groups of three digits of a formatted integer.
+fn thousands(formatted: &str, separator: char) -> StyledString {
+    let (sign, digits) = match formatted.strip_prefix('-') {
+        Some(digits) => ("-", digits),
+        None => ("", formatted),
+    };
+    let mut out = String::from(sign);
+    for (index, digit) in digits.char_indices() {
+        if index > 0 && (digits.len() - index) % 3 == 0 {
+            out.push_str(&SEPARATOR.wrap(separator));
+        }
+        out.push(digit);
+    }
+    StyledString::from(out)
+}
+
+const COUNT: Style = Style::new().bold();
+
+/// Renders a count followed by the noun matching it, `singular` for exactly one and `plural`
+/// otherwise, the count bold.
+///
+/// # Examples
+///
+/// ```rust
+/// use bytescolor::number::count_styled;
+///
+/// let files = vec!["a.rs", "b.rs"];
+/// assert_eq!(count_styled(files.len(), "file", "files"), "2 files");
+/// assert_eq!(count_styled(1_u64, "match", "matches"), "1 match");
+/// assert_eq!(count_styled(0, "warning", "warnings"), "0 warnings");
+/// ```
+pub fn count_styled<T>(n: T, singular: &str, plural: &str) -> StyledString
+where
+    T: fmt::Display + PartialEq + From<u8>,
+{
+    let noun = if n == T::from(1) { singular } else { plural };
+    StyledString::from(format!("{} {}", COUNT.wrap(n), noun))
+}
+
+/// Extension trait rendering floating-point numbers in fixed notation, ledger style.
+pub trait FloatColor {
+    /// Renders the value with `decimals` digits after the decimal point, the integer part bold, the
+    /// separator dimmed and the fraction plain. Negative values are red; values rounding to zero are
+    /// shown without a sign.
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// use bytescolor::number::FloatColor;
+    ///
+    /// assert_eq!(1234.5_f64.fixed_colored(2), "1234.50");
+    /// assert_eq!((-3.14159_f32).fixed_colored(3), "-3.142");
+    /// assert_eq!((-0.001_f64).fixed_colored(2), "0.00");
+    /// assert_eq!(7.0_f64.fixed_colored(0), "7");
+    /// println!("{}", (-12.5_f64).fixed_colored(2)); // Displays "-12.50" in red
+    /// ```
+    fn fixed_colored(&self, decimals: usize) -> StyledString;
+}
+
+macro_rules! impl_float_color {
+    ($($t:ty),*) => {
+        $(
+            impl FloatColor for $t {
+                fn fixed_colored(&self, decimals: usize) -> StyledString {
+                    fixed(&format!("{:.*}", decimals, self))
+                }
+            }
+        )*
+    };
+}
+
+impl_float_color!(f32, f64);
+
+const INTEGER: Style = Style::new().bold();
+const NEGATIVE: Style = Style::new().fg(Color::Red);
+
+/// Colors a number formatted in fixed notation, such as `-12.50`, `NaN` or `inf`.
+fn fixed(formatted: &str) -> StyledString {
+    let formatted = match formatted.strip_prefix('-') {
+        Some(zero) if zero.bytes().all(|b| b == b'0' || b == b'.') => zero,
+        _ => formatted,
+    };
+    let tint = if formatted.starts_with('-') {
+        NEGATIVE
+    } else {
+        Style::new()
+    };
+    let out = match formatted.split_once('.') {
+        Some((integer, fraction)) => format!(
+            "{}{}{}",
+            (INTEGER | tint).wrap(integer),
+            (SEPARATOR | tint).wrap('.'),
+            tint.wrap(fraction)
+        ),
+        None => (INTEGER | tint).wrap(formatted),
+    };
+    StyledString::from(out)
+}
+
+/// Renders the `bits` low bits of `value` with `digit_bits` bits per digit, in groups of `group`
+/// digits counted from the least significant end.
+fn radix(value: u128, bits: u32, digit_bits: u32, prefix: &str, group: usize) -> StyledString {
+    let digits = ((bits + digit_bits - 1) / digit_bits) as usize;
+    let mask = (1u128 << digit_bits) - 1;
+    let mut out = PREFIX.wrap(prefix);
+    let mut chunk = String::new();
+    for index in (0..digits).rev() {
+        let digit = (value >> (index as u32 * digit_bits)) & mask;
+        chunk.push(std::char::from_digit(digit as u32, 16).expect("digit below 16"));
+        if index % group == 0 {
+            out.push_str(&SHADES[index / group % 2].wrap(&chunk));
+            chunk.clear();
+            if index > 0 {
+                out.push_str(&SEPARATOR.wrap('_'));
+            }
+        }
+    }
+    StyledString::from(out)
+}
+
+/// Extension trait highlighting the bits of unsigned integers.
+pub trait BitsColor {
+    /// Renders the binary representation at the full width of the type, most significant bit first,
+    /// with set bits in `set` and clear bits in `clear`.
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// use bytescolor::number::BitsColor;
+    /// use bytescolor::{Color, Style};
+    ///
+    /// let bits = 0b0110_0001_u8.bits_colored(Style::new().fg(Color::Green), Style::new().dim());
+    /// assert_eq!(bits, "0110_0001");
+    /// ```
+    fn bits_colored(&self, set: Style, clear: Style) -> StyledString;
+
+    /// Renders the bits followed by the names of the `flags`, set flags in the set style and clear flags
+    /// in the clear style.
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// use bytescolor::number::{BitsColor, Flags};
+    ///
+    /// let flags = Flags::new().flag(0, "READ").flag(1, "WRITE").flag(2, "EXEC");
+    /// assert_eq!(0b101_u8.flags_colored(&flags), "0000_0101  READ WRITE EXEC");
+    /// ```
+    fn flags_colored(&self, flags: &Flags) -> StyledString;
+}
+
+/// A legend naming individual bits, used by [`BitsColor::flags_colored`].
+#[derive(Debug, Clone, PartialEq, Eq, Hash)]
+pub struct Flags {
+    names: Vec<(u32, String)>,
+    set: Style,
+    clear: Style,
+}
+
+impl Flags {
+    /// Creates an empty legend rendering set bits in bold green and clear bits dimmed.
+    pub fn new() -> Self {
+        Flags {
+            names: Vec::new(),
+            set: Style::new().fg(Color::Green).bold(),
+            clear: Style::new().dim(),
+        }
+    }
+
+    /// Names `bit`, counted from the least significant bit.
+    pub fn flag(mut self, bit: u32, name: impl Into<String>) -> Self {
+        self.names.push((bit, name.into()));
+        self
+    }
+
+    /// Sets the style of set bits and set flag names.
+    pub fn set_style(mut self, style: Style) -> Self {
+        self.set = style;
+        self
+    }
+
+    /// Sets the style of clear bits and clear flag names.
+    pub fn clear_style(mut self, style: Style) -> Self {
+        self.clear = style;
+        self
+    }
+
+    fn render(&self, value: u128, bits: u32) -> StyledString {
+        let mut out = render_bits(value, bits, self.set, self.clear).into_string();
+        if !self.names.is_empty() {
+            out.push(' ');
+        }
+        for (bit, name) in &self.names {
+            let set = *bit < bits && value >> bit & 1 == 1;
+            out.push(' ');
+            out.push_str(&if set { self.set } else { self.clear }.wrap(name));
+        }
+        StyledString::from(out)
+    }
+}
+
+impl Default for Flags {
+    fn default() -> Self {
+        Flags::new()
+    }
+}
+
+macro_rules! impl_bits_color {
+    ($($t:ty),*) => {
+        $(
+            impl BitsColor for $t {
+                fn bits_colored(&self, set: Style, clear: Style) -> StyledString {
+                    render_bits(*self as u128, <$t>::BITS, set, clear)
+                }
+
+                fn flags_colored(&self, flags: &Flags) -> StyledString {
+                    flags.render(*self as u128, <$t>::BITS)
+                }
+            }
+        )*
+    };
+}
+
+impl_bits_color!(u8, u16, u32, u64, u128, usize);
+
+fn render_bits(value: u128, bits: u32, set: Style, clear: Style) -> StyledString {
+    let mut out = String::new();
+    for bit in (0..bits).rev() {
+        if value >> bit & 1 == 1 {
+            out.push_str(&set.wrap('1'));
+        } else {
+            out.push_str(&clear.wrap('0'));
+        }
+        if bit > 0 && bit % 4 == 0 {
+            out.push_str(&SEPARATOR.wrap('_'));
+        }
+    }
+    StyledString::from(out)
+}
+
+/// Picks a style by comparing a value against ascending limits.
+///
+/// # Examples
+///
+/// ```rust
+/// use bytescolor::number::Thresholds;
+/// use bytescolor::{Color, Style};
+///
+/// let load = Thresholds::new(Style::new().fg(Color::Green))
+///     .above(0.7, Style::new().fg(Color::Yellow))
+///     .above(0.9, Style::new().fg(Color::Red));
+/// assert_eq!(load.style_for(0.5), Style::new().fg(Color::Green));
+/// assert_eq!(load.style_for(0.95), Style::new().fg(Color::Red));
+/// ```
+#[derive(Debug, Clone, PartialEq)]
+pub struct Thresholds {
+    base: Style,
+    limits: Vec<(f64, Style)>,
+}
+
+impl Thresholds {
+    /// Creates thresholds styling every value in `base`.
+    pub fn new(base: Style) -> Self {
+        Thresholds {
+            base,
+            limits: Vec::new(),
+        }
+    }
+
+    /// Styles values greater than or equal to `limit` in `style`, unless a higher limit applies.
+    pub fn above(mut self, limit: f64, style: Style) -> Self {
+        let index = self.limits.partition_point(|&(other, _)| other <= limit);
+        self.limits.insert(index, (limit, style));
+        self
+    }
+
+    /// Returns the style for `value`.
+    pub fn style_for(&self, value: f64) -> Style {
+        self.limits
+            .iter()
+            .rev()
+            .find(|&&(limit, _)| value >= limit)
+            .map_or(self.base, |&(_, style)| style)
+    }
+}
+
+/// Extension trait formatting unsigned integers as human-readable byte sizes.
+pub trait HumanBytes {
+    /// Formats the size with binary units, the unit dimmed: `1536` becomes `1.5 KiB`.
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// use bytescolor::number::HumanBytes;
+    ///
+    /// assert_eq!(512_u32.human_bytes(), "512 B");
+    /// assert_eq!(1536_u32.human_bytes(), "1.5 KiB");
+    /// assert_eq!(1_048_575_u32.human_bytes(), "1.0 MiB");
+    /// assert_eq!((3_u64 << 30).human_bytes(), "3.0 GiB");
+    /// ```
+    fn human_bytes(&self) -> StyledString;
+
+    /// Formats the size like [`human_bytes`](HumanBytes::human_bytes), coloring the number by the
+    /// size in bytes.
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// use bytescolor::number::{HumanBytes, Thresholds};
+    /// use bytescolor::{Color, Style};
+    ///
+    /// let usage = Thresholds::new(Style::new().fg(Color::Green))
+    ///     .above((1u64 << 30) as f64, Style::new().fg(Color::Red));
+    /// println!("{}", (5_u64 << 30).human_bytes_with(&usage)); // Displays "5.0" in red, then "GiB"
+    /// ```
+    fn human_bytes_with(&self, thresholds: &Thresholds) -> StyledString;
+}
+
+macro_rules! impl_human_bytes {
+    ($($t:ty),*) => {
+        $(
+            impl HumanBytes for $t {
+                fn human_bytes(&self) -> StyledString {
+                    human_bytes(*self as u128, Style::new())
+                }
+
+                fn human_bytes_with(&self, thresholds: &Thresholds) -> StyledString {
+                    human_bytes(*self as u128, thresholds.style_for(*self as f64))
+                }
+            }
+        )*
+    };
+}
+
+impl_human_bytes!(u8, u16, u32, u64, u128, usize);
+
+const UNIT: Style = Style::new().dim();
+
+fn human_bytes(bytes: u128, style: Style) -> StyledString {
+    const UNITS: [&str; 7] = ["KiB", "MiB", "GiB", "TiB", "PiB", "EiB", "ZiB"];
+    if bytes < 1024 {
+        return StyledString::from(format!("{} {}", style.wrap(bytes), UNIT.wrap("B")));
+    }
+    let mut value = bytes as f64 / 1024.0;
+    let mut unit = 0;
+    // Compare the rounded value, so sizes just below a unit are not shown as 1024.0 of the previous.
+    while (value * 10.0).round() / 10.0 >= 1024.0 && unit + 1 < UNITS.len() {
+        value /= 1024.0;
+        unit += 1;
+    }
+    StyledString::from(format!(
+        "{} {}",
+        style.wrap(format_args!("{:.1}", value)),
+        UNIT.wrap(UNITS[unit])
+    ))
+}
+
+/// Extension trait rendering numbers between 0 and 100 as colored percentages.
+///
+/// Available with the `rgb` feature.
+#[cfg(feature = "rgb")]
+pub trait Percent {
+    /// Renders the value followed by `%`, colored from red at 0 through yellow to green at 100.
+    /// Floats are shown with one decimal.
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// use bytescolor::number::Percent;
+    ///
+    /// assert_eq!(87.5.percent(), "87.5%");
+    /// assert_eq!(42_u8.percent(), "42%");
+    /// ```
+    fn percent(&self) -> StyledString {
+        self.percent_with(&Gradient::red_to_green())
+    }
+
+    /// Renders the value followed by `%`, colored along `gradient`: values at or below 0 take its
+    /// first color, values at or above 100 its last.
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// use bytescolor::gradient::Gradient;
+    /// use bytescolor::number::Percent;
+    ///
+    /// // Low disk usage is good: green to red.
+    /// let usage = Gradient::new(vec![(0, 200, 0), (220, 200, 0), (220, 0, 0)]);
+    /// println!("{}", 93.percent_with(&usage)); // Displays "93%" in red
+    /// ```
+    fn percent_with(&self, gradient: &Gradient) -> StyledString;
+}
+
+#[cfg(feature = "rgb")]
+macro_rules! impl_percent {
+    ($precision:literal => $($t:ty),*) => {
+        $(
+            impl Percent for $t {
+                fn percent_with(&self, gradient: &Gradient) -> StyledString {
+                    let value = *self as f64;
+                    let style = Style::new().fg(gradient.at(value / 100.0));
+                    StyledString::from(style.wrap(format_args!("{:.*}%", $precision, value)))
+                }
+            }
+        )*
+    };
+}
+
+#[cfg(feature = "rgb")]
+impl_percent!(1 => f32, f64);
+#[cfg(feature = "rgb")]
+impl_percent!(0 => u8, u16, u32, u64, usize, i8, i16, i32, i64, isize);
@@ -0,0 +1,239 @@
+//! # OSC Helpers
+//!
+//! Operating System Command sequences talk to the terminal emulator rather than style text: they set the
+//! window title, place data on the system clipboard (OSC 52) or raise desktop notifications.
+//!
+//! These helpers go through the same machinery as colors: when escape output is disabled with
+//! [`control`](crate::control), every helper returns an empty string. User-provided text is stripped of
+//! control characters so it cannot terminate the sequence early and inject escapes of its own.
+//!
+//! Terminal multiplexers swallow sequences they do not understand. With [`set_passthrough`], the helpers
+//! wrap their output in a DCS passthrough envelope so tmux or GNU screen forwards it to the outer terminal.
+//!
+//! ```rust
+//! use bytescolor::osc;
+//!
+//! print!("{}", osc::set_title("build: running"));
+//! print!("{}", osc::notify("build", "finished in 42s"));
+//! ```
+
+use crate::{base64, control};
+
+const BEL: char = '\x07';
+
+/// How OSC sequences are forwarded through terminal multiplexers.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash, Default)]
+pub enum Passthrough {
+    /// Emit sequences as is (the default).
+    #[default]
+    Never,
+    /// Wrap sequences when running inside tmux (`$TMUX`) or GNU screen (`$TERM` starting with `screen`
+    /// outside tmux).
+    Auto,
+    /// Always wrap sequences for tmux.
+    Tmux,
+    /// Always wrap sequences for GNU screen.
+    Screen,
+}
+
+/// Sets how the helpers of this module forward sequences through multiplexers, a part of the global
+/// [`Config`](control::Config) that [`control::with_config`] overrides for a thread.
+///
+/// # Examples
+///
+/// ```rust
+/// # if cfg!(feature = "no-color-build") { return; }
+/// use bytescolor::control::{self, Config};
+/// use bytescolor::osc::{self, Passthrough};
+///
+/// osc::set_passthrough(Passthrough::Tmux);
+/// assert_eq!(osc::set_title("vim"), "\x1bPtmux;\x1b\x1b]0;vim\x07\x1b\\");
+/// # osc::set_passthrough(Passthrough::Never);
+///
+/// let screen = Config { passthrough: Passthrough::Screen, ..control::config() };
+/// control::with_config(screen, || assert_eq!(osc::set_title("vim"), "\x1bP\x1b]0;vim\x07\x1b\\"));
+/// ```
+pub fn set_passthrough(mode: Passthrough) {
+    control::update_config(|config| config.passthrough = mode);
+}
+
+/// Returns the passthrough mode in effect on the current thread.
+pub fn passthrough() -> Passthrough {
+    control::config().passthrough
+}
+
+/// Wraps an escape `sequence` in a tmux DCS passthrough envelope, doubling the escape characters it
+/// contains. tmux only forwards it with `set -g allow-passthrough on`.
+///
+/// # Examples
+///
+/// ```rust
+/// use bytescolor::osc;
+///
+/// assert_eq!(osc::wrap_tmux("\x1b]2;t\x07"), "\x1bPtmux;\x1b\x1b]2;t\x07\x1b\\");
+/// ```
+pub fn wrap_tmux(sequence: &str) -> String {
+    format!("\x1bPtmux;{}\x1b\\", sequence.replace('\x1b', "\x1b\x1b"))
+}
+
+/// Wraps an escape `sequence` in GNU screen DCS passthrough envelopes. screen limits the length of a
+/// single string, so long sequences are split across several envelopes.
+///
+/// # Examples
+///
+/// ```rust
+/// use bytescolor::osc;
+///
+/// assert_eq!(osc::wrap_screen("\x1b]2;t\x07"), "\x1bP\x1b]2;t\x07\x1b\\");
+/// ```
+pub fn wrap_screen(sequence: &str) -> String {
+    const CHUNK: usize = 768;
+    let mut out = String::with_capacity(sequence.len() + 4);
+    let mut rest = sequence;
+    while !rest.is_empty() {
+        let mut end = rest.len().min(CHUNK);
+        while !rest.is_char_boundary(end) {
+            end -= 1;
+        }
+        out.push_str("\x1bP");
+        out.push_str(&rest[..end]);
+        out.push_str("\x1b\\");
+        rest = &rest[end..];
+    }
+    out
+}
+
+fn in_tmux() -> bool {
+    std::env::var_os("TMUX").is_some_and(|value| !value.is_empty())
+}
+
+fn in_screen() -> bool {
+    !in_tmux() && std::env::var("TERM").is_ok_and(|term| term.starts_with("screen"))
+}
+
+/// Removes C0 and C1 control characters, which could otherwise terminate the sequence.
+fn sanitize(text: &str) -> String {
+    text.chars().filter(|&c| !c.is_control()).collect()
+}
+
+fn osc(body: String) -> String {
+    if cfg!(feature = "no-color-build") || !control::colors_enabled() {
+        return String::new();
+    }
+    let sequence = format!("\x1b]{}{}", body, BEL);
+    match passthrough() {
+        Passthrough::Never => sequence,
+        Passthrough::Tmux => wrap_tmux(&sequence),
+        Passthrough::Screen => wrap_screen(&sequence),
+        Passthrough::Auto if in_tmux() => wrap_tmux(&sequence),
+        Passthrough::Auto if in_screen() => wrap_screen(&sequence),
+        Passthrough::Auto => sequence,
+    }
+}
+
+/// Sets the terminal window (and tab) title.
+///
+/// # Examples
+///
+/// ```rust
+/// # if cfg!(feature = "no-color-build") { return; }
+/// use bytescolor::osc;
+///
+/// assert_eq!(osc::set_title("logs\x1b[31m"), "\x1b]0;logs[31m\x07");
+/// ```
+pub fn set_title(title: &str) -> String {
+    osc(format!("0;{}", sanitize(title)))
+}
+
+/// Places `data` on the system clipboard using OSC 52.
+///
+/// Support varies: some terminals disable clipboard writes by default, and tmux needs
+/// `set-clipboard on`.
+///
+/// # Examples
+///
+/// ```rust
+/// # if cfg!(feature = "no-color-build") { return; }
+/// use bytescolor::osc;
+///
+/// assert_eq!(osc::set_clipboard("hi"), "\x1b]52;c;aGk=\x07");
+/// ```
+pub fn set_clipboard(data: impl AsRef<[u8]>) -> String {
+    osc(format!("52;c;{}", base64::encode(data.as_ref())))
+}
+
+/// The escape sequence family used for desktop notifications.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub enum NotificationProtocol {
+    /// `OSC 9` (iTerm2, WezTerm, ConEmu, Ghostty). Carries a message only, the title is prepended.
+    Osc9,
+    /// `OSC 777;notify` (rxvt-unicode, foot, VTE-based terminals).
+    Osc777,
+    /// `OSC 99` (kitty).
+    Osc99,
+}
+
+impl NotificationProtocol {
+    /// Guesses the protocol understood by the current terminal from the environment
+    /// (`TERM_PROGRAM`, `TERM`, and terminal-specific variables).
+    ///
+    /// Returns `None` if the terminal is not known to support notifications.
+    pub fn detect() -> Option<Self> {
+        let var = |name: &str| std::env::var(name).unwrap_or_default();
+        let term = var("TERM");
+        let program = var("TERM_PROGRAM");
+        if term == "xterm-kitty" || std::env::var_os("KITTY_WINDOW_ID").is_some() {
+            Some(NotificationProtocol::Osc99)
+        } else if matches!(program.as_str(), "iTerm.app" | "WezTerm" | "ghostty")
+            || std::env::var_os("ConEmuPID").is_some()
+        {
+            Some(NotificationProtocol::Osc9)
+        } else if term.contains("rxvt")
+            || term.starts_with("foot")
+            || std::env::var_os("VTE_VERSION").is_some()
+        {
+            Some(NotificationProtocol::Osc777)
+        } else {
+            None
+        }
+    }
+}
+
+/// Raises a desktop notification with the protocol detected by [`NotificationProtocol::detect`].
+///
+/// Returns an empty string when the terminal is not known to support notifications.
+pub fn notify(title: &str, body: &str) -> String {
+    match NotificationProtocol::detect() {
+        Some(protocol) => notify_with(protocol, title, body),
+        None => String::new(),
+    }
+}
+
+/// Raises a desktop notification with an explicit `protocol`.
+///
+/// # Examples
+///
+/// ```rust
+/// # if cfg!(feature = "no-color-build") { return; }
+/// use bytescolor::osc::{self, NotificationProtocol};
+///
+/// assert_eq!(
+///     osc::notify_with(NotificationProtocol::Osc777, "ci", "passed"),
+///     "\x1b]777;notify;ci;passed\x07"
+/// );
+/// ```
+pub fn notify_with(protocol: NotificationProtocol, title: &str, body: &str) -> String {
+    let (title, body) = (sanitize(title), sanitize(body));
+    match protocol {
+        NotificationProtocol::Osc9 => osc(format!("9;{}: {}", title, body)),
+        // `;` separates fields in OSC 777, it cannot appear in the title.
+        NotificationProtocol::Osc777 => {
+            osc(format!("777;notify;{};{}", title.replace(';', ","), body))
+        }
+        NotificationProtocol::Osc99 => {
+            let mut out = osc(format!("99;i=1:d=0;{}", title));
+            out.push_str(&osc(format!("99;i=1:d=1:p=body;{}", body)));
+            out
+        }
+    }
+}
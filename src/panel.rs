@@ -0,0 +1,265 @@
+//! # Panels
+//!
+//! Draws Unicode box-drawing borders around text. The content may span several lines and may already be
+//! styled: widths are measured on the visible content with [`ansi::visible_width`](crate::ansi::visible_width),
+//! so colored text lines up with the border just like plain text.
+//!
+//! ```rust
+//! use bytescolor::panel::{Border, Panel};
+//! use bytescolor::{ByteColor, Color, Style};
+//!
+//! let body = format!("{}\nall systems nominal", "ready".green());
+//! let panel = Panel::new(body)
+//!     .title("status")
+//!     .border(Border::Rounded)
+//!     .border_style(Style::new().fg(Color::Cyan));
+//! println!("{}", panel);
+//! ```
+//!
+//! This module is available with the `widgets` feature, enabled by default.
+
+use std::fmt;
+
+use crate::ansi::{char_width, sequence_len, visible_width};
+use crate::Style;
+
+/// The character set used to draw a border.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash, Default)]
+pub enum Border {
+    /// `┌─┐` thin lines with square corners.
+    #[default]
+    Single,
+    /// `╭─╮` thin lines with rounded corners.
+    Rounded,
+    /// `╔═╗` double lines.
+    Double,
+    /// `┏━┓` thick lines.
+    Heavy,
+    /// `+-+` plain ASCII, for terminals without box-drawing glyphs.
+    Ascii,
+}
+
+impl Border {
+    /// Returns the `[top-left, top-right, bottom-left, bottom-right, horizontal, vertical]` glyphs.
+    fn glyphs(self) -> [char; 6] {
+        match self {
+            Border::Single => ['┌', '┐', '└', '┘', '─', '│'],
+            Border::Rounded => ['╭', '╮', '╰', '╯', '─', '│'],
+            Border::Double => ['╔', '╗', '╚', '╝', '═', '║'],
+            Border::Heavy => ['┏', '┓', '┗', '┛', '━', '┃'],
+            Border::Ascii => ['+', '+', '+', '+', '-', '|'],
+        }
+    }
+}
+
+/// A bordered block of text with an optional title.
+///
+/// # Examples
+///
+/// ```rust
+/// use bytescolor::panel::Panel;
+///
+/// let panel = Panel::new("hello\nworld!").title("hi").render();
+/// assert_eq!(panel, "┌─ hi ───┐\n│ hello  │\n│ world! │\n└────────┘");
+///
+/// let panel = Panel::new("a\tb\nabc\tb").tab_width(4).render();
+/// assert_eq!(panel, "┌───────┐\n│ a   b │\n│ abc b │\n└───────┘");
+/// ```
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct Panel {
+    content: String,
+    title: Option<String>,
+    border: Border,
+    border_style: Style,
+    padding: usize,
+    vertical_padding: usize,
+    tab_width: usize,
+}
+
+impl Panel {
+    /// Creates a panel around `content` with a single-line border and one space of horizontal padding.
+    pub fn new(content: impl Into<String>) -> Self {
+        Panel {
+            content: content.into(),
+            title: None,
+            border: Border::Single,
+            border_style: Style::new(),
+            padding: 1,
+            vertical_padding: 0,
+            tab_width: 4,
+        }
+    }
+
+    /// Sets a title embedded in the top border.
+    pub fn title(mut self, title: impl Into<String>) -> Self {
+        self.title = Some(title.into());
+        self
+    }
+
+    /// Sets the border glyphs.
+    pub fn border(mut self, border: Border) -> Self {
+        self.border = border;
+        self
+    }
+
+    /// Sets the style of the border (not of the content or the title).
+    pub fn border_style(mut self, style: Style) -> Self {
+        self.border_style = style;
+        self
+    }
+
+    /// Sets the number of spaces between the vertical borders and the content.
+    pub fn padding(mut self, padding: usize) -> Self {
+        self.padding = padding;
+        self
+    }
+
+    /// Sets the number of empty lines between the horizontal borders and the content.
+    pub fn vertical_padding(mut self, lines: usize) -> Self {
+        self.vertical_padding = lines;
+        self
+    }
+
+    /// Sets the distance between tab stops: tabs in the content are expanded to spaces up to the next
+    /// multiple of `tab_width` columns, so they are measured like any other text (4 by default).
+    pub fn tab_width(mut self, tab_width: usize) -> Self {
+        self.tab_width = tab_width;
+        self
+    }
+
+    /// Renders the panel. Carriage returns in the content are dropped, so `\r\n` line endings do not
+    /// break the right border.
+    pub fn render(&self) -> String {
+        let [top_left, top_right, bottom_left, bottom_right, horizontal, vertical] =
+            self.border.glyphs();
+        let lines: Vec<String> = self
+            .content
+            .split('\n')
+            .map(|line| expand_tabs(line, self.tab_width))
+            .collect();
+        let content_width = lines
+            .iter()
+            .map(|line| visible_width(line))
+            .max()
+            .unwrap_or(0);
+        let title_width = self
+            .title
+            .as_ref()
+            .map_or(0, |title| visible_width(title) + 3);
+        let inner = (content_width + 2 * self.padding).max(title_width + 1);
+        let border = |text: String| self.border_style.wrap(text);
+        let fill = |n: usize| horizontal.to_string().repeat(n);
+
+        let mut out = String::new();
+        match &self.title {
+            Some(title) => {
+                out.push_str(&border(format!("{}{} ", top_left, horizontal)));
+                out.push_str(title);
+                out.push_str(&border(format!(
+                    " {}{}",
+                    fill(inner - title_width),
+                    top_right
+                )));
+            }
+            None => out.push_str(&border(format!("{}{}{}", top_left, fill(inner), top_right))),
+        }
+        out.push('\n');
+
+        let side = border(vertical.to_string());
+        let empty = std::iter::repeat("").take(self.vertical_padding);
+        let body = empty
+            .clone()
+            .chain(lines.iter().map(String::as_str))
+            .chain(empty);
+        for line in body {
+            out.push_str(&side);
+            out.push_str(&" ".repeat(self.padding));
+            out.push_str(line);
+            if line.contains('\x1b') {
+                // Keep a style left open by the content from leaking into the border.
+                out.push_str("\x1b[0m");
+            }
+            out.push_str(&" ".repeat(inner - self.padding - visible_width(line)));
+            out.push_str(&side);
+            out.push('\n');
+        }
+        out.push_str(&border(format!(
+            "{}{}{}",
+            bottom_left,
+            fill(inner),
+            bottom_right
+        )));
+        out
+    }
+}
+
+impl fmt::Display for Panel {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.write_str(&self.render())
+    }
+}
+
+/// Expands the tabs of `line` to the next multiple of `tab_width` columns, escape sequences taking no
+/// column, and drops carriage returns, which would move the cursor back over the border.
+fn expand_tabs(line: &str, tab_width: usize) -> String {
+    if !line.contains(['\t', '\r']) {
+        return line.to_string();
+    }
+    let mut out = String::with_capacity(line.len());
+    let mut column = 0;
+    let mut pos = 0;
+    while let Some(c) = line[pos..].chars().next() {
+        match c {
+            '\x1b' => {
+                let mut end = pos + sequence_len(&line.as_bytes()[pos..]);
+                while !line.is_char_boundary(end) {
+                    end += 1;
+                }
+                out.push_str(&line[pos..end]);
+                pos = end;
+                continue;
+            }
+            '\t' if tab_width > 0 => {
+                let spaces = tab_width - column % tab_width;
+                out.push_str(&" ".repeat(spaces));
+                column += spaces;
+            }
+            '\t' | '\r' => {}
+            c => {
+                out.push(c);
+                column += char_width(c);
+            }
+        }
+        pos += c.len_utf8();
+    }
+    out
+}
+
+/// Extension trait drawing a border around text.
+pub trait Boxed {
+    /// Wraps the text in a single-line border drawn with `style`.
+    ///
+    /// Use [`Panel`] for titles, other border glyphs or custom padding.
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// use bytescolor::panel::Boxed;
+    /// use bytescolor::{Color, Style};
+    ///
+    /// println!("{}", "Deployed!".boxed(Style::new().fg(Color::Green))); // A green box around "Deployed!"
+    /// ```
+    fn boxed(&self, style: Style) -> String;
+}
+
+impl Boxed for str {
+    fn boxed(&self, style: Style) -> String {
+        Panel::new(self).border_style(style).render()
+    }
+}
+
+impl Boxed for [u8] {
+    fn boxed(&self, style: Style) -> String {
+        String::from_utf8_lossy(self).boxed(style)
+    }
+}
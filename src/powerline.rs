@@ -0,0 +1,116 @@
+//! # Powerline Segments
+//!
+//! [`Powerline`] joins colored segments with the arrow glyphs of powerline fonts, each separator drawn
+//! in the background of the segment before it over the background of the segment after it, so adjacent
+//! segments blend into one another, for status lines and prompt generators.
+//!
+//! ```rust
+//! use bytescolor::powerline::Powerline;
+//! use bytescolor::Color;
+//!
+//! let status = Powerline::new()
+//!     .segment("main", Color::Black, Color::Green)
+//!     .segment("~/src/app", Color::White, Color::Blue)
+//!     .segment("3 jobs", Color::Black, Color::Yellow);
+//! println!("{}", status);
+//! ```
+//!
+//! The default separator, U+E0B0, is only drawn as an arrow by fonts patched for powerline; a plain
+//! glyph such as `▶` can be used instead with [`Powerline::separator`].
+//!
+//! This module is available with the `widgets` feature, enabled by default.
+
+use std::fmt;
+
+use crate::{Color, Style, StyledText};
+
+/// Colored segments joined by separators.
+///
+/// # Examples
+///
+/// ```rust
+/// # if cfg!(feature = "no-color-build") { return; }
+/// use bytescolor::powerline::Powerline;
+/// use bytescolor::{ansi, Color};
+///
+/// let line = Powerline::new()
+///     .separator('▶')
+///     .segment("ok", Color::Black, Color::Green)
+///     .segment("8s", Color::White, Color::Blue);
+/// assert_eq!(ansi::strip(&line.render()), " ok ▶ 8s ▶");
+/// assert_eq!(
+///     line.render(),
+///     "\x1b[30;42m ok \x1b[32;44m▶\x1b[37m 8s \x1b[0;34m▶\x1b[0m"
+/// );
+/// ```
+#[derive(Debug, Clone, PartialEq, Eq, Hash)]
+pub struct Powerline {
+    segments: Vec<(String, Style, Color)>,
+    separator: char,
+}
+
+impl Default for Powerline {
+    fn default() -> Self {
+        Powerline {
+            segments: Vec::new(),
+            separator: '\u{e0b0}',
+        }
+    }
+}
+
+impl Powerline {
+    /// Creates a line without segments, separated by the powerline arrow U+E0B0.
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// Appends a segment showing `text`, padded with a space on each side, in `fg` on `bg`.
+    pub fn segment(self, text: impl fmt::Display, fg: Color, bg: Color) -> Self {
+        self.segment_styled(text, Style::new().fg(fg), bg)
+    }
+
+    /// Appends a segment showing `text`, padded with a space on each side, in `style` on `bg`, for
+    /// bold or italic segments. The background of `style` is replaced with `bg`.
+    pub fn segment_styled(mut self, text: impl fmt::Display, style: Style, bg: Color) -> Self {
+        self.segments.push((text.to_string(), style.bg(bg), bg));
+        self
+    }
+
+    /// Sets the glyph drawn between segments and after the last one.
+    pub fn separator(mut self, separator: char) -> Self {
+        self.separator = separator;
+        self
+    }
+
+    /// Returns `true` if the line has no segments.
+    pub fn is_empty(&self) -> bool {
+        self.segments.is_empty()
+    }
+
+    /// Returns the line as a styled document.
+    pub fn to_styled_text(&self) -> StyledText {
+        let mut out = StyledText::new();
+        for (index, (text, style, bg)) in self.segments.iter().enumerate() {
+            out.push(format!(" {} ", text), *style);
+            let separator = Style::new().fg(*bg);
+            let separator = match self.segments.get(index + 1) {
+                Some(&(_, _, next)) => separator.bg(next),
+                None => separator,
+            };
+            out.push(self.separator.to_string(), separator);
+        }
+        out
+    }
+
+    /// Renders the line, changing only the colors that differ between consecutive segments and
+    /// separators.
+    pub fn render(&self) -> String {
+        self.to_styled_text().render()
+    }
+}
+
+impl fmt::Display for Powerline {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.write_str(&self.render())
+    }
+}
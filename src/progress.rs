@@ -0,0 +1,209 @@
+//! # Progress Bars
+//!
+//! A minimal [`ProgressBar`] rendering a colored bar, a percentage and an estimated time of arrival,
+//! redrawn in place with a carriage return.
+//!
+//! ```rust,no_run
+//! use bytescolor::progress::ProgressBar;
+//!
+//! let mut bar = ProgressBar::new(200).label("Downloading").gradient(true);
+//! let mut stderr = std::io::stderr();
+//! for _ in 0..200 {
+//!     bar.inc(1);
+//!     bar.draw(&mut stderr).unwrap();
+//! }
+//! bar.finish(&mut stderr).unwrap();
+//! ```
+//!
+//! This module is available with the `widgets` feature, enabled by default.
+
+use std::io::{self, Write};
+use std::time::{Duration, Instant};
+
+use crate::gradient::Gradient;
+use crate::{term, Color, Style};
+
+/// A progress bar tracking a position out of a known total.
+///
+/// # Examples
+///
+/// ```rust
+/// use bytescolor::ansi;
+/// use bytescolor::progress::ProgressBar;
+///
+/// let mut bar = ProgressBar::new(4).width(8).show_eta(false);
+/// bar.set(2);
+/// assert_eq!(ansi::strip(&bar.render()), "████░░░░  50%");
+/// ```
+#[derive(Debug, Clone)]
+pub struct ProgressBar {
+    total: u64,
+    position: u64,
+    width: usize,
+    label: Option<String>,
+    filled_style: Style,
+    empty_style: Style,
+    gradient: Option<Gradient>,
+    show_eta: bool,
+    started: Instant,
+}
+
+impl ProgressBar {
+    /// Creates a bar for `total` units of work, 30 cells wide, green on a dimmed track.
+    pub fn new(total: u64) -> Self {
+        ProgressBar {
+            total,
+            position: 0,
+            width: 30,
+            label: None,
+            filled_style: Style::new().fg(Color::Green),
+            empty_style: Style::new().dim(),
+            gradient: None,
+            show_eta: true,
+            started: Instant::now(),
+        }
+    }
+
+    /// Sets the width of the bar in cells.
+    pub fn width(mut self, width: usize) -> Self {
+        self.width = width;
+        self
+    }
+
+    /// Sets a label printed before the bar.
+    pub fn label(mut self, label: impl Into<String>) -> Self {
+        self.label = Some(label.into());
+        self
+    }
+
+    /// Sets the style of the filled part of the bar.
+    pub fn style(mut self, style: Style) -> Self {
+        self.filled_style = style;
+        self
+    }
+
+    /// Sets the style of the empty part of the bar.
+    pub fn empty_style(mut self, style: Style) -> Self {
+        self.empty_style = style;
+        self
+    }
+
+    /// Colors the filled cells along a red → yellow → green gradient instead of a single style.
+    pub fn gradient(mut self, enabled: bool) -> Self {
+        self.gradient = enabled.then(Gradient::red_to_green);
+        self
+    }
+
+    /// Colors the filled cells along a custom gradient.
+    pub fn gradient_with(mut self, gradient: Gradient) -> Self {
+        self.gradient = Some(gradient);
+        self
+    }
+
+    /// Shows or hides the estimated time remaining (shown by default).
+    pub fn show_eta(mut self, show: bool) -> Self {
+        self.show_eta = show;
+        self
+    }
+
+    /// Sets the current position, clamped to the total.
+    pub fn set(&mut self, position: u64) {
+        self.position = position.min(self.total);
+    }
+
+    /// Advances the current position by `delta`, clamped to the total.
+    pub fn inc(&mut self, delta: u64) {
+        self.set(self.position.saturating_add(delta));
+    }
+
+    /// Returns the current position.
+    pub fn position(&self) -> u64 {
+        self.position
+    }
+
+    /// Returns the total amount of work.
+    pub fn total(&self) -> u64 {
+        self.total
+    }
+
+    /// Returns the completed fraction in `0.0..=1.0`. An empty total counts as complete.
+    pub fn fraction(&self) -> f64 {
+        if self.total == 0 {
+            1.0
+        } else {
+            self.position as f64 / self.total as f64
+        }
+    }
+
+    /// Estimates the remaining time from the average rate since the bar was created.
+    ///
+    /// Returns `None` until some progress has been made.
+    pub fn eta(&self) -> Option<Duration> {
+        if self.position == 0 {
+            return None;
+        }
+        let elapsed = self.started.elapsed().as_secs_f64();
+        let remaining = (self.total - self.position) as f64;
+        Some(Duration::from_secs_f64(
+            elapsed * remaining / self.position as f64,
+        ))
+    }
+
+    /// Renders the bar as a single line, without any cursor movement.
+    pub fn render(&self) -> String {
+        let fraction = self.fraction();
+        let filled = ((fraction * self.width as f64).round() as usize).min(self.width);
+        let mut out = String::new();
+        if let Some(label) = &self.label {
+            out.push_str(label);
+            out.push(' ');
+        }
+        match &self.gradient {
+            Some(gradient) => {
+                for cell in 0..filled {
+                    let t = if self.width > 1 {
+                        cell as f64 / (self.width - 1) as f64
+                    } else {
+                        1.0
+                    };
+                    out.push_str(&Style::new().fg(gradient.at(t)).wrap('█'));
+                }
+            }
+            None => out.push_str(&self.filled_style.wrap("█".repeat(filled))),
+        }
+        out.push_str(&self.empty_style.wrap("░".repeat(self.width - filled)));
+        out.push_str(&format!(" {:>3}%", (fraction * 100.0).floor() as u32));
+        if self.show_eta {
+            let eta = match self.eta() {
+                Some(eta) => format_eta(eta),
+                None => "--:--".to_string(),
+            };
+            out.push_str(&Style::new().dim().wrap(format!(" ETA {}", eta)));
+        }
+        out
+    }
+
+    /// Redraws the bar in place: returns the cursor to the start of the line, writes the bar and
+    /// clears whatever was left from a previous, longer line.
+    pub fn draw<W: Write>(&self, writer: &mut W) -> io::Result<()> {
+        write!(writer, "\r{}{}", self.render(), term::CLEAR_LINE_END)?;
+        writer.flush()
+    }
+
+    /// Marks the work as complete, draws the final state and moves to the next line.
+    pub fn finish<W: Write>(&mut self, writer: &mut W) -> io::Result<()> {
+        self.position = self.total;
+        self.draw(writer)?;
+        writer.write_all(b"\n")?;
+        writer.flush()
+    }
+}
+
+fn format_eta(eta: Duration) -> String {
+    let secs = eta.as_secs();
+    if secs >= 3600 {
+        format!("{}:{:02}:{:02}", secs / 3600, secs / 60 % 60, secs % 60)
+    } else {
+        format!("{:02}:{:02}", secs / 60, secs % 60)
+    }
+}
@@ -0,0 +1,145 @@
+//! # Prompts
+//!
+//! A tiny inquirer-like layer for interactive input: [`Prompt`] prints a styled question, reads the
+//! answer from a line of input and, once accepted, redraws the question line with a `✔` and the answer
+//! highlighted. Rejected answers are reported beneath it with a red `✖`.
+//!
+//! ```rust,no_run
+//! use bytescolor::prompt::Prompt;
+//!
+//! let name = Prompt::new("Project name?").default("demo").ask()?;
+//! # Ok::<(), std::io::Error>(())
+//! ```
+//!
+//! The redraw uses cursor movement, so it only happens while colors are enabled (see
+//! [`control`](crate::control)); otherwise each step is printed on its own line.
+//!
+//! This module is available with the `widgets` feature, enabled by default.
+
+use std::io::{self, BufRead, Write};
+
+use crate::{control, term, Color, Style};
+
+const QUESTION: Style = Style::new().fg(Color::Cyan).bold();
+const DONE: Style = Style::new().fg(Color::Green).bold();
+const FAILED: Style = Style::new().fg(Color::Red).bold();
+const ANSWER: Style = Style::new().fg(Color::Cyan);
+const HINT: Style = Style::new().dim();
+
+/// A check run on answers, returning the message shown when one is rejected.
+pub type Validator = fn(&str) -> Result<(), String>;
+
+/// A question asked on the terminal.
+///
+/// # Examples
+///
+/// ```rust
+/// # if cfg!(feature = "no-color-build") { return; }
+/// use bytescolor::prompt::Prompt;
+///
+/// let prompt = Prompt::new("Port?").default("8080").validate(|answer| {
+///     answer.parse::<u16>().map(|_| ()).map_err(|_| "not a port number".to_string())
+/// });
+/// let mut output = Vec::new();
+/// let answer = prompt.ask_with(&b"http\n\n"[..], &mut output).unwrap();
+/// assert_eq!(answer, "8080");
+///
+/// let output = String::from_utf8(output).unwrap();
+/// assert!(output.contains("not a port number"));
+/// assert!(output.ends_with("\x1b[1;32m✔\x1b[0m \x1b[1mPort?\x1b[0m \x1b[2m·\x1b[0m \x1b[36m8080\x1b[0m\n"));
+/// ```
+#[derive(Debug, Clone)]
+pub struct Prompt {
+    question: String,
+    default: Option<String>,
+    validate: Option<Validator>,
+}
+
+impl Prompt {
+    /// Creates a prompt asking `question`.
+    pub fn new(question: impl Into<String>) -> Self {
+        Prompt {
+            question: question.into(),
+            default: None,
+            validate: None,
+        }
+    }
+
+    /// Sets the answer used when the input is an empty line, shown dimmed after the question.
+    pub fn default(mut self, default: impl Into<String>) -> Self {
+        self.default = Some(default.into());
+        self
+    }
+
+    /// Sets a check run on every answer: an `Err` message is shown and the question asked again.
+    pub fn validate(mut self, validate: Validator) -> Self {
+        self.validate = Some(validate);
+        self
+    }
+
+    /// Asks the question on stderr and reads the answer from stdin.
+    ///
+    /// # Errors
+    ///
+    /// Returns any I/O error, and an [`io::ErrorKind::UnexpectedEof`] error if the input ends before
+    /// an answer is accepted.
+    pub fn ask(&self) -> io::Result<String> {
+        self.ask_with(io::stdin().lock(), io::stderr().lock())
+    }
+
+    /// Asks the question on `output` and reads the answer from `input`.
+    ///
+    /// # Errors
+    ///
+    /// Returns any I/O error, and an [`io::ErrorKind::UnexpectedEof`] error if the input ends before
+    /// an answer is accepted.
+    pub fn ask_with<R: BufRead, W: Write>(
+        &self,
+        mut input: R,
+        mut output: W,
+    ) -> io::Result<String> {
+        let question = Style::new().bold().wrap(&self.question);
+        let mut line = String::new();
+        loop {
+            write!(output, "{} {} ", QUESTION.wrap('?'), question)?;
+            if let Some(default) = &self.default {
+                write!(output, "{} ", HINT.wrap(format_args!("({})", default)))?;
+            }
+            output.flush()?;
+
+            line.clear();
+            if input.read_line(&mut line)? == 0 {
+                writeln!(output)?;
+                writeln!(output, "{} {}", FAILED.wrap('✖'), question)?;
+                return Err(io::Error::new(
+                    io::ErrorKind::UnexpectedEof,
+                    "input ended before an answer was given",
+                ));
+            }
+            let mut answer = line.trim_end_matches(['\n', '\r']).to_string();
+            if answer.is_empty() {
+                if let Some(default) = &self.default {
+                    answer = default.clone();
+                }
+            }
+            if let Some(Err(message)) = self.validate.map(|validate| validate(&answer)) {
+                writeln!(output, "{} {}", FAILED.wrap('✖'), FAILED.wrap(message))?;
+                continue;
+            }
+
+            if control::colors_enabled() {
+                write!(output, "{}\r{}", term::cursor_up(1), term::CLEAR_LINE)?;
+            }
+            writeln!(
+                output,
+                "{} {} {} {}",
+                DONE.wrap('✔'),
+                question,
+                HINT.wrap('·'),
+                ANSWER.wrap(&answer)
+            )?;
+            output.flush()?;
+            return Ok(answer);
+        }
+    }
+}
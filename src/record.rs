@@ -0,0 +1,183 @@
+//! # Recording
+//!
+//! [`Recorder`] is a writer that captures everything written through it, styles included, along with
+//! the time each write happened. A recording can be replayed with its original timing, or exported as an
+//! [asciinema](https://asciinema.org) cast, to generate demos straight from a program using this crate.
+//!
+//! ```rust
+//! use std::io::Write;
+//! use bytescolor::record::Recorder;
+//! use bytescolor::ByteColor;
+//!
+//! let mut recorder = Recorder::new(std::io::sink());
+//! writeln!(recorder, "{} compiling", "→".cyan())?;
+//! writeln!(recorder, "{} done", "✔".green())?;
+//! let cast = recorder.to_asciicast(80, 24);
+//! // std::fs::write("demo.cast", cast)?;
+//! # Ok::<(), std::io::Error>(())
+//! ```
+//!
+//! This module is available with the `basic` feature, enabled by default.
+
+use std::fmt::Write as _;
+use std::io::{self, Write};
+use std::thread;
+use std::time::{Duration, Instant};
+
+use crate::markup::escape_json;
+
+/// A chunk of captured output.
+#[derive(Debug, Clone, PartialEq, Eq, Hash)]
+pub struct Event {
+    time: Duration,
+    data: Vec<u8>,
+}
+
+impl Event {
+    /// Returns the time of the write, relative to the creation of the recorder.
+    pub fn time(&self) -> Duration {
+        self.time
+    }
+
+    /// Returns the bytes written, escape sequences included.
+    pub fn data(&self) -> &[u8] {
+        &self.data
+    }
+}
+
+/// A writer recording the output passed through it to an inner writer.
+///
+/// # Examples
+///
+/// ```rust
+/// use std::io::Write;
+/// use bytescolor::record::Recorder;
+///
+/// let mut recorder = Recorder::new(Vec::new());
+/// write!(recorder, "hello")?;
+/// assert_eq!(recorder.events()[0].data(), b"hello");
+/// assert_eq!(recorder.into_inner(), b"hello");
+/// # Ok::<(), std::io::Error>(())
+/// ```
+#[derive(Debug)]
+pub struct Recorder<W: Write> {
+    inner: W,
+    start: Instant,
+    events: Vec<Event>,
+}
+
+impl<W: Write> Recorder<W> {
+    /// Creates a recorder forwarding output to `inner`, starting the clock. Pass [`io::sink`] to only
+    /// record.
+    pub fn new(inner: W) -> Self {
+        Recorder {
+            inner,
+            start: Instant::now(),
+            events: Vec::new(),
+        }
+    }
+
+    /// Returns the recorded events, in order.
+    pub fn events(&self) -> &[Event] {
+        &self.events
+    }
+
+    /// Returns the wrapped writer.
+    pub fn get_mut(&mut self) -> &mut W {
+        &mut self.inner
+    }
+
+    /// Unwraps the recorder, returning the inner writer.
+    pub fn into_inner(self) -> W {
+        self.inner
+    }
+
+    /// Writes the recording to `out`, waiting between events as long as when it was recorded, divided
+    /// by `speed` (`2.0` plays twice as fast). A `speed` that is not a positive number, such as `0.0`
+    /// or NaN, writes the events without waiting, like an infinite one.
+    ///
+    /// # Errors
+    ///
+    /// Returns any error of `out`.
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// use std::io::Write;
+    /// use bytescolor::record::Recorder;
+    ///
+    /// let mut recorder = Recorder::new(std::io::sink());
+    /// write!(recorder, "one ")?;
+    /// write!(recorder, "two")?;
+    /// let mut out = Vec::new();
+    /// recorder.replay(&mut out, 0.0)?; // all at once
+    /// assert_eq!(out, b"one two");
+    /// # Ok::<(), std::io::Error>(())
+    /// ```
+    pub fn replay<O: Write>(&self, mut out: O, speed: f64) -> io::Result<()> {
+        let start = Instant::now();
+        for event in &self.events {
+            let due = if speed > 0.0 {
+                Duration::try_from_secs_f64(event.time.as_secs_f64() / speed)
+                    .unwrap_or(Duration::MAX)
+            } else {
+                Duration::ZERO
+            };
+            if let Some(wait) = due.checked_sub(start.elapsed()) {
+                thread::sleep(wait);
+            }
+            out.write_all(&event.data)?;
+            out.flush()?;
+        }
+        Ok(())
+    }
+
+    /// Returns the recording in asciinema cast v2 format, for a terminal of `width` × `height` cells.
+    ///
+    /// Output that is not valid UTF-8 is converted lossily.
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// use std::io::Write;
+    /// use bytescolor::record::Recorder;
+    ///
+    /// let mut recorder = Recorder::new(std::io::sink());
+    /// write!(recorder, "\x1b[32mok\x1b[0m\n")?;
+    /// let cast = recorder.to_asciicast(80, 24);
+    /// let mut lines = cast.lines();
+    /// assert_eq!(lines.next(), Some(r#"{"version": 2, "width": 80, "height": 24}"#));
+    /// assert!(lines.next().unwrap().ends_with(r#", "o", "\u001b[32mok\u001b[0m\n"]"#));
+    /// # Ok::<(), std::io::Error>(())
+    /// ```
+    pub fn to_asciicast(&self, width: u16, height: u16) -> String {
+        let mut out = format!(
+            "{{\"version\": 2, \"width\": {}, \"height\": {}}}\n",
+            width, height
+        );
+        for event in &self.events {
+            let _ = write!(out, "[{:.6}, \"o\", ", event.time.as_secs_f64());
+            escape_json(&mut out, &String::from_utf8_lossy(&event.data));
+            out.push_str("]\n");
+        }
+        out
+    }
+}
+
+impl<W: Write> Write for Recorder<W> {
+    fn write(&mut self, buf: &[u8]) -> io::Result<usize> {
+        let n = self.inner.write(buf)?;
+        if n > 0 {
+            let time = self.start.elapsed();
+            self.events.push(Event {
+                time,
+                data: buf[..n].to_vec(),
+            });
+        }
+        Ok(n)
+    }
+
+    fn flush(&mut self) -> io::Result<()> {
+        self.inner.flush()
+    }
+}
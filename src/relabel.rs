@@ -0,0 +1,271 @@
+//! # Relabeled Output
+//!
+//! [`Relabel`] forwards the lines of a stream, typically the output of a child process, each tagged with
+//! a colored label, the core of tools running several tasks at once and interleaving their output:
+//!
+//! ```text
+//! api │ listening on :8080
+//! web │ compiled in 1.2s
+//! api │ GET /health 200
+//! ```
+//!
+//! Labels get a color derived from their text (see [`label_style`]), so a task keeps its color from one
+//! run to the next without any configuration.
+//!
+//! ```rust,no_run
+//! use std::process::{Command, Stdio};
+//! use bytescolor::relabel::Relabel;
+//!
+//! let mut child = Command::new("cargo").arg("build").stderr(Stdio::piped()).spawn()?;
+//! let stderr = child.stderr.take().unwrap();
+//! Relabel::new("build").forward(stderr, std::io::stdout())?;
+//! child.wait()?;
+//! # Ok::<(), std::io::Error>(())
+//! ```
+//!
+//! Output produced in-process, rather than read from a stream, goes through a [`PrefixedWriter`], which
+//! tags each line written to it with a styled prefix and, optionally, a timestamp.
+//!
+//! This module is available with the `widgets` feature, enabled by default.
+
+use std::fmt;
+use std::io::{self, BufRead, BufReader, Read, Write};
+use std::time::SystemTime;
+
+use crate::{time, Color, Style};
+
+const SEPARATOR: Style = Style::new().dim();
+
+/// The colors labels are picked from, all readable on dark and light backgrounds.
+const PALETTE: [Color; 10] = [
+    Color::Cyan,
+    Color::Magenta,
+    Color::Yellow,
+    Color::Green,
+    Color::Blue,
+    Color::BrightCyan,
+    Color::BrightMagenta,
+    Color::BrightYellow,
+    Color::BrightGreen,
+    Color::BrightBlue,
+];
+
+/// The style of a label: a bold color picked by a hash of the label, the same in every run and on
+/// every platform.
+///
+/// # Examples
+///
+/// ```rust
+/// use bytescolor::relabel::label_style;
+///
+/// assert_eq!(label_style("api"), label_style("api"));
+/// ```
+pub fn label_style(label: &str) -> Style {
+    // FNV-1a, because the standard hasher is not guaranteed to be stable across releases.
+    let hash = label.bytes().fold(0xcbf2_9ce4_8422_2325_u64, |hash, byte| {
+        (hash ^ u64::from(byte)).wrapping_mul(0x0100_0000_01b3)
+    });
+    Style::new()
+        .fg(PALETTE[(hash % PALETTE.len() as u64) as usize])
+        .bold()
+}
+
+/// Tags lines with a colored label.
+///
+/// # Examples
+///
+/// ```rust
+/// use bytescolor::relabel::Relabel;
+/// use bytescolor::ansi;
+///
+/// let relabel = Relabel::new("db").width(5);
+/// let mut out = Vec::new();
+/// let lines = relabel.forward(&b"starting\r\nready\n"[..], &mut out).unwrap();
+/// assert_eq!(lines, 2);
+/// let out = String::from_utf8(out).unwrap();
+/// assert_eq!(ansi::strip(&out), "db    │ starting\ndb    │ ready\n");
+/// ```
+#[derive(Debug, Clone, PartialEq, Eq, Hash)]
+pub struct Relabel {
+    label: String,
+    style: Style,
+    width: usize,
+}
+
+impl Relabel {
+    /// Creates a relabeler tagging lines with `label`, in the style given by [`label_style`].
+    pub fn new(label: impl Into<String>) -> Self {
+        let label = label.into();
+        Relabel {
+            style: label_style(&label),
+            label,
+            width: 0,
+        }
+    }
+
+    /// Sets the style of the label.
+    pub fn style(mut self, style: Style) -> Self {
+        self.style = style;
+        self
+    }
+
+    /// Pads the label to `width` columns, so the output of tasks with labels of different lengths
+    /// lines up. Give every task the width of the longest label.
+    pub fn width(mut self, width: usize) -> Self {
+        self.width = width;
+        self
+    }
+
+    /// Returns `line` tagged with the label, without a final newline. A reset is appended to lines
+    /// containing escape sequences, so styles left open by the task do not leak into the next label.
+    pub fn relabel_line(&self, line: &str) -> String {
+        let padding = self
+            .width
+            .saturating_sub(crate::ansi::visible_width(&self.label));
+        let mut out = format!(
+            "{}{} {} {}",
+            self.style.wrap(&self.label),
+            " ".repeat(padding),
+            SEPARATOR.wrap('│'),
+            line
+        );
+        if line.contains('\x1b') {
+            out.push_str("\x1b[0m");
+        }
+        out
+    }
+
+    /// Forwards every line of `reader` to `writer`, tagged with the label, and returns the number of
+    /// lines. Line endings are normalized to `\n`; invalid UTF-8 is replaced.
+    ///
+    /// Each line is written with a single `write_all`, so relabelers running on several threads and
+    /// writing to [`Stdout`](std::io::Stdout) or [`Stderr`](std::io::Stderr) never interleave within
+    /// a line.
+    pub fn forward<R: Read, W: Write>(&self, reader: R, mut writer: W) -> io::Result<u64> {
+        let mut reader = BufReader::new(reader);
+        let mut buffer = Vec::new();
+        let mut lines = 0;
+        loop {
+            buffer.clear();
+            if reader.read_until(b'\n', &mut buffer)? == 0 {
+                break;
+            }
+            let line = String::from_utf8_lossy(&buffer);
+            let line = line.trim_end_matches('\n').trim_end_matches('\r');
+            let mut out = self.relabel_line(line);
+            out.push('\n');
+            writer.write_all(out.as_bytes())?;
+            writer.flush()?;
+            lines += 1;
+        }
+        Ok(lines)
+    }
+}
+
+/// A writer inserting a prefix at the start of every line written through it.
+///
+/// Lines may be written in any number of pieces and a single write may hold several lines: the prefix
+/// is written before the first byte of each line, so a line that is never started gets no prefix. A
+/// reset is written before the line break of lines containing escape sequences, so styles left open do
+/// not leak into the next prefix.
+///
+/// With [`timestamps`](PrefixedWriter::timestamps), each prefix is preceded by the time the line was
+/// started, as a [`time::timestamp`].
+///
+/// # Examples
+///
+/// ```rust
+/// use std::io::Write;
+/// use bytescolor::relabel::{label_style, PrefixedWriter};
+/// use bytescolor::ansi;
+///
+/// let mut out = PrefixedWriter::new(Vec::new(), label_style("web").paint("web | "));
+/// write!(out, "compiling")?;
+/// writeln!(out, " done")?;
+/// write!(out, "serving\nready\n")?;
+/// let out = String::from_utf8(out.into_inner()).unwrap();
+/// assert_eq!(ansi::strip(&out), "web | compiling done\nweb | serving\nweb | ready\n");
+/// # Ok::<(), std::io::Error>(())
+/// ```
+#[derive(Debug)]
+pub struct PrefixedWriter<W: Write> {
+    inner: W,
+    prefix: String,
+    timestamps: bool,
+    /// Whether the next byte written starts a line.
+    line_start: bool,
+    /// Whether the current line contains escape sequences.
+    escapes: bool,
+}
+
+impl<W: Write> PrefixedWriter<W> {
+    /// Creates a writer prefixing each line with `prefix`, written as is: it may be styled and
+    /// include its own separator.
+    pub fn new(inner: W, prefix: impl fmt::Display) -> Self {
+        PrefixedWriter {
+            inner,
+            prefix: prefix.to_string(),
+            timestamps: false,
+            line_start: true,
+            escapes: false,
+        }
+    }
+
+    /// Starts each prefix with the time its line was started, followed by a space.
+    pub fn timestamps(mut self, timestamps: bool) -> Self {
+        self.timestamps = timestamps;
+        self
+    }
+
+    /// Returns the wrapped writer.
+    pub fn get_ref(&self) -> &W {
+        &self.inner
+    }
+
+    /// Returns the wrapped writer. Writing to it directly inserts no prefix.
+    pub fn get_mut(&mut self) -> &mut W {
+        &mut self.inner
+    }
+
+    /// Returns the wrapped writer.
+    pub fn into_inner(self) -> W {
+        self.inner
+    }
+}
+
+impl<W: Write> Write for PrefixedWriter<W> {
+    /// Writes all of `buf` with the prefixes of the lines it starts, in a single `write_all`, so
+    /// writers sharing a [`Stdout`](std::io::Stdout) do not interleave within a write.
+    fn write(&mut self, buf: &[u8]) -> io::Result<usize> {
+        let mut out = Vec::with_capacity(buf.len() + self.prefix.len());
+        for piece in buf.split_inclusive(|&b| b == b'\n') {
+            if self.line_start {
+                if self.timestamps {
+                    write!(out, "{} ", time::timestamp(SystemTime::now()))?;
+                }
+                out.extend_from_slice(self.prefix.as_bytes());
+                self.line_start = false;
+                self.escapes = false;
+            }
+            let (body, newline) = match piece.strip_suffix(b"\n") {
+                Some(body) => (body, true),
+                None => (piece, false),
+            };
+            out.extend_from_slice(body);
+            self.escapes |= body.contains(&0x1b);
+            if newline {
+                if self.escapes {
+                    out.extend_from_slice(b"\x1b[0m");
+                }
+                out.push(b'\n');
+                self.line_start = true;
+            }
+        }
+        self.inner.write_all(&out)?;
+        Ok(buf.len())
+    }
+
+    fn flush(&mut self) -> io::Result<()> {
+        self.inner.flush()
+    }
+}
@@ -0,0 +1,204 @@
+//! # Rendering Targets
+//!
+//! The [`Render`] trait renders one styled value for different sinks: the terminal gets escape
+//! sequences, a log file plain text, a web page HTML and a log aggregator JSON, all from the same
+//! message instead of formatting it once per sink.
+//!
+//! ```rust
+//! use bytescolor::render::{Render, Target};
+//! use bytescolor::{Style, StyledText};
+//!
+//! let mut message = StyledText::styled("error", Style::RED | Style::BOLD);
+//! message.push(": disk full", Style::new());
+//!
+//! assert_eq!(message.render_as(Target::Plain), "error: disk full");
+//! assert_eq!(
+//!     message.render_as(Target::Html),
+//!     r#"<span style="color:#cd0000;font-weight:bold">error</span>: disk full"#
+//! );
+//! assert_eq!(
+//!     message.render_as(Target::Json),
+//!     r#"[{"text":"error","fg":"red","bold":true},{"text":": disk full"}]"#
+//! );
+//! ```
+//!
+//! [`Target::Ansi`] renders like [`StyledText::render`], honoring the [`control`](crate::control)
+//! settings; the other targets ignore them, as they do not write to a terminal.
+//!
+//! This module is available with the `html` feature, enabled by default.
+
+use std::fmt::Write;
+
+use crate::markup::{escape_html, escape_json, hex, style_entries, ColorValue, Entry};
+use crate::{Span, Style, StyledString, StyledText};
+
+/// A kind of output a styled value is rendered for.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub enum Target {
+    /// Text with ANSI escape sequences, for terminals.
+    Ansi,
+    /// The text without any styling, for files and pipes.
+    Plain,
+    /// HTML `<span>` elements with inline CSS, colors written as RGB values; unstyled text is only
+    /// escaped.
+    Html,
+    /// A JSON array of spans, each an object of its text and the style attributes that are set, in the
+    /// form produced by the `serde` feature (see the
+    /// [serialization section](crate::text#serialization) of the text module).
+    Json,
+}
+
+/// A value that can be rendered for any [`Target`].
+///
+/// Implementors only convert themselves to a [`StyledText`]; the rendering is provided.
+///
+/// # Examples
+///
+/// ```rust
+/// use bytescolor::render::{Render, Target};
+/// use bytescolor::{Style, StyledText};
+///
+/// struct Event {
+///     level: &'static str,
+///     message: String,
+/// }
+///
+/// impl Render for Event {
+///     fn to_styled_text(&self) -> StyledText {
+///         let mut text = StyledText::styled(self.level, Style::YELLOW);
+///         text.push(format!(" {}", self.message), Style::new());
+///         text
+///     }
+/// }
+///
+/// let event = Event { level: "warn", message: "retrying".to_string() };
+/// assert_eq!(event.render_as(Target::Plain), "warn retrying");
+/// ```
+pub trait Render {
+    /// Returns the value as a styled document.
+    fn to_styled_text(&self) -> StyledText;
+
+    /// Renders the value for `target`.
+    fn render_as(&self, target: Target) -> String {
+        let text = self.to_styled_text();
+        match target {
+            Target::Ansi => text.render(),
+            Target::Plain => text.visible(),
+            Target::Html => html(&text),
+            Target::Json => json(&text),
+        }
+    }
+}
+
+impl Render for StyledText {
+    fn to_styled_text(&self) -> StyledText {
+        self.clone()
+    }
+}
+
+impl Render for Span {
+    fn to_styled_text(&self) -> StyledText {
+        self.clone().into()
+    }
+}
+
+/// Parses the escape sequences of the rendered string, see [`StyledText::from_ansi`].
+impl Render for StyledString {
+    fn to_styled_text(&self) -> StyledText {
+        StyledText::from_ansi(self)
+    }
+}
+
+/// Parses the escape sequences of the text, so captured output of other programs can be rendered as
+/// well, see [`StyledText::from_ansi`].
+impl Render for str {
+    fn to_styled_text(&self) -> StyledText {
+        StyledText::from_ansi(self)
+    }
+}
+
+impl Render for String {
+    fn to_styled_text(&self) -> StyledText {
+        StyledText::from_ansi(self)
+    }
+}
+
+impl<T: Render + ?Sized> Render for &T {
+    fn to_styled_text(&self) -> StyledText {
+        (**self).to_styled_text()
+    }
+}
+
+fn html(text: &StyledText) -> String {
+    let mut out = String::new();
+    for span in text.spans() {
+        let css = css(span.style());
+        if css.is_empty() {
+            escape_html(&mut out, span.text());
+        } else {
+            let _ = write!(out, r#"<span style="{}">"#, css);
+            escape_html(&mut out, span.text());
+            out.push_str("</span>");
+        }
+    }
+    out
+}
+
+fn css(style: Style) -> String {
+    let (mut fg, mut bg) = (style.foreground(), style.background());
+    if style.is_reverse() {
+        std::mem::swap(&mut fg, &mut bg);
+    }
+    let mut rules = Vec::new();
+    if let Some(fg) = fg {
+        rules.push(format!("color:{}", hex(fg.to_rgb())));
+    }
+    if let Some(bg) = bg {
+        rules.push(format!("background-color:{}", hex(bg.to_rgb())));
+    }
+    if style.is_bold() {
+        rules.push("font-weight:bold".to_string());
+    }
+    if style.is_dim() {
+        rules.push("opacity:0.6".to_string());
+    }
+    if style.is_italic() {
+        rules.push("font-style:italic".to_string());
+    }
+    match (style.is_underline(), style.is_strikethrough()) {
+        (true, true) => rules.push("text-decoration:underline line-through".to_string()),
+        (true, false) => rules.push("text-decoration:underline".to_string()),
+        (false, true) => rules.push("text-decoration:line-through".to_string()),
+        (false, false) => {}
+    }
+    if style.is_hidden() {
+        rules.push("visibility:hidden".to_string());
+    }
+    rules.join(";")
+}
+
+fn json(text: &StyledText) -> String {
+    let mut out = String::from("[");
+    for (index, span) in text.spans().iter().enumerate() {
+        if index > 0 {
+            out.push(',');
+        }
+        out.push_str(r#"{"text":"#);
+        escape_json(&mut out, span.text());
+        for (key, entry) in style_entries(span.style()) {
+            let _ = write!(out, r#","{}":"#, key);
+            match entry {
+                Entry::Color(color) => match ColorValue::from(color) {
+                    ColorValue::Index(index) => {
+                        let _ = write!(out, "{}", index);
+                    }
+                    ColorValue::Text(text) => escape_json(&mut out, &text),
+                },
+                Entry::Set => out.push_str("true"),
+            }
+        }
+        out.push('}');
+    }
+    out.push(']');
+    out
+}
@@ -0,0 +1,171 @@
+//! # Rules and Section Headers
+//!
+//! Horizontal rules spanning the terminal, alone or with a title, for separating the phases of CLI
+//! output:
+//!
+//! ```text
+//! ────────────────────────────────────────
+//! ──────────────── Tests ─────────────────
+//! ── Tests ───────────────────────────────
+//! ```
+//!
+//! [`hr`] and [`section`] cover the common cases; [`Rule`] sets the width, the glyph and where the title
+//! goes.
+//!
+//! ```rust
+//! use bytescolor::rule::{hr, section};
+//! use bytescolor::{Color, Style};
+//!
+//! println!("{}", section("Build", Style::new().fg(Color::Cyan)));
+//! // ... build output ...
+//! println!("{}", hr(Style::new().dim()));
+//! ```
+//!
+//! This module is available with the `widgets` feature, enabled by default.
+
+use std::fmt;
+
+use crate::{ansi, Style};
+
+/// A horizontal rule, optionally with a title.
+///
+/// # Examples
+///
+/// ```rust
+/// use bytescolor::rule::Rule;
+/// use bytescolor::ansi;
+///
+/// let rule = Rule::new().title("Tests").width(21);
+/// assert_eq!(ansi::strip(&rule.render()), "─────── Tests ───────");
+/// let flanked = rule.centered(false).glyph('=');
+/// assert_eq!(ansi::strip(&flanked.render()), "== Tests ============");
+/// ```
+#[derive(Debug, Clone, PartialEq, Eq, Hash)]
+pub struct Rule {
+    title: Option<String>,
+    style: Style,
+    glyph: char,
+    width: Option<usize>,
+    centered: bool,
+}
+
+impl Default for Rule {
+    fn default() -> Self {
+        Rule {
+            title: None,
+            style: Style::new(),
+            glyph: '─',
+            width: None,
+            centered: true,
+        }
+    }
+}
+
+impl Rule {
+    /// Creates an unstyled `─` rule without a title, as wide as the terminal.
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// Sets the title, surrounded by a space on each side. A plain title is drawn in the style of the
+    /// rule made bold; a styled one keeps its own styling.
+    pub fn title(mut self, title: impl fmt::Display) -> Self {
+        self.title = Some(title.to_string());
+        self
+    }
+
+    /// Sets the style of the rule.
+    pub fn style(mut self, style: Style) -> Self {
+        self.style = style;
+        self
+    }
+
+    /// Sets the character the rule is drawn with.
+    pub fn glyph(mut self, glyph: char) -> Self {
+        self.glyph = glyph;
+        self
+    }
+
+    /// Sets the width of the rule instead of detecting it.
+    ///
+    /// By default the width of the terminal is used, as reported by
+    /// [`terminal_size`](crate::terminal_size), falling back to 80.
+    pub fn width(mut self, width: usize) -> Self {
+        self.width = Some(width);
+        self
+    }
+
+    /// Centers the title, or places it after two glyphs when `false`. Centered by default; an odd
+    /// glyph goes to the right.
+    pub fn centered(mut self, centered: bool) -> Self {
+        self.centered = centered;
+        self
+    }
+
+    /// Renders the rule, without a final newline. A title too wide for the rule is truncated, and left
+    /// out when not even its first character fits.
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// use bytescolor::{ansi, rule::Rule};
+    ///
+    /// assert_eq!(ansi::strip(&Rule::new().title("Hello").width(1).render()), "─");
+    /// ```
+    pub fn render(&self) -> String {
+        let width = self.width.unwrap_or_else(default_width);
+        let glyph_width = ansi::text_width(&self.glyph.to_string()).max(1);
+        let glyphs = |n: usize| match n {
+            0 => String::new(),
+            n => self.style.wrap(self.glyph.to_string().repeat(n)),
+        };
+        let title = match &self.title {
+            Some(title) => title,
+            None => return glyphs(width / glyph_width),
+        };
+        let truncated = ansi::truncate(title, width.saturating_sub(2));
+        if width < 2 || (truncated.is_empty() && !title.is_empty()) {
+            return glyphs(width / glyph_width);
+        }
+        let title = truncated;
+        let title = if title.contains('\x1b') {
+            title.into_owned()
+        } else {
+            (self.style | Style::BOLD).wrap(title)
+        };
+        let fill = width.saturating_sub(ansi::visible_width(&title) + 2) / glyph_width;
+        let left = if self.centered { fill / 2 } else { fill.min(2) };
+        format!("{} {} {}", glyphs(left), title, glyphs(fill - left))
+    }
+}
+
+impl fmt::Display for Rule {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.write_str(&self.render())
+    }
+}
+
+/// Renders a `─` rule as wide as the terminal in `style`.
+pub fn hr(style: Style) -> String {
+    Rule::new().style(style).render()
+}
+
+/// Renders `title` centered in a `─` rule as wide as the terminal, the rule in `style` and a plain
+/// title in `style` made bold.
+///
+/// # Examples
+///
+/// ```rust
+/// use bytescolor::rule::section;
+/// use bytescolor::{ansi, Style};
+///
+/// let header = section("Deploy", Style::CYAN);
+/// assert!(ansi::strip(&header).contains("── Deploy ──"));
+/// ```
+pub fn section(title: impl fmt::Display, style: Style) -> String {
+    Rule::new().title(title).style(style).render()
+}
+
+fn default_width() -> usize {
+    crate::terminal_size().map_or(80, |(columns, _)| usize::from(columns))
+}
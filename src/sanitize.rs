@@ -0,0 +1,187 @@
+//! # Untrusted Input
+//!
+//! Text from users, files or the network may contain control characters and escape sequences of its
+//! own. [`ShowControls`] makes them visible, rendering control characters in caret notation (`^C`,
+//! `^[`) and whitespace controls as dimmed symbols, so hidden whitespace and terminal-injection attempts
+//! stand out.
+//!
+//! ```rust
+//! use bytescolor::sanitize::ShowControls;
+//!
+//! let input = "name\t\x1b[2Jboom";
+//! println!("{}", input.show_controls()); // Displays "name\t^[[2Jboom" with "\t" dimmed and "^[" in red
+//! ```
+//!
+//! Before styling untrusted text, [`Sanitize`] strips or escapes whatever it embeds, so the crate's own
+//! escape sequences are the only ones reaching the terminal and log lines cannot be forged:
+//!
+//! ```rust
+//! use bytescolor::sanitize::Sanitize;
+//! use bytescolor::ByteColor;
+//!
+//! let user = "bob\x1b[0m\r[INFO] admin logged in";
+//! println!("unknown user {}", user.sanitize().red());
+//! ```
+
+use std::borrow::Cow;
+
+use crate::{ansi, Color, Style, StyledString};
+
+const SYMBOL: Style = Style::new().dim();
+const CARET: Style = Style::new().fg(Color::Red).bold();
+
+/// Extension trait making control characters visible.
+pub trait ShowControls {
+    /// Replaces control characters: tab, line feed and carriage return become dimmed `\t`, `\n`
+    /// (followed by the line break itself) and `\r`; other C0 controls and DEL become red caret
+    /// notation; C1 controls and invalid UTF-8 bytes become red `\u{..}` and `\x..` escapes.
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// use bytescolor::sanitize::ShowControls;
+    ///
+    /// assert_eq!("a\tb\x03".show_controls(), "a\\tb^C");
+    /// assert_eq!(b"\x1b[31m\xff".show_controls(), "^[[31m\\xff");
+    /// ```
+    fn show_controls(&self) -> StyledString;
+}
+
+impl ShowControls for str {
+    fn show_controls(&self) -> StyledString {
+        let mut out = String::with_capacity(self.len());
+        push_controls(&mut out, self);
+        StyledString::from(out)
+    }
+}
+
+impl ShowControls for [u8] {
+    fn show_controls(&self) -> StyledString {
+        let mut out = String::with_capacity(self.len());
+        let mut rest = self;
+        loop {
+            match std::str::from_utf8(rest) {
+                Ok(valid) => {
+                    push_controls(&mut out, valid);
+                    break;
+                }
+                Err(error) => {
+                    let (valid, invalid) = rest.split_at(error.valid_up_to());
+                    push_controls(
+                        &mut out,
+                        std::str::from_utf8(valid).expect("valid up to this point"),
+                    );
+                    let len = error.error_len().unwrap_or(invalid.len());
+                    for byte in &invalid[..len] {
+                        out.push_str(&CARET.wrap(format_args!("\\x{:02x}", byte)));
+                    }
+                    rest = &invalid[len..];
+                }
+            }
+        }
+        StyledString::from(out)
+    }
+}
+
+fn push_controls(out: &mut String, text: &str) {
+    for c in text.chars() {
+        match c {
+            '\t' => out.push_str(&SYMBOL.wrap("\\t")),
+            '\n' => {
+                out.push_str(&SYMBOL.wrap("\\n"));
+                out.push('\n');
+            }
+            '\r' => out.push_str(&SYMBOL.wrap("\\r")),
+            '\0'..='\x1f' => {
+                out.push_str(&CARET.wrap(format_args!("^{}", (c as u8 + 0x40) as char)))
+            }
+            '\x7f' => out.push_str(&CARET.wrap("^?")),
+            '\u{80}'..='\u{9f}' => out.push_str(&CARET.wrap(format_args!("\\u{{{:x}}}", c as u32))),
+            _ => out.push(c),
+        }
+    }
+}
+
+/// Extension trait neutralizing escape sequences and control characters in untrusted input.
+///
+/// Tabs and line feeds are kept; every other control character (carriage returns included, which can
+/// overwrite a log line) is removed or escaped. Byte slices are decoded as lossy UTF-8 first.
+pub trait Sanitize {
+    /// Removes escape sequences and control characters.
+    ///
+    /// Returns the input unchanged, without allocating, when there is nothing to remove.
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// use bytescolor::sanitize::Sanitize;
+    ///
+    /// assert_eq!("ok\x1b[2J\x1b]0;pwned\x07!\r".sanitize(), "ok!");
+    /// assert_eq!("a\tb\n".sanitize(), "a\tb\n");
+    /// ```
+    fn sanitize(&self) -> Cow<'_, str>;
+
+    /// Replaces control characters with their Rust-style escapes (`\x1b`, `\r`, `\u{9b}`), leaving
+    /// the text printable and inert.
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// use bytescolor::sanitize::Sanitize;
+    ///
+    /// assert_eq!("\x1b[31mred".escape_controls(), "\\x1b[31mred");
+    /// ```
+    fn escape_controls(&self) -> Cow<'_, str>;
+}
+
+impl Sanitize for str {
+    fn sanitize(&self) -> Cow<'_, str> {
+        if !self.chars().any(is_unsafe) {
+            return Cow::Borrowed(self);
+        }
+        Cow::Owned(
+            ansi::strip(self)
+                .chars()
+                .filter(|&c| !is_unsafe(c))
+                .collect(),
+        )
+    }
+
+    fn escape_controls(&self) -> Cow<'_, str> {
+        if !self.chars().any(is_unsafe) {
+            return Cow::Borrowed(self);
+        }
+        let mut out = String::with_capacity(self.len() + 8);
+        for c in self.chars() {
+            match c {
+                '\r' => out.push_str("\\r"),
+                c if is_unsafe(c) && (c as u32) < 0x80 => {
+                    out.push_str(&format!("\\x{:02x}", c as u32))
+                }
+                c if is_unsafe(c) => out.push_str(&format!("\\u{{{:x}}}", c as u32)),
+                c => out.push(c),
+            }
+        }
+        Cow::Owned(out)
+    }
+}
+
+impl Sanitize for [u8] {
+    fn sanitize(&self) -> Cow<'_, str> {
+        match String::from_utf8_lossy(self) {
+            Cow::Borrowed(text) => text.sanitize(),
+            Cow::Owned(text) => Cow::Owned(text.sanitize().into_owned()),
+        }
+    }
+
+    fn escape_controls(&self) -> Cow<'_, str> {
+        match String::from_utf8_lossy(self) {
+            Cow::Borrowed(text) => text.escape_controls(),
+            Cow::Owned(text) => Cow::Owned(text.escape_controls().into_owned()),
+        }
+    }
+}
+
+fn is_unsafe(c: char) -> bool {
+    c.is_control() && c != '\t' && c != '\n'
+}
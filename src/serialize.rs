@@ -0,0 +1,58 @@
+//! `serde` serialization of styles and styled text, see the
+//! [serialization section](crate::text#serialization) of the text module.
+
+use serde::ser::{Serialize, SerializeMap, SerializeSeq, Serializer};
+
+use crate::markup::{style_entries, ColorValue, Entry};
+use crate::{Color, Span, Style, StyledText};
+
+/// Serializes standard colors as their snake case name, palette colors as their index and RGB colors
+/// as a `#rrggbb` string.
+impl Serialize for Color {
+    fn serialize<S: Serializer>(&self, serializer: S) -> Result<S::Ok, S::Error> {
+        match ColorValue::from(*self) {
+            ColorValue::Index(index) => serializer.serialize_u8(index),
+            ColorValue::Text(text) => serializer.serialize_str(&text),
+        }
+    }
+}
+
+/// Serializes as a map holding the `fg` and `bg` colors that are set and a `true` entry per attribute.
+impl Serialize for Style {
+    fn serialize<S: Serializer>(&self, serializer: S) -> Result<S::Ok, S::Error> {
+        let mut map = serializer.serialize_map(None)?;
+        serialize_style(*self, &mut map)?;
+        map.end()
+    }
+}
+
+/// Serializes as the map of its style with a leading `text` entry.
+impl Serialize for Span {
+    fn serialize<S: Serializer>(&self, serializer: S) -> Result<S::Ok, S::Error> {
+        let mut map = serializer.serialize_map(None)?;
+        map.serialize_entry("text", self.text())?;
+        serialize_style(self.style(), &mut map)?;
+        map.end()
+    }
+}
+
+/// Serializes as a sequence of spans.
+impl Serialize for StyledText {
+    fn serialize<S: Serializer>(&self, serializer: S) -> Result<S::Ok, S::Error> {
+        let mut seq = serializer.serialize_seq(Some(self.spans().len()))?;
+        for span in self.spans() {
+            seq.serialize_element(span)?;
+        }
+        seq.end()
+    }
+}
+
+fn serialize_style<M: SerializeMap>(style: Style, map: &mut M) -> Result<(), M::Error> {
+    for (key, entry) in style_entries(style) {
+        match entry {
+            Entry::Color(color) => map.serialize_entry(key, &color)?,
+            Entry::Set => map.serialize_entry(key, &true)?,
+        }
+    }
+    Ok(())
+}
@@ -0,0 +1,114 @@
+//! # Shell Prompts
+//!
+//! Shells compute the width of their prompt to position the cursor and wrap long command lines. Escape
+//! sequences in a prompt string take no room on screen, so they must be marked as such, each shell with
+//! its own syntax; [`Shell::wrap`] emits a style in the syntax of the target shell, so prompt-generator
+//! tools can reuse the styles of their terminal output.
+//!
+//! ```rust
+//! # if cfg!(feature = "no-color-build") { return; }
+//! use bytescolor::shell::Shell;
+//! use bytescolor::{Color, Style};
+//!
+//! let path = Style::new().fg(Color::Blue).bold();
+//! assert_eq!(Shell::Bash.wrap(path, "~/src"), "\\[\x1b[1;34m\\]~/src\\[\x1b[0m\\]");
+//! assert_eq!(Shell::Zsh.wrap(path, "~/src"), "%{\x1b[1;34m%}~/src%{\x1b[0m%}");
+//! assert_eq!(Shell::Fish.wrap(path, "~/src"), "(set_color --bold blue)~/src(set_color normal)");
+//! ```
+//!
+//! The text is inserted verbatim, so it may itself contain prompt escapes such as `\w` or `%~`.
+//!
+//! This module is available with the `basic` feature, enabled by default.
+
+use crate::{markup, Color, Style};
+
+/// A shell whose prompt syntax styles are exported to.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub enum Shell {
+    /// Bash `PS1`: escape sequences enclosed in `\[` and `\]`.
+    Bash,
+    /// Zsh `PROMPT`: escape sequences enclosed in `%{` and `%}`.
+    Zsh,
+    /// The output of a fish `fish_prompt` function: `set_color` command substitutions, to be used in
+    /// an `echo` or `printf` argument.
+    Fish,
+}
+
+impl Shell {
+    /// Wraps `text` in `style`, in the prompt syntax of the shell.
+    ///
+    /// Like the [`ByteColor`](crate::ByteColor) methods, this returns `text` unchanged when escape
+    /// sequences are disabled (see [`control`](crate::control)) or `style` is plain.
+    pub fn wrap(self, style: Style, text: &str) -> String {
+        format!("{}{}{}", self.prefix(style), text, self.suffix(style))
+    }
+
+    /// Returns the marked sequence switching to `style`, or an empty string when it emits nothing.
+    pub fn prefix(self, style: Style) -> String {
+        let prefix = style.prefix();
+        if prefix.is_empty() {
+            return prefix;
+        }
+        match self {
+            Shell::Bash => format!("\\[{}\\]", prefix),
+            Shell::Zsh => format!("%{{{}%}}", prefix),
+            Shell::Fish => format!("(set_color {})", fish_arguments(style)),
+        }
+    }
+
+    /// Returns the marked sequence resetting `style`, or an empty string when it emits nothing.
+    pub fn suffix(self, style: Style) -> String {
+        let suffix = style.suffix();
+        if suffix.is_empty() {
+            return suffix;
+        }
+        match self {
+            Shell::Bash => format!("\\[{}\\]", suffix),
+            Shell::Zsh => format!("%{{{}%}}", suffix),
+            Shell::Fish => "(set_color normal)".to_string(),
+        }
+    }
+}
+
+/// Returns the `set_color` arguments selecting `style`. Blink, hidden and strikethrough have no
+/// `set_color` equivalent and are dropped.
+fn fish_arguments(style: Style) -> String {
+    let mut arguments = Vec::new();
+    for attribute in markup::attributes(style) {
+        match attribute {
+            "blink" | "hidden" | "strikethrough" => {}
+            "italic" => arguments.push("--italics".to_string()),
+            attribute => arguments.push(format!("--{}", attribute)),
+        }
+    }
+    if let Some(bg) = style.background() {
+        arguments.push(format!("--background={}", fish_color(bg)));
+    }
+    arguments.push(style.foreground().map_or("normal".to_string(), fish_color));
+    arguments.join(" ")
+}
+
+fn fish_color(color: Color) -> String {
+    let name = match color {
+        Color::Black => "black",
+        Color::Red => "red",
+        Color::Green => "green",
+        Color::Yellow => "yellow",
+        Color::Blue => "blue",
+        Color::Magenta => "magenta",
+        Color::Cyan => "cyan",
+        Color::White => "white",
+        Color::BrightBlack => "brblack",
+        Color::BrightRed => "brred",
+        Color::BrightGreen => "brgreen",
+        Color::BrightYellow => "bryellow",
+        Color::BrightBlue => "brblue",
+        Color::BrightMagenta => "brmagenta",
+        Color::BrightCyan => "brcyan",
+        Color::BrightWhite => "brwhite",
+        Color::Fixed(_) | Color::Rgb(..) => {
+            return markup::hex(color.to_rgb())[1..].to_string();
+        }
+    };
+    name.to_string()
+}
@@ -0,0 +1,424 @@
+//! # Source Snippets
+//!
+//! [`Snippet`] renders lines of source text the way compilers point at errors: line numbers in a
+//! dimmed gutter, the offending spans highlighted, caret underlines with labels below them, and
+//! notes and help after the source.
+//!
+//! ```text
+//!  --> config.toml:3:8
+//!   |
+//! 2 | host = "localhost"
+//!   |        ----------- host defined here
+//! 3 | port = "eighty"
+//!   |        ^^^^^^^^ expected a number
+//!   |
+//!   = help: ports are numbers between 1 and 65535
+//! ```
+//!
+//! It is meant for configuration validators, linters and TODO finders built on this crate; spans are
+//! given as byte ranges into the source, as returned by most parsers. The first span is the primary
+//! one, underlined with `^` and located by the ` --> ` line; the others are underlined with `-`, each
+//! in its own color.
+//!
+//! ```rust
+//! use bytescolor::snippet::Snippet;
+//!
+//! let source = "[server]\nhost = \"localhost\"\nport = \"eighty\"\n";
+//! let snippet = Snippet::new(source)
+//!     .origin("config.toml")
+//!     .span(35..43, "expected a number")
+//!     .span(16..27, "host defined here")
+//!     .help("ports are numbers between 1 and 65535");
+//! eprintln!("{}", snippet);
+//! ```
+//!
+//! This module is available with the `widgets` feature, enabled by default.
+
+use std::fmt;
+use std::ops::Range;
+
+use crate::ansi::text_width;
+use crate::{Color, Style};
+
+const GUTTER: Style = Style::new().dim();
+const NOTE: Style = Style::new().bold();
+const HELP: Style = Style::new().fg(Color::Cyan).bold();
+
+/// The styles of the secondary spans, in order.
+const SECONDARY: [Style; 4] = [
+    Style::new().fg(Color::Blue).bold(),
+    Style::new().fg(Color::Magenta).bold(),
+    Style::new().fg(Color::Cyan).bold(),
+    Style::new().fg(Color::Yellow).bold(),
+];
+
+/// The number of columns a tab is expanded to, so carets line up whatever the tab stops are.
+const TAB: &str = "    ";
+
+/// Lines of source text with highlighted, labeled spans, followed by notes.
+///
+/// # Examples
+///
+/// ```rust
+/// use bytescolor::snippet::Snippet;
+/// use bytescolor::ansi;
+///
+/// let source = "let x = 1;\nlet y = x +;\n";
+/// let snippet = Snippet::new(source).origin("main.rs").span(22..23, "expected an expression");
+/// assert_eq!(
+///     ansi::strip(&snippet.render()),
+///     " --> main.rs:2:12\n  |\n2 | let y = x +;\n  |            ^ expected an expression"
+/// );
+///
+/// let snippet = Snippet::new("total = add(price, tax)\n")
+///     .span(8..11, "expects 3 arguments")
+///     .span(12..17, "first")
+///     .span(19..22, "second")
+///     .note("add(a, b, c) is defined in math.rs");
+/// assert_eq!(
+///     ansi::strip(&snippet.render()),
+///     "  |\n\
+///      1 | total = add(price, tax)\n  \
+///      |         ^^^ -----  --- second\n  \
+///      |         |   first\n  \
+///      |         expects 3 arguments\n  \
+///      |\n  \
+///      = note: add(a, b, c) is defined in math.rs"
+/// );
+///
+/// // Spans starting at the same column get a label row each.
+/// let snippet = Snippet::new("send(port * 2, host)\n")
+///     .span(5..13, "cannot multiply")
+///     .span(5..9, "this is a string")
+///     .span(15..19, "expected a number");
+/// assert_eq!(
+///     ansi::strip(&snippet.render()),
+///     "  |\n\
+///      1 | send(port * 2, host)\n  \
+///      |      ----^^^^  ---- expected a number\n  \
+///      |      cannot multiply\n  \
+///      |      this is a string"
+/// );
+/// ```
+#[derive(Debug, Clone, PartialEq, Eq, Hash)]
+pub struct Snippet {
+    source: String,
+    origin: Option<String>,
+    first_line: usize,
+    spans: Vec<Annotation>,
+    notes: Vec<(&'static str, String)>,
+    style: Style,
+}
+
+/// A labeled span of the source.
+#[derive(Debug, Clone, PartialEq, Eq, Hash)]
+struct Annotation {
+    range: Range<usize>,
+    label: String,
+    style: Option<Style>,
+}
+
+/// A span as seen on one line: the columns it underlines and the label ending there, if any.
+struct Mark<'a> {
+    column: usize,
+    width: usize,
+    glyph: char,
+    style: Style,
+    label: Option<&'a str>,
+}
+
+impl Snippet {
+    /// Creates a snippet of `source`, numbered from line 1, the primary span in bold red.
+    pub fn new(source: impl Into<String>) -> Self {
+        Snippet {
+            source: source.into(),
+            origin: None,
+            first_line: 1,
+            spans: Vec::new(),
+            notes: Vec::new(),
+            style: Style::new().fg(Color::Red).bold(),
+        }
+    }
+
+    /// Sets the origin of the source, usually a path, shown with the line and column of the primary
+    /// span as ` --> path:line:column`.
+    pub fn origin(mut self, origin: impl Into<String>) -> Self {
+        self.origin = Some(origin.into());
+        self
+    }
+
+    /// Sets the number of the first line of the source, for snippets cut out of a larger file.
+    pub fn first_line(mut self, line: usize) -> Self {
+        self.first_line = line;
+        self
+    }
+
+    /// Highlights the bytes `range` of the source and labels them with `label`, which may be empty.
+    /// The first span is the primary one; the others get the next of a set of distinct colors.
+    ///
+    /// Only the lines the spans cover are shown, with `...` in the gutter where lines are skipped;
+    /// without spans, the whole source is. Ranges past the end of the source are clamped, and ranges
+    /// splitting a character are widened to include it. An empty range points between two
+    /// characters with a single caret.
+    pub fn span(mut self, range: Range<usize>, label: impl Into<String>) -> Self {
+        self.spans.push(Annotation {
+            range,
+            label: label.into(),
+            style: None,
+        });
+        self
+    }
+
+    /// Highlights the bytes `range` of the source in `style`, labeled with `label`; see
+    /// [`span`](Snippet::span).
+    pub fn span_styled(
+        mut self,
+        range: Range<usize>,
+        label: impl Into<String>,
+        style: Style,
+    ) -> Self {
+        self.spans.push(Annotation {
+            range,
+            label: label.into(),
+            style: Some(style),
+        });
+        self
+    }
+
+    /// Sets the style of the primary span, its carets and its label.
+    pub fn style(mut self, style: Style) -> Self {
+        self.style = style;
+        self
+    }
+
+    /// Adds a `= note:` line after the source.
+    pub fn note(mut self, note: impl Into<String>) -> Self {
+        self.notes.push(("note:", note.into()));
+        self
+    }
+
+    /// Adds a `= help:` line after the source.
+    pub fn help(mut self, help: impl Into<String>) -> Self {
+        self.notes.push(("help:", help.into()));
+        self
+    }
+
+    /// Renders the snippet, without a final newline.
+    pub fn render(&self) -> String {
+        let lines = self.lines();
+        let spans: Vec<(Range<usize>, &str, Style)> = self
+            .spans
+            .iter()
+            .enumerate()
+            .map(|(index, span)| {
+                let style = match (index, span.style) {
+                    (_, Some(style)) => style,
+                    (0, None) => self.style,
+                    (_, None) => SECONDARY[(index - 1) % SECONDARY.len()],
+                };
+                (self.clamp(&span.range), span.label.as_str(), style)
+            })
+            .collect();
+        let covers = |index: usize, range: &Range<usize>| {
+            let (start, line) = lines[index];
+            overlaps(range, start, start + line.len())
+        };
+        let shown: Vec<usize> = (0..lines.len())
+            .filter(|&index| {
+                spans.is_empty() || spans.iter().any(|(range, ..)| covers(index, range))
+            })
+            .collect();
+        let last_number = self.first_line + shown.last().copied().unwrap_or(0);
+        let width = last_number.to_string().len();
+        let pad = " ".repeat(width);
+        let empty_gutter = format!("{} {}", pad, GUTTER.wrap('|'));
+        let mut out = Vec::new();
+        if let (Some(origin), Some((range, ..))) = (&self.origin, spans.first()) {
+            let index = lines
+                .iter()
+                .rposition(|&(start, _)| start <= range.start)
+                .expect("the first line starts at 0");
+            let (start, line) = lines[index];
+            let column = line[..(range.start - start).min(line.len())]
+                .chars()
+                .count()
+                + 1;
+            out.push(format!(
+                "{}{} {}:{}:{}",
+                pad,
+                GUTTER.wrap("-->"),
+                origin,
+                self.first_line + index,
+                column
+            ));
+        }
+        if !spans.is_empty() {
+            out.push(empty_gutter.clone());
+        }
+        let mut previous = None;
+        for &index in &shown {
+            if previous.is_some_and(|previous| index > previous + 1) {
+                out.push(GUTTER.wrap("..."));
+            }
+            previous = Some(index);
+            let (start, line) = lines[index];
+            let number = GUTTER.wrap(format!(
+                "{:>width$} |",
+                self.first_line + index,
+                width = width
+            ));
+            // Later spans are applied first, so the primary span wins where spans overlap.
+            let mut marks = Vec::new();
+            let mut styles = vec![Style::new(); line.len()];
+            for (position, (range, label, style)) in spans.iter().enumerate().rev() {
+                if !covers(index, range) {
+                    continue;
+                }
+                let from = range.start.clamp(start, start + line.len()) - start;
+                let to = range.end.clamp(start, start + line.len()) - start;
+                styles[from..to].fill(*style);
+                let ends_here = !(index + 1..lines.len()).any(|next| covers(next, range));
+                marks.push(Mark {
+                    column: text_width(&expand(&line[..from])),
+                    width: text_width(&expand(&line[from..to])).max(1),
+                    glyph: if position == 0 { '^' } else { '-' },
+                    style: *style,
+                    label: Some(*label).filter(|label| ends_here && !label.is_empty()),
+                });
+            }
+            out.push(format!("{} {}", number, highlight(line, &styles)));
+            if marks.is_empty() {
+                continue;
+            }
+            marks.sort_by_key(|mark| mark.column);
+            for row in render_marks(&marks) {
+                out.push(format!("{} {}", empty_gutter, row));
+            }
+        }
+        if !self.notes.is_empty() {
+            out.push(empty_gutter);
+        }
+        for (kind, text) in &self.notes {
+            let style = if *kind == "help:" { HELP } else { NOTE };
+            let indent = format!("\n{}   {}", pad, " ".repeat(kind.len() + 1));
+            out.push(format!(
+                "{} {} {} {}",
+                pad,
+                GUTTER.wrap('='),
+                style.wrap(kind),
+                text.replace('\n', &indent)
+            ));
+        }
+        out.join("\n")
+    }
+
+    /// Returns the lines of the source with their byte offsets, without line terminators.
+    fn lines(&self) -> Vec<(usize, &str)> {
+        let mut lines = Vec::new();
+        let mut start = 0;
+        let source = self.source.strip_suffix('\n').unwrap_or(&self.source);
+        for line in source.split('\n') {
+            lines.push((start, line.strip_suffix('\r').unwrap_or(line)));
+            start += line.len() + 1;
+        }
+        lines
+    }
+
+    /// Clamps `range` to the source, the final line terminator excluded, and widens it to character
+    /// boundaries.
+    fn clamp(&self, range: &Range<usize>) -> Range<usize> {
+        let source = self.source.strip_suffix('\n').unwrap_or(&self.source);
+        let len = source.strip_suffix('\r').unwrap_or(source).len();
+        let mut start = range.start.min(len);
+        let mut end = range.end.clamp(start, len);
+        while !self.source.is_char_boundary(start) {
+            start -= 1;
+        }
+        while !self.source.is_char_boundary(end) {
+            end += 1;
+        }
+        start..end
+    }
+}
+
+impl fmt::Display for Snippet {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.write_str(&self.render())
+    }
+}
+
+/// Returns `true` if `range` covers part of the line spanning the bytes `start..end`, or points into
+/// it when empty.
+fn overlaps(range: &Range<usize>, start: usize, end: usize) -> bool {
+    if range.is_empty() {
+        (start..=end).contains(&range.start)
+    } else {
+        range.start <= end && range.end > start
+    }
+}
+
+fn expand(text: &str) -> String {
+    text.replace('\t', TAB)
+}
+
+/// Renders `line` with each byte in its style from `styles`, tabs expanded.
+fn highlight(line: &str, styles: &[Style]) -> String {
+    let mut out = String::new();
+    let mut start = 0;
+    for end in (1..=line.len()).filter(|&end| line.is_char_boundary(end)) {
+        if end == line.len() || styles[end] != styles[start] {
+            out.push_str(&styles[start].wrap(expand(&line[start..end])));
+            start = end;
+        }
+    }
+    out
+}
+
+/// Renders the rows below a source line: the underlines of `marks`, sorted by column, with the label
+/// of the rightmost one, then a row per other label from right to left, with a `|` under each
+/// underline whose label is still to come, left of the label of the row.
+fn render_marks(marks: &[Mark<'_>]) -> Vec<String> {
+    let mut underline = Vec::new();
+    for mark in marks.iter().rev() {
+        let end = mark.column + mark.width;
+        if underline.len() < end {
+            underline.resize(end, (' ', Style::new()));
+        }
+        underline[mark.column..end].fill((mark.glyph, mark.style));
+    }
+    let mut rows = vec![render_cells(&underline)];
+    let mut labeled: Vec<&Mark<'_>> = marks.iter().filter(|mark| mark.label.is_some()).collect();
+    let rightmost = marks.last().map(|mark| mark.column);
+    if let Some(last) = labeled.last().filter(|last| Some(last.column) == rightmost) {
+        rows[0].push(' ');
+        rows[0].push_str(&last.style.wrap(last.label.unwrap_or_default()));
+        labeled.pop();
+    }
+    while let Some(mark) = labeled.pop() {
+        let mut cells = vec![(' ', Style::new()); mark.column];
+        for left in labeled.iter().filter(|left| left.column < mark.column) {
+            cells[left.column] = ('|', left.style);
+        }
+        let mut row = render_cells(&cells);
+        row.push_str(&mark.style.wrap(mark.label.unwrap_or_default()));
+        rows.push(row);
+    }
+    rows
+}
+
+/// Renders single-column cells, grouping runs of the same style.
+fn render_cells(cells: &[(char, Style)]) -> String {
+    let mut out = String::new();
+    let mut run = String::new();
+    let mut style = Style::new();
+    for &(glyph, cell_style) in cells {
+        if cell_style != style && !run.is_empty() {
+            out.push_str(&style.wrap(&run));
+            run.clear();
+        }
+        style = cell_style;
+        run.push(glyph);
+    }
+    out.push_str(&style.wrap(&run));
+    out
+}
@@ -0,0 +1,233 @@
+//! # Spinners
+//!
+//! A [`Spinner`] shows colored activity feedback for long-running steps whose duration is unknown.
+//! Drive it manually with [`Spinner::tick`] from a loop that already exists, or, with the `spinner-thread`
+//! feature, hand it to a background thread with [`Spinner::spawn`].
+//!
+//! ```rust,no_run
+//! use bytescolor::spinner::Spinner;
+//!
+//! let mut spinner = Spinner::new("Resolving dependencies");
+//! let mut stderr = std::io::stderr();
+//! for _ in 0..50 {
+//!     // ... do a slice of work ...
+//!     spinner.tick();
+//!     spinner.draw(&mut stderr).unwrap();
+//! }
+//! spinner.finish(&mut stderr, "✔", "Resolved").unwrap();
+//! ```
+//!
+//! This module is available with the `widgets` feature, enabled by default.
+
+use std::io::{self, Write};
+
+use crate::{term, Color, Style};
+
+/// Braille dots, the default frames.
+pub const DOTS: &[&str] = &["⠋", "⠙", "⠹", "⠸", "⠼", "⠴", "⠦", "⠧", "⠇", "⠏"];
+
+/// ASCII line frames, for terminals without Unicode support.
+pub const LINE: &[&str] = &["-", "\\", "|", "/"];
+
+/// Growing and shrinking arc frames.
+pub const ARC: &[&str] = &["◜", "◠", "◝", "◞", "◡", "◟"];
+
+/// An animated activity indicator followed by a message.
+///
+/// # Examples
+///
+/// ```rust
+/// use bytescolor::spinner::{Spinner, LINE};
+/// use bytescolor::Style;
+///
+/// let mut spinner = Spinner::new("Working").frames(LINE).style(Style::new());
+/// assert_eq!(spinner.render(), "- Working");
+/// spinner.tick();
+/// assert_eq!(spinner.render(), "\\ Working");
+/// ```
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct Spinner {
+    frames: Vec<String>,
+    style: Style,
+    message: String,
+    frame: usize,
+}
+
+impl Spinner {
+    /// Creates a cyan [`DOTS`] spinner showing `message`.
+    pub fn new(message: impl Into<String>) -> Self {
+        Spinner {
+            frames: DOTS.iter().map(|frame| frame.to_string()).collect(),
+            style: Style::new().fg(Color::Cyan),
+            message: message.into(),
+            frame: 0,
+        }
+    }
+
+    /// Sets the animation frames.
+    ///
+    /// # Panics
+    ///
+    /// Panics if `frames` is empty.
+    pub fn frames<I, S>(mut self, frames: I) -> Self
+    where
+        I: IntoIterator<Item = S>,
+        S: AsRef<str>,
+    {
+        self.frames = frames
+            .into_iter()
+            .map(|frame| frame.as_ref().to_string())
+            .collect();
+        assert!(
+            !self.frames.is_empty(),
+            "a spinner needs at least one frame"
+        );
+        self.frame = 0;
+        self
+    }
+
+    /// Sets the style of the animated frame.
+    pub fn style(mut self, style: Style) -> Self {
+        self.style = style;
+        self
+    }
+
+    /// Replaces the message shown next to the frame.
+    pub fn set_message(&mut self, message: impl Into<String>) {
+        self.message = message.into();
+    }
+
+    /// Advances the animation by one frame.
+    pub fn tick(&mut self) {
+        self.frame = (self.frame + 1) % self.frames.len();
+    }
+
+    /// Renders the current frame and the message, without any cursor movement.
+    pub fn render(&self) -> String {
+        format!(
+            "{} {}",
+            self.style.wrap(&self.frames[self.frame]),
+            self.message
+        )
+    }
+
+    /// Redraws the spinner in place at the start of the current line.
+    pub fn draw<W: Write>(&self, writer: &mut W) -> io::Result<()> {
+        write!(writer, "\r{}{}", self.render(), term::CLEAR_LINE_END)?;
+        writer.flush()
+    }
+
+    /// Replaces the spinner line with a final `symbol message` line (for instance a green `✔`)
+    /// and moves to the next line.
+    pub fn finish<W: Write>(
+        &self,
+        writer: &mut W,
+        symbol: impl std::fmt::Display,
+        message: impl std::fmt::Display,
+    ) -> io::Result<()> {
+        write!(writer, "\r{} {}{}\n", symbol, message, term::CLEAR_LINE_END)?;
+        writer.flush()
+    }
+
+    /// Animates the spinner on a background thread, redrawing it on `writer` every `interval`.
+    ///
+    /// The returned [`SpinnerHandle`] updates the message and stops the animation; dropping it stops
+    /// the thread and clears the line.
+    ///
+    /// This method is available with the `spinner-thread` feature.
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// use std::time::Duration;
+    /// use bytescolor::spinner::Spinner;
+    ///
+    /// let handle = Spinner::new("Compiling").spawn(std::io::sink(), Duration::from_millis(80));
+    /// handle.set_message("Linking");
+    /// handle.finish("✔", "Done").unwrap();
+    /// ```
+    #[cfg(feature = "spinner-thread")]
+    pub fn spawn<W>(self, writer: W, interval: std::time::Duration) -> SpinnerHandle<W>
+    where
+        W: Write + Send + 'static,
+    {
+        use std::sync::atomic::{AtomicBool, Ordering};
+        use std::sync::{Arc, Mutex};
+
+        let shared = Arc::new(Mutex::new((self, writer)));
+        let stop = Arc::new(AtomicBool::new(false));
+        let thread = {
+            let shared = Arc::clone(&shared);
+            let stop = Arc::clone(&stop);
+            std::thread::spawn(move || {
+                while !stop.load(Ordering::Acquire) {
+                    {
+                        let mut guard = shared.lock().unwrap_or_else(|e| e.into_inner());
+                        let (spinner, writer) = &mut *guard;
+                        // Drawing errors (e.g. a closed pipe) must not take the program down.
+                        let _ = spinner.draw(writer);
+                        spinner.tick();
+                    }
+                    std::thread::park_timeout(interval);
+                }
+            })
+        };
+        SpinnerHandle {
+            shared,
+            stop,
+            thread: Some(thread),
+        }
+    }
+}
+
+/// Controls a spinner animated by a background thread, see [`Spinner::spawn`].
+///
+/// Available with the `spinner-thread` feature.
+#[cfg(feature = "spinner-thread")]
+#[derive(Debug)]
+pub struct SpinnerHandle<W: Write> {
+    shared: std::sync::Arc<std::sync::Mutex<(Spinner, W)>>,
+    stop: std::sync::Arc<std::sync::atomic::AtomicBool>,
+    thread: Option<std::thread::JoinHandle<()>>,
+}
+
+#[cfg(feature = "spinner-thread")]
+impl<W: Write> SpinnerHandle<W> {
+    /// Replaces the message shown next to the frame.
+    pub fn set_message(&self, message: impl Into<String>) {
+        let mut guard = self.shared.lock().unwrap_or_else(|e| e.into_inner());
+        guard.0.set_message(message);
+    }
+
+    /// Stops the animation and replaces the spinner line with a final `symbol message` line.
+    pub fn finish(
+        mut self,
+        symbol: impl std::fmt::Display,
+        message: impl std::fmt::Display,
+    ) -> io::Result<()> {
+        self.join();
+        let mut guard = self.shared.lock().unwrap_or_else(|e| e.into_inner());
+        let (spinner, writer) = &mut *guard;
+        spinner.finish(writer, symbol, message)
+    }
+
+    fn join(&mut self) {
+        self.stop.store(true, std::sync::atomic::Ordering::Release);
+        if let Some(thread) = self.thread.take() {
+            thread.thread().unpark();
+            let _ = thread.join();
+        }
+    }
+}
+
+#[cfg(feature = "spinner-thread")]
+impl<W: Write> Drop for SpinnerHandle<W> {
+    fn drop(&mut self) {
+        if self.thread.is_some() {
+            self.join();
+            let mut guard = self.shared.lock().unwrap_or_else(|e| e.into_inner());
+            let _ = write!(guard.1, "\r{}", term::CLEAR_LINE_END);
+            let _ = guard.1.flush();
+        }
+    }
+}
@@ -0,0 +1,91 @@
+//! # Status Values
+//!
+//! Extension traits rendering `Option` and `Result` values in status-reporting code without matching on
+//! them first: present values are styled and missing ones dimmed, successes green and errors red.
+//!
+//! ```rust
+//! use bytescolor::status::{OptionColor, ResultColor};
+//!
+//! let proxy: Option<&str> = None;
+//! let connected: Result<&str, String> = Err("connection refused".into());
+//! println!("proxy: {}", proxy.colored()); // Displays "none" dimmed
+//! println!("db: {}", connected.colored()); // Displays "connection refused" in red
+//! ```
+//!
+//! This module is available with the `widgets` feature, enabled by default.
+
+use std::fmt::Display;
+
+use crate::{Color, Style, StyledString};
+
+/// Extension trait rendering optional values.
+pub trait OptionColor {
+    /// Renders the value of `Some` in cyan, and `None` as a dimmed `none`.
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// # if cfg!(feature = "no-color-build") { return; }
+    /// use bytescolor::status::OptionColor;
+    ///
+    /// assert_eq!(Some(30).colored().as_str(), "\x1b[36m30\x1b[0m");
+    /// assert_eq!(None::<u32>.colored().as_str(), "\x1b[2mnone\x1b[0m");
+    /// ```
+    fn colored(&self) -> StyledString {
+        self.colored_with(Style::new().fg(Color::Cyan), "none")
+    }
+
+    /// Renders the value of `Some` in `style`, and `None` as the dimmed `placeholder`.
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// use bytescolor::status::OptionColor;
+    /// use bytescolor::Style;
+    ///
+    /// let user: Option<&str> = None;
+    /// assert_eq!(user.colored_with(Style::new().bold(), "(anonymous)"), "(anonymous)");
+    /// ```
+    fn colored_with(&self, style: Style, placeholder: &str) -> StyledString;
+}
+
+impl<T: Display> OptionColor for Option<T> {
+    fn colored_with(&self, style: Style, placeholder: &str) -> StyledString {
+        match self {
+            Some(value) => style.wrap(value).into(),
+            None => Style::new().dim().wrap(placeholder).into(),
+        }
+    }
+}
+
+/// Extension trait rendering results.
+pub trait ResultColor {
+    /// Renders the value of `Ok` in green, and the error of `Err` in red.
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// # if cfg!(feature = "no-color-build") { return; }
+    /// use bytescolor::status::ResultColor;
+    ///
+    /// let ok: Result<&str, &str> = Ok("up");
+    /// let err: Result<&str, &str> = Err("down");
+    /// assert_eq!(ok.colored().as_str(), "\x1b[32mup\x1b[0m");
+    /// assert_eq!(err.colored().as_str(), "\x1b[31mdown\x1b[0m");
+    /// ```
+    fn colored(&self) -> StyledString {
+        self.colored_with(Style::new().fg(Color::Green), Style::new().fg(Color::Red))
+    }
+
+    /// Renders the value of `Ok` in `ok`, and the error of `Err` in `err`.
+    fn colored_with(&self, ok: Style, err: Style) -> StyledString;
+}
+
+impl<T: Display, E: Display> ResultColor for Result<T, E> {
+    fn colored_with(&self, ok: Style, err: Style) -> StyledString {
+        match self {
+            Ok(value) => ok.wrap(value).into(),
+            Err(error) => err.wrap(error).into(),
+        }
+    }
+}
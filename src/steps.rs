@@ -0,0 +1,158 @@
+//! # Step Reporters
+//!
+//! [`Steps`] reports the progress of a task made of a known number of steps, the way build and
+//! install tools do: each step is printed as `[1/5] … Building` while it runs, then redrawn in place
+//! as `[1/5] ✔ Building (1s 200ms)` or `[1/5] ✖ Building (3s)` once it is over.
+//!
+//! ```rust,no_run
+//! use bytescolor::steps::Steps;
+//!
+//! let mut steps = Steps::new(2);
+//! let mut stderr = std::io::stderr();
+//! steps.start(&mut stderr, "Fetching sources").unwrap();
+//! // ... fetch ...
+//! steps.success(&mut stderr).unwrap();
+//! steps.start(&mut stderr, "Building").unwrap();
+//! // ... build ...
+//! steps.fail(&mut stderr).unwrap();
+//! ```
+//!
+//! This module is available with the `widgets` feature, enabled by default.
+
+use std::io::{self, Write};
+use std::time::{Duration, Instant};
+
+use crate::time::HumanDuration;
+use crate::{term, Color, Style};
+
+const RUNNING: Style = Style::new().fg(Color::Yellow);
+const SUCCESS: Style = Style::new().fg(Color::Green);
+const FAILURE: Style = Style::new().fg(Color::Red);
+const ELAPSED: Style = Style::new().dim();
+
+/// A reporter of numbered steps with a status glyph and their elapsed time.
+///
+/// # Examples
+///
+/// ```rust
+/// use std::time::Duration;
+/// use bytescolor::steps::Steps;
+/// use bytescolor::ansi;
+///
+/// let mut steps = Steps::new(12);
+/// let mut out = Vec::new();
+/// steps.start(&mut out, "Linking").unwrap();
+/// assert_eq!(ansi::strip(&steps.render()), "[ 1/12] … Linking");
+/// assert_eq!(
+///     ansi::strip(&steps.render_done(true, Duration::from_millis(1200))),
+///     "[ 1/12] ✔ Linking (1s 200ms)"
+/// );
+/// ```
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct Steps {
+    total: usize,
+    current: usize,
+    label: String,
+    started: Option<Instant>,
+    counter: Style,
+}
+
+impl Steps {
+    /// Creates a reporter of `total` steps, with a bold cyan step counter.
+    pub fn new(total: usize) -> Self {
+        Steps {
+            total,
+            current: 0,
+            label: String::new(),
+            started: None,
+            counter: Style::new().fg(Color::Cyan).bold(),
+        }
+    }
+
+    /// Sets the style of the `[n/total]` step counter.
+    pub fn counter_style(mut self, style: Style) -> Self {
+        self.counter = style;
+        self
+    }
+
+    /// Returns the number of the current step, counting from 1, or 0 before the first one.
+    pub fn current(&self) -> usize {
+        self.current
+    }
+
+    /// Starts the next step and draws its running line, without moving to the next line.
+    pub fn start<W: Write>(&mut self, writer: &mut W, label: impl Into<String>) -> io::Result<()> {
+        self.current += 1;
+        self.label = label.into();
+        self.started = Some(Instant::now());
+        write!(writer, "\r{}{}", self.render(), term::CLEAR_LINE_END)?;
+        writer.flush()
+    }
+
+    /// Ends the current step successfully, redrawing its line with a green `✔` and the elapsed time,
+    /// and moves to the next line. Returns the elapsed time.
+    pub fn success<W: Write>(&mut self, writer: &mut W) -> io::Result<Duration> {
+        self.finish(writer, true)
+    }
+
+    /// Ends the current step with a failure, redrawing its line with a red `✖` and the elapsed time,
+    /// and moves to the next line. Returns the elapsed time.
+    pub fn fail<W: Write>(&mut self, writer: &mut W) -> io::Result<Duration> {
+        self.finish(writer, false)
+    }
+
+    /// Renders the line of the running step, without any cursor movement.
+    pub fn render(&self) -> String {
+        format!(
+            "{} {} {}",
+            self.render_counter(),
+            RUNNING.wrap('…'),
+            self.label
+        )
+    }
+
+    /// Renders the line of the current step once it has succeeded or failed after `elapsed`, without
+    /// any cursor movement.
+    pub fn render_done(&self, success: bool, elapsed: Duration) -> String {
+        let glyph = if success {
+            SUCCESS.wrap('✔')
+        } else {
+            FAILURE.wrap('✖')
+        };
+        format!(
+            "{} {} {} {}{}{}",
+            self.render_counter(),
+            glyph,
+            self.label,
+            ELAPSED.wrap('('),
+            elapsed.human_duration(),
+            ELAPSED.wrap(')')
+        )
+    }
+
+    fn finish<W: Write>(&mut self, writer: &mut W, success: bool) -> io::Result<Duration> {
+        let elapsed = self
+            .started
+            .take()
+            .map_or(Duration::ZERO, |at| at.elapsed());
+        writeln!(
+            writer,
+            "\r{}{}",
+            self.render_done(success, elapsed),
+            term::CLEAR_LINE_END
+        )?;
+        writer.flush()?;
+        Ok(elapsed)
+    }
+
+    /// Renders `[n/total]`, the step number padded to the width of the total so lines stay aligned.
+    fn render_counter(&self) -> String {
+        let width = self.total.to_string().len();
+        self.counter.wrap(format!(
+            "[{:>width$}/{}]",
+            self.current,
+            self.total,
+            width = width
+        ))
+    }
+}
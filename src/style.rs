@@ -0,0 +1,1145 @@
+//! # Styles
+//!
+//! A [`Style`] bundles an optional foreground [`Color`], an optional background [`Color`] and a set of
+//! text attributes (bold, underline, ...) into a single `Copy` value. Styles are the building block shared
+//! by every subsystem of the crate that needs to describe formatting as data rather than as a method call.
+//!
+//! ```rust
+//! use bytescolor::{ByteColor, Color, Style};
+//!
+//! let error = Style::new().fg(Color::Red).bold();
+//! println!("{}", "error".styled(error)); // Displays "error" in bold red
+//! ```
+
+use std::fmt;
+use std::ops::{Add, AddAssign, BitOr, BitOrAssign};
+
+use crate::backend::{self, ColorBackend};
+use crate::control::{self, ColorLevel};
+use crate::StyledString;
+
+const BOLD: u8 = 1 << 0;
+const DIM: u8 = 1 << 1;
+const ITALIC: u8 = 1 << 2;
+const UNDERLINE: u8 = 1 << 3;
+const BLINK: u8 = 1 << 4;
+const REVERSE: u8 = 1 << 5;
+const HIDDEN: u8 = 1 << 6;
+const STRIKETHROUGH: u8 = 1 << 7;
+
+/// A terminal color, either one of the 16 standard ANSI colors, an entry of the 256-color palette,
+/// or a 24-bit RGB value.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub enum Color {
+    Black,
+    Red,
+    Green,
+    Yellow,
+    Blue,
+    Magenta,
+    Cyan,
+    White,
+    BrightBlack,
+    BrightRed,
+    BrightGreen,
+    BrightYellow,
+    BrightBlue,
+    BrightMagenta,
+    BrightCyan,
+    BrightWhite,
+    /// A color from the 256-color palette.
+    Fixed(u8),
+    /// A 24-bit color.
+    Rgb(u8, u8, u8),
+}
+
+/// The default xterm values of the 16 standard colors, used to approximate colors when downgrading.
+const ANSI_PALETTE: [(u8, u8, u8); 16] = [
+    (0, 0, 0),
+    (205, 0, 0),
+    (0, 205, 0),
+    (205, 205, 0),
+    (0, 0, 238),
+    (205, 0, 205),
+    (0, 205, 205),
+    (229, 229, 229),
+    (127, 127, 127),
+    (255, 0, 0),
+    (0, 255, 0),
+    (255, 255, 0),
+    (92, 92, 255),
+    (255, 0, 255),
+    (0, 255, 255),
+    (255, 255, 255),
+];
+
+/// Channel values of the 6×6×6 color cube of the 256-color palette.
+const CUBE_LEVELS: [u8; 6] = [0, 95, 135, 175, 215, 255];
+
+impl Color {
+    /// Returns the RGB value of the color, using the xterm defaults for palette colors.
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// use bytescolor::Color;
+    ///
+    /// assert_eq!(Color::Fixed(196).to_rgb(), (255, 0, 0));
+    /// assert_eq!(Color::Fixed(244).to_rgb(), (128, 128, 128));
+    /// ```
+    pub fn to_rgb(self) -> (u8, u8, u8) {
+        match self {
+            Color::Rgb(r, g, b) => (r, g, b),
+            Color::Fixed(code @ 0..=15) => ANSI_PALETTE[code as usize],
+            Color::Fixed(code @ 16..=231) => {
+                let index = code - 16;
+                (
+                    CUBE_LEVELS[(index / 36) as usize],
+                    CUBE_LEVELS[(index / 6 % 6) as usize],
+                    CUBE_LEVELS[(index % 6) as usize],
+                )
+            }
+            Color::Fixed(code) => {
+                let gray = 8 + 10 * (code - 232);
+                (gray, gray, gray)
+            }
+            ansi => ANSI_PALETTE[ansi.ansi_index().expect("standard color") as usize],
+        }
+    }
+
+    /// Returns the WCAG contrast ratio between the color and `other`, from 1 (identical luminance) to
+    /// 21 (black on white), using the xterm defaults for palette colors. Text is comfortably readable
+    /// from about 4.5, and hard to read below 3.
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// use bytescolor::Color;
+    ///
+    /// assert_eq!(Color::Rgb(0, 0, 0).contrast_ratio(Color::Rgb(255, 255, 255)), 21.0);
+    /// assert!(Color::Blue.contrast_ratio(Color::Black) < 3.0);
+    /// ```
+    pub fn contrast_ratio(self, other: Color) -> f64 {
+        let (a, b) = (self.luminance(), other.luminance());
+        (a.max(b) + 0.05) / (a.min(b) + 0.05)
+    }
+
+    /// Returns the WCAG relative luminance of the color.
+    fn luminance(self) -> f64 {
+        let (r, g, b) = self.to_rgb();
+        let linear = |channel: u8| {
+            let channel = f64::from(channel) / 255.0;
+            if channel <= 0.039_28 {
+                channel / 12.92
+            } else {
+                ((channel + 0.055) / 1.055).powf(2.4)
+            }
+        };
+        0.2126 * linear(r) + 0.7152 * linear(g) + 0.0722 * linear(b)
+    }
+
+    /// Approximates the color with one displayable at `level`, or returns `None` for
+    /// [`ColorLevel::None`].
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// use bytescolor::control::ColorLevel;
+    /// use bytescolor::Color;
+    ///
+    /// assert_eq!(Color::Rgb(255, 0, 0).downgrade(ColorLevel::Ansi256), Some(Color::Fixed(196)));
+    /// assert_eq!(Color::Rgb(250, 10, 10).downgrade(ColorLevel::Ansi16), Some(Color::BrightRed));
+    /// assert_eq!(Color::Red.downgrade(ColorLevel::None), None);
+    /// ```
+    pub fn downgrade(self, level: ColorLevel) -> Option<Color> {
+        match (level, self) {
+            (ColorLevel::None, _) => None,
+            (ColorLevel::TrueColor, color) => Some(color),
+            (ColorLevel::Ansi256, Color::Rgb(r, g, b)) => {
+                Some(Color::Fixed(nearest_fixed(r, g, b)))
+            }
+            (ColorLevel::Ansi256, color) => Some(color),
+            (ColorLevel::Ansi16, Color::Fixed(code @ 0..=15)) => Some(Color::from_ansi_index(code)),
+            (ColorLevel::Ansi16, color @ (Color::Fixed(_) | Color::Rgb(..))) => {
+                let rgb = color.to_rgb();
+                let index = (0..16)
+                    .min_by_key(|&i| distance(ANSI_PALETTE[i as usize], rgb))
+                    .expect("palette is not empty");
+                Some(Color::from_ansi_index(index))
+            }
+            (ColorLevel::Ansi16, color) => Some(color),
+        }
+    }
+
+    /// Returns the index of a standard color in the 16-color palette.
+    fn ansi_index(self) -> Option<u8> {
+        let index = match self {
+            Color::Black => 0,
+            Color::Red => 1,
+            Color::Green => 2,
+            Color::Yellow => 3,
+            Color::Blue => 4,
+            Color::Magenta => 5,
+            Color::Cyan => 6,
+            Color::White => 7,
+            Color::BrightBlack => 8,
+            Color::BrightRed => 9,
+            Color::BrightGreen => 10,
+            Color::BrightYellow => 11,
+            Color::BrightBlue => 12,
+            Color::BrightMagenta => 13,
+            Color::BrightCyan => 14,
+            Color::BrightWhite => 15,
+            Color::Fixed(_) | Color::Rgb(..) => return None,
+        };
+        Some(index)
+    }
+
+    fn from_ansi_index(index: u8) -> Color {
+        const COLORS: [Color; 16] = [
+            Color::Black,
+            Color::Red,
+            Color::Green,
+            Color::Yellow,
+            Color::Blue,
+            Color::Magenta,
+            Color::Cyan,
+            Color::White,
+            Color::BrightBlack,
+            Color::BrightRed,
+            Color::BrightGreen,
+            Color::BrightYellow,
+            Color::BrightBlue,
+            Color::BrightMagenta,
+            Color::BrightCyan,
+            Color::BrightWhite,
+        ];
+        COLORS[index as usize]
+    }
+
+    /// Writes the SGR parameters selecting this color as foreground (`background == false`)
+    /// or background (`background == true`).
+    fn write_sgr(&self, f: &mut dyn fmt::Write, background: bool) -> fmt::Result {
+        let base = if background { 40 } else { 30 };
+        match *self {
+            Color::Black => write!(f, "{}", base),
+            Color::Red => write!(f, "{}", base + 1),
+            Color::Green => write!(f, "{}", base + 2),
+            Color::Yellow => write!(f, "{}", base + 3),
+            Color::Blue => write!(f, "{}", base + 4),
+            Color::Magenta => write!(f, "{}", base + 5),
+            Color::Cyan => write!(f, "{}", base + 6),
+            Color::White => write!(f, "{}", base + 7),
+            Color::BrightBlack => write!(f, "{}", base + 60),
+            Color::BrightRed => write!(f, "{}", base + 61),
+            Color::BrightGreen => write!(f, "{}", base + 62),
+            Color::BrightYellow => write!(f, "{}", base + 63),
+            Color::BrightBlue => write!(f, "{}", base + 64),
+            Color::BrightMagenta => write!(f, "{}", base + 65),
+            Color::BrightCyan => write!(f, "{}", base + 66),
+            Color::BrightWhite => write!(f, "{}", base + 67),
+            Color::Fixed(code) => write!(f, "{};5;{}", base + 8, code),
+            Color::Rgb(r, g, b) => write!(f, "{};2;{};{};{}", base + 8, r, g, b),
+        }
+    }
+}
+
+fn distance(a: (u8, u8, u8), b: (u8, u8, u8)) -> u32 {
+    let d = |x: u8, y: u8| (x as i32 - y as i32).pow(2) as u32;
+    d(a.0, b.0) + d(a.1, b.1) + d(a.2, b.2)
+}
+
+/// Returns the entry of the 256-color palette closest to an RGB value, choosing between the nearest
+/// entry of the color cube and the nearest entry of the grayscale ramp.
+fn nearest_fixed(r: u8, g: u8, b: u8) -> u8 {
+    let level = |c: u8| {
+        (0..6)
+            .min_by_key(|&i| (CUBE_LEVELS[i] as i32 - c as i32).abs())
+            .expect("cube is not empty") as u8
+    };
+    let (ri, gi, bi) = (level(r), level(g), level(b));
+    let cube = 16 + 36 * ri + 6 * gi + bi;
+    let average = (r as u32 + g as u32 + b as u32) / 3;
+    let gray = 232 + ((average.saturating_sub(3) / 10).min(23) as u8);
+    let rgb = (r, g, b);
+    if distance(Color::Fixed(gray).to_rgb(), rgb) < distance(Color::Fixed(cube).to_rgb(), rgb) {
+        gray
+    } else {
+        cube
+    }
+}
+
+/// A combination of foreground color, background color and text attributes.
+///
+/// `Style::new()` is the plain style: it renders text unchanged. Builder methods return a modified copy,
+/// so styles can be declared as constants and freely passed by value.
+///
+/// # Examples
+///
+/// ```rust
+/// use bytescolor::{Color, Style};
+///
+/// const HEADER: Style = Style::new().fg(Color::Cyan).bold().underline();
+/// assert!(HEADER.is_bold());
+/// assert_eq!(HEADER.foreground(), Some(Color::Cyan));
+/// ```
+///
+/// Styles also combine with the `|` (or `+`) operator, the right-hand side adding its attributes and
+/// overriding the colors it sets:
+///
+/// ```rust
+/// use bytescolor::{ByteColor, Color, Style};
+///
+/// let warning = Style::YELLOW | Style::BOLD | Style::UNDERLINE;
+/// assert_eq!(warning, Style::new().fg(Color::Yellow).bold().underline());
+/// println!("{}", "careful".styled(warning)); // Displays "careful" in bold underlined yellow
+/// ```
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash, Default)]
+pub struct Style {
+    fg: Option<Color>,
+    bg: Option<Color>,
+    attrs: u8,
+}
+
+impl Style {
+    /// The style with a black foreground.
+    pub const BLACK: Style = Style::new().fg(Color::Black);
+    /// The style with a red foreground.
+    pub const RED: Style = Style::new().fg(Color::Red);
+    /// The style with a green foreground.
+    pub const GREEN: Style = Style::new().fg(Color::Green);
+    /// The style with a yellow foreground.
+    pub const YELLOW: Style = Style::new().fg(Color::Yellow);
+    /// The style with a blue foreground.
+    pub const BLUE: Style = Style::new().fg(Color::Blue);
+    /// The style with a magenta foreground.
+    pub const MAGENTA: Style = Style::new().fg(Color::Magenta);
+    /// The style with a cyan foreground.
+    pub const CYAN: Style = Style::new().fg(Color::Cyan);
+    /// The style with a white foreground.
+    pub const WHITE: Style = Style::new().fg(Color::White);
+    /// The style with a bright black foreground.
+    pub const BRIGHT_BLACK: Style = Style::new().fg(Color::BrightBlack);
+    /// The style with a bright red foreground.
+    pub const BRIGHT_RED: Style = Style::new().fg(Color::BrightRed);
+    /// The style with a bright green foreground.
+    pub const BRIGHT_GREEN: Style = Style::new().fg(Color::BrightGreen);
+    /// The style with a bright yellow foreground.
+    pub const BRIGHT_YELLOW: Style = Style::new().fg(Color::BrightYellow);
+    /// The style with a bright blue foreground.
+    pub const BRIGHT_BLUE: Style = Style::new().fg(Color::BrightBlue);
+    /// The style with a bright magenta foreground.
+    pub const BRIGHT_MAGENTA: Style = Style::new().fg(Color::BrightMagenta);
+    /// The style with a bright cyan foreground.
+    pub const BRIGHT_CYAN: Style = Style::new().fg(Color::BrightCyan);
+    /// The style with a bright white foreground.
+    pub const BRIGHT_WHITE: Style = Style::new().fg(Color::BrightWhite);
+    /// The style with only the bold attribute.
+    pub const BOLD: Style = Style::new().bold();
+    /// The style with only the dim attribute.
+    pub const DIM: Style = Style::new().dim();
+    /// The style with only the italic attribute.
+    pub const ITALIC: Style = Style::new().italic();
+    /// The style with only the underline attribute.
+    pub const UNDERLINE: Style = Style::new().underline();
+    /// The style with only the blink attribute.
+    pub const BLINK: Style = Style::new().blink();
+    /// The style with only the reverse video attribute.
+    pub const REVERSE: Style = Style::new().reverse();
+    /// The style with only the hidden attribute.
+    pub const HIDDEN: Style = Style::new().hidden();
+    /// The style with only the strikethrough attribute.
+    pub const STRIKETHROUGH: Style = Style::new().strikethrough();
+
+    /// Creates a plain style with no colors and no attributes.
+    pub const fn new() -> Self {
+        Style {
+            fg: None,
+            bg: None,
+            attrs: 0,
+        }
+    }
+
+    /// Sets the foreground color.
+    pub const fn fg(mut self, color: Color) -> Self {
+        self.fg = Some(color);
+        self
+    }
+
+    /// Sets the background color.
+    pub const fn bg(mut self, color: Color) -> Self {
+        self.bg = Some(color);
+        self
+    }
+
+    /// Adds the bold attribute.
+    pub const fn bold(self) -> Self {
+        self.with(BOLD)
+    }
+
+    /// Adds the dim (faint) attribute.
+    pub const fn dim(self) -> Self {
+        self.with(DIM)
+    }
+
+    /// Adds the italic attribute.
+    pub const fn italic(self) -> Self {
+        self.with(ITALIC)
+    }
+
+    /// Adds the underline attribute.
+    pub const fn underline(self) -> Self {
+        self.with(UNDERLINE)
+    }
+
+    /// Adds the blink attribute.
+    pub const fn blink(self) -> Self {
+        self.with(BLINK)
+    }
+
+    /// Adds the reverse video attribute, swapping foreground and background.
+    pub const fn reverse(self) -> Self {
+        self.with(REVERSE)
+    }
+
+    /// Adds the hidden (concealed) attribute.
+    pub const fn hidden(self) -> Self {
+        self.with(HIDDEN)
+    }
+
+    /// Adds the strikethrough attribute.
+    pub const fn strikethrough(self) -> Self {
+        self.with(STRIKETHROUGH)
+    }
+
+    /// Returns `self` with the attributes of `other` added and the colors `other` sets replaced.
+    const fn combine(self, other: Style) -> Style {
+        Style {
+            fg: match other.fg {
+                Some(fg) => Some(fg),
+                None => self.fg,
+            },
+            bg: match other.bg {
+                Some(bg) => Some(bg),
+                None => self.bg,
+            },
+            attrs: self.attrs | other.attrs,
+        }
+    }
+
+    const fn with(mut self, attr: u8) -> Self {
+        self.attrs |= attr;
+        self
+    }
+
+    /// Returns the problems that can make text in this style unreadable: blinking, a foreground and
+    /// background of too little contrast, or, without a background, a foreground that nearly vanishes
+    /// on common dark (black) or light (white) terminal backgrounds.
+    ///
+    /// Meant for warning about user-provided styles, such as themes read from a file.
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// use bytescolor::style::StyleIssue;
+    /// use bytescolor::{Color, Style};
+    ///
+    /// assert!(Style::new().fg(Color::Red).bold().validate().is_empty());
+    /// assert_eq!(
+    ///     Style::new().fg(Color::Fixed(233)).blink().validate(),
+    ///     [StyleIssue::Blink, StyleIssue::InvisibleOnDark]
+    /// );
+    /// assert!(matches!(
+    ///     Style::new().fg(Color::Yellow).bg(Color::White).validate()[..],
+    ///     [StyleIssue::LowContrast { .. }]
+    /// ));
+    /// ```
+    pub fn validate(&self) -> Vec<StyleIssue> {
+        let mut issues = Vec::new();
+        if self.is_blink() {
+            issues.push(StyleIssue::Blink);
+        }
+        match (self.fg, self.bg) {
+            (Some(fg), Some(bg)) => {
+                let ratio = fg.contrast_ratio(bg);
+                if ratio < MIN_CONTRAST {
+                    issues.push(StyleIssue::LowContrast { ratio });
+                }
+            }
+            (Some(fg), None) => {
+                let backgrounds = [
+                    (Color::Rgb(0, 0, 0), StyleIssue::InvisibleOnDark),
+                    (Color::Rgb(255, 255, 255), StyleIssue::InvisibleOnLight),
+                ];
+                for (background, issue) in backgrounds {
+                    if fg.contrast_ratio(background) < MIN_BACKGROUND_CONTRAST {
+                        issues.push(issue);
+                    }
+                }
+            }
+            _ => {}
+        }
+        issues
+    }
+
+    /// Returns the foreground color, if any.
+    pub const fn foreground(&self) -> Option<Color> {
+        self.fg
+    }
+
+    /// Returns the background color, if any.
+    pub const fn background(&self) -> Option<Color> {
+        self.bg
+    }
+
+    /// Returns `true` if the style is bold.
+    pub const fn is_bold(&self) -> bool {
+        self.attrs & BOLD != 0
+    }
+
+    /// Returns `true` if the style is dim.
+    pub const fn is_dim(&self) -> bool {
+        self.attrs & DIM != 0
+    }
+
+    /// Returns `true` if the style is italic.
+    pub const fn is_italic(&self) -> bool {
+        self.attrs & ITALIC != 0
+    }
+
+    /// Returns `true` if the style is underlined.
+    pub const fn is_underline(&self) -> bool {
+        self.attrs & UNDERLINE != 0
+    }
+
+    /// Returns `true` if the style blinks.
+    pub const fn is_blink(&self) -> bool {
+        self.attrs & BLINK != 0
+    }
+
+    /// Returns `true` if the style uses reverse video.
+    pub const fn is_reverse(&self) -> bool {
+        self.attrs & REVERSE != 0
+    }
+
+    /// Returns `true` if the style is hidden.
+    pub const fn is_hidden(&self) -> bool {
+        self.attrs & HIDDEN != 0
+    }
+
+    /// Returns `true` if the style is struck through.
+    pub const fn is_strikethrough(&self) -> bool {
+        self.attrs & STRIKETHROUGH != 0
+    }
+
+    /// Returns `true` if the style has no colors and no attributes.
+    pub const fn is_plain(&self) -> bool {
+        self.fg.is_none() && self.bg.is_none() && self.attrs == 0
+    }
+
+    /// Downgrades the colors of the style to `level`, see [`Color::downgrade`].
+    pub fn downgrade(mut self, level: ColorLevel) -> Self {
+        self.fg = self.fg.and_then(|color| color.downgrade(level));
+        self.bg = self.bg.and_then(|color| color.downgrade(level));
+        self
+    }
+
+    /// Returns the escape sequence that switches the terminal from this style to `next`, or an empty
+    /// string when they render the same.
+    ///
+    /// Only what differs is changed where possible, so streams of densely styled text stay small.
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// # if cfg!(feature = "no-color-build") { return; }
+    /// use bytescolor::Style;
+    ///
+    /// let heading = Style::BLUE | Style::BOLD;
+    /// assert_eq!(heading.transition_to(Style::BLUE), "\x1b[22m");
+    /// assert_eq!(Style::BLUE.transition_to(Style::RED | Style::UNDERLINE), "\x1b[4;31m");
+    /// assert_eq!(heading.transition_to(Style::new()), "\x1b[0m");
+    /// ```
+    pub fn transition_to(&self, next: Style) -> String {
+        let mut out = String::new();
+        self.write_transition(next, &mut out)
+            .expect("writing to a String cannot fail");
+        out
+    }
+
+    /// Writes the escape sequence switching from this style to `next` with the installed backend.
+    pub(crate) fn write_transition(&self, next: Style, f: &mut dyn fmt::Write) -> fmt::Result {
+        if cfg!(feature = "no-color-build") {
+            return Ok(());
+        }
+        let backend = backend::backend();
+        let (from, to) = (self.effective(backend), next.effective(backend));
+        if from == to {
+            return Ok(());
+        }
+        backend.write_transition(from, to, f)
+    }
+
+    /// Writes the shortest SGR sequence switching from this style to `next`, used by the
+    /// [`Ansi`](crate::backend::Ansi) backend.
+    pub(crate) fn write_sgr_transition(&self, next: Style, f: &mut dyn fmt::Write) -> fmt::Result {
+        if *self == next {
+            return Ok(());
+        }
+        if next.is_plain() {
+            return f.write_str("\x1b[0m");
+        }
+        if self.is_plain() {
+            return next.write_prefix(f);
+        }
+        let mut codes = String::new();
+        let mut push = |code: fmt::Arguments<'_>| {
+            if !codes.is_empty() {
+                codes.push(';');
+            }
+            let _ = fmt::Write::write_fmt(&mut codes, code);
+        };
+        let removed = self.attrs & !next.attrs;
+        let mut added = next.attrs & !self.attrs;
+        if removed & (BOLD | DIM) != 0 {
+            // 22 turns off both bold and dim, so the one that stays is turned back on.
+            push(format_args!("22"));
+            added |= next.attrs & (BOLD | DIM);
+        }
+        const OFF: [(u8, u8); 6] = [
+            (ITALIC, 23),
+            (UNDERLINE, 24),
+            (BLINK, 25),
+            (REVERSE, 27),
+            (HIDDEN, 28),
+            (STRIKETHROUGH, 29),
+        ];
+        for (attr, code) in OFF {
+            if removed & attr != 0 {
+                push(format_args!("{}", code));
+            }
+        }
+        let on = Style {
+            fg: None,
+            bg: None,
+            attrs: added,
+        };
+        let mut params = String::new();
+        on.write_prefix(&mut params)?;
+        if let Some(on) = params
+            .strip_prefix("\x1b[")
+            .and_then(|p| p.strip_suffix('m'))
+        {
+            push(format_args!("{}", on));
+        }
+        for (current, wanted, background) in [(self.fg, next.fg, false), (self.bg, next.bg, true)] {
+            if current != wanted {
+                let mut code = String::new();
+                match wanted {
+                    Some(color) => color.write_sgr(&mut code, background)?,
+                    None => code.push_str(if background { "49" } else { "39" }),
+                }
+                push(format_args!("{}", code));
+            }
+        }
+        let mut full = String::new();
+        next.write_prefix(&mut full)?;
+        if codes.len() + 3 <= full.len() + 2 {
+            write!(f, "\x1b[{}m", codes)
+        } else {
+            // Resetting inside the same sequence: ESC [ 0 ; ... m.
+            write!(f, "\x1b[0;{}", &full["\x1b[".len()..])
+        }
+    }
+
+    /// Returns the style as it is rendered under the global [`control`] settings: plain when
+    /// escape sequences are disabled, downgraded to the lower of the global color level and the
+    /// level of `backend` otherwise.
+    pub(crate) fn effective(&self, backend: &dyn ColorBackend) -> Self {
+        if !control::colors_enabled() {
+            Style::new()
+        } else {
+            self.downgrade(control::color_level().min(backend.color_level()))
+        }
+    }
+
+    /// Returns the escape sequence that switches the terminal to this style,
+    /// or an empty string for the plain style.
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// # if cfg!(feature = "no-color-build") { return; }
+    /// use bytescolor::{Color, Style};
+    ///
+    /// assert_eq!(Style::new().fg(Color::Red).bold().prefix(), "\x1b[1;31m");
+    /// assert_eq!(Style::new().prefix(), "");
+    /// ```
+    pub fn prefix(&self) -> String {
+        if cfg!(feature = "no-color-build") {
+            return String::new();
+        }
+        let backend = backend::backend();
+        let style = self.effective(backend);
+        let mut out = String::new();
+        if !style.is_plain() {
+            backend
+                .write_start(style, &mut out)
+                .expect("writing to a String cannot fail");
+        }
+        out
+    }
+
+    /// Returns the escape sequence that resets the terminal after this style,
+    /// or an empty string for the plain style.
+    pub fn suffix(&self) -> String {
+        if cfg!(feature = "no-color-build") {
+            return String::new();
+        }
+        let backend = backend::backend();
+        let style = self.effective(backend);
+        let mut out = String::new();
+        if !style.is_plain() {
+            backend
+                .write_end(style, &mut out)
+                .expect("writing to a String cannot fail");
+        }
+        out
+    }
+
+    /// Writes the SGR sequence selecting this style, used by the [`Ansi`](crate::backend::Ansi)
+    /// backend.
+    pub(crate) fn write_prefix(&self, f: &mut dyn fmt::Write) -> fmt::Result {
+        if self.is_plain() {
+            return Ok(());
+        }
+        f.write_str("\x1b[")?;
+        let mut first = true;
+        let mut sep = |f: &mut dyn fmt::Write| -> fmt::Result {
+            if !first {
+                f.write_char(';')?;
+            }
+            first = false;
+            Ok(())
+        };
+        const CODES: [(u8, u8); 8] = [
+            (BOLD, 1),
+            (DIM, 2),
+            (ITALIC, 3),
+            (UNDERLINE, 4),
+            (BLINK, 5),
+            (REVERSE, 7),
+            (HIDDEN, 8),
+            (STRIKETHROUGH, 9),
+        ];
+        for (attr, code) in CODES {
+            if self.attrs & attr != 0 {
+                sep(f)?;
+                write!(f, "{}", code)?;
+            }
+        }
+        if let Some(fg) = self.fg {
+            sep(f)?;
+            fg.write_sgr(f, false)?;
+        }
+        if let Some(bg) = self.bg {
+            sep(f)?;
+            bg.write_sgr(f, true)?;
+        }
+        f.write_char('m')
+    }
+
+    /// Returns this style updated by the parameters of an SGR sequence (`ESC [ params m`), the way a
+    /// terminal would. Unknown parameters are ignored.
+    pub(crate) fn apply_sgr(mut self, params: &[u16]) -> Style {
+        if params.is_empty() {
+            return Style::new();
+        }
+        let mut i = 0;
+        while i < params.len() {
+            match params[i] {
+                0 => self = Style::new(),
+                1 => self.attrs |= BOLD,
+                2 => self.attrs |= DIM,
+                3 => self.attrs |= ITALIC,
+                4 => self.attrs |= UNDERLINE,
+                5 | 6 => self.attrs |= BLINK,
+                7 => self.attrs |= REVERSE,
+                8 => self.attrs |= HIDDEN,
+                9 => self.attrs |= STRIKETHROUGH,
+                22 => self.attrs &= !(BOLD | DIM),
+                23 => self.attrs &= !ITALIC,
+                24 => self.attrs &= !UNDERLINE,
+                25 => self.attrs &= !BLINK,
+                27 => self.attrs &= !REVERSE,
+                28 => self.attrs &= !HIDDEN,
+                29 => self.attrs &= !STRIKETHROUGH,
+                code @ 30..=37 => self.fg = Some(Color::from_ansi_index((code - 30) as u8)),
+                code @ 40..=47 => self.bg = Some(Color::from_ansi_index((code - 40) as u8)),
+                code @ 90..=97 => self.fg = Some(Color::from_ansi_index((code - 90 + 8) as u8)),
+                code @ 100..=107 => self.bg = Some(Color::from_ansi_index((code - 100 + 8) as u8)),
+                39 => self.fg = None,
+                49 => self.bg = None,
+                code @ (38 | 48) => {
+                    let (color, used) = match params.get(i + 1) {
+                        Some(5) => (params.get(i + 2).map(|&n| Color::Fixed(n as u8)), 2),
+                        Some(2) => match params.get(i + 2..i + 5) {
+                            Some(&[r, g, b]) => (Some(Color::Rgb(r as u8, g as u8, b as u8)), 4),
+                            _ => (None, params.len()),
+                        },
+                        _ => (None, params.len()),
+                    };
+                    if code == 38 {
+                        self.fg = color.or(self.fg);
+                    } else {
+                        self.bg = color.or(self.bg);
+                    }
+                    i += used;
+                }
+                _ => {}
+            }
+            i += 1;
+        }
+        self
+    }
+
+    /// Renders `value` in this style, the functional counterpart of
+    /// [`ByteColor::styled`](crate::ByteColor::styled) that works for any `Display` value without
+    /// importing a trait.
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// # if cfg!(feature = "no-color-build") { return; }
+    /// use bytescolor::Style;
+    ///
+    /// assert_eq!(Style::BOLD.paint(42).as_str(), "\x1b[1m42\x1b[0m");
+    /// ```
+    pub fn paint(&self, value: impl fmt::Display) -> StyledString {
+        StyledString::from(self.wrap(value))
+    }
+
+    /// Wraps `content` in this style's prefix and suffix, or returns it unchanged when escape
+    /// sequences are disabled through [`control`].
+    pub(crate) fn wrap(&self, content: impl fmt::Display) -> String {
+        let mut out = String::new();
+        self.write_wrapped(&mut out, content)
+            .expect("writing to a String cannot fail");
+        out
+    }
+
+    /// Writes `content` wrapped in this style's prefix and suffix to `f`, without allocating.
+    pub(crate) fn write_wrapped(
+        &self,
+        f: &mut dyn fmt::Write,
+        content: impl fmt::Display,
+    ) -> fmt::Result {
+        if cfg!(feature = "no-color-build") {
+            return write!(f, "{}", content);
+        }
+        let backend = backend::backend();
+        let style = self.effective(backend);
+        if style.is_plain() {
+            return write!(f, "{}", content);
+        }
+        backend.write_start(style, f)?;
+        write!(f, "{}", content)?;
+        backend.write_end(style, f)
+    }
+}
+
+impl BitOr for Style {
+    type Output = Style;
+
+    /// Combines two styles: attributes of both apply, and colors of `rhs` override those of `self`.
+    fn bitor(self, rhs: Style) -> Style {
+        self.combine(rhs)
+    }
+}
+
+impl BitOr<Color> for Style {
+    type Output = Style;
+
+    /// Sets the foreground color.
+    fn bitor(self, rhs: Color) -> Style {
+        self.fg(rhs)
+    }
+}
+
+impl BitOr<Style> for Color {
+    type Output = Style;
+
+    /// Combines a foreground color with a style.
+    fn bitor(self, rhs: Style) -> Style {
+        Style::from(self).combine(rhs)
+    }
+}
+
+impl BitOrAssign for Style {
+    fn bitor_assign(&mut self, rhs: Style) {
+        *self = self.combine(rhs);
+    }
+}
+
+impl BitOrAssign<Color> for Style {
+    fn bitor_assign(&mut self, rhs: Color) {
+        *self = self.fg(rhs);
+    }
+}
+
+impl Add for Style {
+    type Output = Style;
+
+    /// Same as `|`.
+    fn add(self, rhs: Style) -> Style {
+        self.combine(rhs)
+    }
+}
+
+impl Add<Color> for Style {
+    type Output = Style;
+
+    /// Same as `|`.
+    fn add(self, rhs: Color) -> Style {
+        self.fg(rhs)
+    }
+}
+
+impl Add<Style> for Color {
+    type Output = Style;
+
+    /// Same as `|`.
+    fn add(self, rhs: Style) -> Style {
+        Style::from(self).combine(rhs)
+    }
+}
+
+impl AddAssign for Style {
+    fn add_assign(&mut self, rhs: Style) {
+        *self = self.combine(rhs);
+    }
+}
+
+impl From<Color> for Style {
+    fn from(color: Color) -> Self {
+        Style::new().fg(color)
+    }
+}
+
+/// The contrast ratio below which a foreground is hard to read on its background.
+pub(crate) const MIN_CONTRAST: f64 = 3.0;
+
+/// The contrast ratio below which a foreground nearly vanishes on a terminal background.
+const MIN_BACKGROUND_CONTRAST: f64 = 1.5;
+
+/// A problem making text in a style hard to read, see [`Style::validate`].
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub enum StyleIssue {
+    /// The text blinks, which is distracting and disabled by many terminals.
+    Blink,
+    /// The foreground and background colors contrast too little, with the given WCAG ratio (see
+    /// [`Color::contrast_ratio`]).
+    LowContrast { ratio: f64 },
+    /// Without a background of its own, the foreground nearly vanishes on dark (black) terminal
+    /// backgrounds.
+    InvisibleOnDark,
+    /// Without a background of its own, the foreground nearly vanishes on light (white) terminal
+    /// backgrounds.
+    InvisibleOnLight,
+}
+
+impl fmt::Display for StyleIssue {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            StyleIssue::Blink => f.write_str("blinking text"),
+            StyleIssue::LowContrast { ratio } => {
+                write!(f, "low contrast between colors ({:.1}:1)", ratio)
+            }
+            StyleIssue::InvisibleOnDark => {
+                f.write_str("foreground nearly invisible on dark backgrounds")
+            }
+            StyleIssue::InvisibleOnLight => {
+                f.write_str("foreground nearly invisible on light backgrounds")
+            }
+        }
+    }
+}
+
+/// The colors that have a name, by name.
+const COLOR_NAMES: [(&str, Color); 16] = [
+    ("black", Color::Black),
+    ("red", Color::Red),
+    ("green", Color::Green),
+    ("yellow", Color::Yellow),
+    ("blue", Color::Blue),
+    ("magenta", Color::Magenta),
+    ("cyan", Color::Cyan),
+    ("white", Color::White),
+    ("brightblack", Color::BrightBlack),
+    ("brightred", Color::BrightRed),
+    ("brightgreen", Color::BrightGreen),
+    ("brightyellow", Color::BrightYellow),
+    ("brightblue", Color::BrightBlue),
+    ("brightmagenta", Color::BrightMagenta),
+    ("brightcyan", Color::BrightCyan),
+    ("brightwhite", Color::BrightWhite),
+];
+
+/// Returns the snake case name of a standard color, as serialized by the `serde` feature.
+#[cfg(any(feature = "html", feature = "serde"))]
+pub(crate) fn color_name(color: Color) -> &'static str {
+    match color {
+        Color::Black => "black",
+        Color::Red => "red",
+        Color::Green => "green",
+        Color::Yellow => "yellow",
+        Color::Blue => "blue",
+        Color::Magenta => "magenta",
+        Color::Cyan => "cyan",
+        Color::White => "white",
+        Color::BrightBlack => "bright_black",
+        Color::BrightRed => "bright_red",
+        Color::BrightGreen => "bright_green",
+        Color::BrightYellow => "bright_yellow",
+        Color::BrightBlue => "bright_blue",
+        Color::BrightMagenta => "bright_magenta",
+        Color::BrightCyan => "bright_cyan",
+        Color::BrightWhite => "bright_white",
+        Color::Fixed(_) | Color::Rgb(..) => "",
+    }
+}
+
+/// The text attributes, by name.
+const ATTRIBUTE_NAMES: [(&str, Style); 8] = [
+    ("bold", Style::BOLD),
+    ("dim", Style::DIM),
+    ("italic", Style::ITALIC),
+    ("underline", Style::UNDERLINE),
+    ("blink", Style::BLINK),
+    ("reverse", Style::REVERSE),
+    ("hidden", Style::HIDDEN),
+    ("strikethrough", Style::STRIKETHROUGH),
+];
+
+/// An error describing why a color or style description could not be parsed.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct ParseStyleError {
+    message: &'static str,
+    word: String,
+}
+
+impl ParseStyleError {
+    fn new(message: &'static str, word: &str) -> Self {
+        ParseStyleError {
+            message,
+            word: word.to_string(),
+        }
+    }
+
+    /// Returns the word of the description that could not be parsed, empty if one was missing.
+    pub fn word(&self) -> &str {
+        &self.word
+    }
+}
+
+impl fmt::Display for ParseStyleError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        if self.word.is_empty() {
+            f.write_str(self.message)
+        } else {
+            write!(f, "{} '{}'", self.message, self.word)
+        }
+    }
+}
+
+impl std::error::Error for ParseStyleError {}
+
+impl std::str::FromStr for Color {
+    type Err = ParseStyleError;
+
+    /// Parses a color name such as `red` or `bright_blue` (case, `_` and `-` are ignored), a palette
+    /// index from `0` to `255`, or a `#rrggbb` hex value.
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// use bytescolor::Color;
+    ///
+    /// assert_eq!("bright-blue".parse(), Ok(Color::BrightBlue));
+    /// assert_eq!("208".parse(), Ok(Color::Fixed(208)));
+    /// assert_eq!("#ff8800".parse(), Ok(Color::Rgb(255, 136, 0)));
+    /// assert!("purple".parse::<Color>().is_err());
+    /// ```
+    fn from_str(text: &str) -> Result<Self, Self::Err> {
+        let error = || ParseStyleError::new("unknown color", text);
+        if let Some(hex) = text.strip_prefix('#') {
+            if hex.len() != 6 || !hex.bytes().all(|b| b.is_ascii_hexdigit()) {
+                return Err(error());
+            }
+            let channel = |at: usize| u8::from_str_radix(&hex[at..at + 2], 16).map_err(|_| error());
+            return Ok(Color::Rgb(channel(0)?, channel(2)?, channel(4)?));
+        }
+        if text.bytes().all(|b| b.is_ascii_digit()) && !text.is_empty() {
+            return text.parse().map(Color::Fixed).map_err(|_| error());
+        }
+        let name: String = text
+            .chars()
+            .filter(|&c| c != '_' && c != '-')
+            .map(|c| c.to_ascii_lowercase())
+            .collect();
+        let name = match name.as_str() {
+            "gray" | "grey" => "brightblack",
+            name => name,
+        };
+        COLOR_NAMES
+            .iter()
+            .find(|(known, _)| *known == name)
+            .map(|&(_, color)| color)
+            .ok_or_else(error)
+    }
+}
+
+impl std::str::FromStr for Style {
+    type Err = ParseStyleError;
+
+    /// Parses a style described by words separated by whitespace: attribute names (`bold`, `dim`,
+    /// `italic`, `underline`, `blink`, `reverse`, `hidden`, `strikethrough`), a foreground color, and a
+    /// background color after `on`. Colors are written as accepted by [`Color`]'s `FromStr`. `none`
+    /// describes the plain style.
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// use bytescolor::{Color, Style};
+    ///
+    /// let style: Style = "bold red on #202020".parse().unwrap();
+    /// assert_eq!(style, Style::new().bold().fg(Color::Red).bg(Color::Rgb(32, 32, 32)));
+    /// assert_eq!("none".parse(), Ok(Style::new()));
+    /// assert_eq!("bold on".parse::<Style>().unwrap_err().to_string(), "missing color after 'on'");
+    /// ```
+    fn from_str(text: &str) -> Result<Self, Self::Err> {
+        let mut style = Style::new();
+        let mut words = text.split_whitespace();
+        while let Some(word) = words.next() {
+            let lower = word.to_ascii_lowercase();
+            if lower == "none" {
+                continue;
+            }
+            if lower == "on" {
+                let color = words
+                    .next()
+                    .ok_or_else(|| ParseStyleError::new("missing color after 'on'", ""))?;
+                style = style.bg(color.parse()?);
+            } else if let Some(&(_, attr)) = ATTRIBUTE_NAMES.iter().find(|(name, _)| *name == lower)
+            {
+                style |= attr;
+            } else {
+                let color = word
+                    .parse()
+                    .map_err(|_| ParseStyleError::new("unknown color or attribute", word))?;
+                style = style.fg(color);
+            }
+        }
+        Ok(style)
+    }
+}
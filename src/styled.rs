@@ -0,0 +1,363 @@
+//! # Styled Strings
+//!
+//! [`StyledString`] is what the [`ByteColor`](crate::ByteColor) methods return: the rendered text,
+//! escape sequences included. It prints and dereferences like the underlying `String`, but compares
+//! against plain text by visible content only, which keeps assertions in tests readable. Format flags
+//! such as `{:>10}` and `{:.3}` apply to the visible content, so existing format strings keep aligning
+//! when colors are added.
+//!
+//! ```rust
+//! # if cfg!(feature = "no-color-build") { return; }
+//! use bytescolor::ByteColor;
+//!
+//! let error = "error".red();
+//! assert_eq!(error, "error");
+//! assert_eq!(error.as_str(), "\x1b[31merror\x1b[0m");
+//! println!("{}", error); // Displays "error" in red
+//! ```
+
+use std::borrow::Cow;
+use std::collections::hash_map::DefaultHasher;
+use std::fmt;
+use std::hash::{Hash, Hasher};
+use std::ops::{Add, AddAssign, Deref};
+
+use crate::ansi;
+use crate::control::ColorLevel;
+
+/// A rendered string that may contain escape sequences.
+///
+/// `Display`, `Deref<Target = str>` and [`as_str`](StyledString::as_str) expose the raw text with its
+/// escape sequences. Comparisons with `str`, `&str` and `String` ignore the escape sequences, while two
+/// `StyledString`s are equal only if their raw text is.
+#[derive(Debug, Clone, PartialEq, Eq, PartialOrd, Ord, Hash, Default)]
+pub struct StyledString {
+    raw: String,
+}
+
+impl StyledString {
+    /// Wraps already rendered text.
+    pub fn new(raw: impl Into<String>) -> Self {
+        StyledString { raw: raw.into() }
+    }
+
+    /// Returns the raw text, escape sequences included.
+    pub fn as_str(&self) -> &str {
+        &self.raw
+    }
+
+    /// Returns the text without its escape sequences.
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// use bytescolor::ByteColor;
+    ///
+    /// assert_eq!("ok".green().visible(), "ok");
+    /// ```
+    pub fn visible(&self) -> Cow<'_, str> {
+        ansi::strip(&self.raw)
+    }
+
+    /// Returns the number of columns the text occupies on the terminal.
+    pub fn visible_width(&self) -> usize {
+        ansi::visible_width(&self.raw)
+    }
+
+    /// Returns `true` if `other` shows the same text, whatever the escape sequences of either.
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// # if cfg!(feature = "no-color-build") { return; }
+    /// use bytescolor::ByteColor;
+    ///
+    /// assert!("ok".green().eq_visible("ok".bold()));
+    /// assert!("ok".green().eq_visible("ok"));
+    /// assert_ne!("ok".green(), "ok".bold());
+    /// ```
+    pub fn eq_visible(&self, other: impl AsRef<str>) -> bool {
+        self.visible() == ansi::strip(other.as_ref())
+    }
+
+    /// Returns a hash of the visible text, equal for values that are [`eq_visible`](Self::eq_visible)
+    /// and to the [`StyledText::hash_visible`](crate::StyledText::hash_visible) of the same text, for
+    /// deduplicating styled values by content.
+    ///
+    /// The hash is stable within a build of the program but not across Rust versions, so it should not
+    /// be persisted.
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// use std::collections::HashSet;
+    /// use bytescolor::ByteColor;
+    ///
+    /// let lines = ["warn".yellow(), "warn".bold(), "ok".green()];
+    /// let mut seen = HashSet::new();
+    /// let unique: Vec<_> = lines.iter().filter(|line| seen.insert(line.hash_visible())).collect();
+    /// assert_eq!(unique.len(), 2);
+    /// ```
+    pub fn hash_visible(&self) -> u64 {
+        hash_visible(&self.visible())
+    }
+
+    /// Returns the text with `prefix` prepended to every line, empty lines included. Styles active
+    /// across a line break are restored after the prefix, so inner colors are preserved; see
+    /// [`StyledText::prefix_lines`](crate::StyledText::prefix_lines).
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// use bytescolor::ByteColor;
+    ///
+    /// let output = "line one\nline two".green();
+    /// let quoted = output.prefix_lines("│ ".blue());
+    /// assert_eq!(quoted, "│ line one\n│ line two");
+    /// ```
+    pub fn prefix_lines(&self, prefix: impl AsRef<str>) -> StyledString {
+        let text = crate::StyledText::from_ansi(&self.raw);
+        let prefix = crate::StyledText::from_ansi(prefix.as_ref());
+        StyledString::from(text.prefix_lines(prefix).render())
+    }
+
+    /// Returns the text with every non-empty line indented by `n` spaces; see
+    /// [`StyledText::indent`](crate::StyledText::indent).
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// use bytescolor::ByteColor;
+    ///
+    /// assert_eq!("a\nb".red().indent(4), "    a\n    b");
+    /// ```
+    pub fn indent(&self, n: usize) -> StyledString {
+        StyledString::from(crate::StyledText::from_ansi(&self.raw).indent(n).render())
+    }
+
+    /// Splits the text on the visible occurrences of `pattern`, each piece closing the styles it uses
+    /// and reopening those active at its start, so colors do not leak from one piece to the next; see
+    /// [`StyledText::split_visible`](crate::StyledText::split_visible).
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// # if cfg!(feature = "no-color-build") { return; }
+    /// use bytescolor::ByteColor;
+    ///
+    /// let fields = "id,name".yellow().split_visible(",");
+    /// assert_eq!(fields, ["id", "name"]);
+    /// assert_eq!(fields[1].as_str(), "\x1b[33mname\x1b[0m");
+    /// ```
+    pub fn split_visible(&self, pattern: &str) -> Vec<StyledString> {
+        crate::StyledText::from_ansi(&self.raw)
+            .split_visible(pattern)
+            .iter()
+            .map(|piece| StyledString::from(piece.render()))
+            .collect()
+    }
+
+    /// Splits the text into lines, each styled on its own; see
+    /// [`StyledText::lines_styled`](crate::StyledText::lines_styled).
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// # if cfg!(feature = "no-color-build") { return; }
+    /// use bytescolor::ByteColor;
+    ///
+    /// let lines = "first\nsecond".red().lines_styled();
+    /// assert_eq!(lines[0].as_str(), "\x1b[31mfirst\x1b[0m");
+    /// assert_eq!(lines[1].as_str(), "\x1b[31msecond\x1b[0m");
+    /// ```
+    pub fn lines_styled(&self) -> Vec<StyledString> {
+        crate::StyledText::from_ansi(&self.raw)
+            .lines_styled()
+            .iter()
+            .map(|line| StyledString::from(line.render()))
+            .collect()
+    }
+
+    /// Returns the text shortened to at most `max` columns, its middle replaced with a dimmed `…`; see
+    /// [`StyledText::truncate_middle_visible`](crate::StyledText::truncate_middle_visible).
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// use bytescolor::{ansi, StyledString};
+    ///
+    /// let url = StyledString::from("https://example.com/docs/getting-started");
+    /// let short = url.truncate_middle_visible(24);
+    /// assert_eq!(short, "https://exa…ting-started");
+    /// assert_eq!(ansi::visible_width(&short), 24);
+    /// ```
+    pub fn truncate_middle_visible(&self, max: usize) -> StyledString {
+        let text = crate::StyledText::from_ansi(&self.raw);
+        StyledString::from(text.truncate_middle_visible(max).render())
+    }
+
+    /// Renders the text again for a target supporting colors up to `level`, downgrading the colors
+    /// it uses; see [`StyledText::render_for`](crate::StyledText::render_for). Text rendered while
+    /// escape sequences were disabled has no colors to convert.
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// # if cfg!(feature = "no-color-build") { return; }
+    /// use bytescolor::control::ColorLevel;
+    /// use bytescolor::ByteColor;
+    ///
+    /// let status = "ready".rgb((0, 215, 95));
+    /// assert_eq!(status.render_for(ColorLevel::Ansi256).as_str(), "\x1b[38;5;41mready\x1b[0m");
+    /// ```
+    pub fn render_for(&self, level: ColorLevel) -> StyledString {
+        StyledString::from(crate::StyledText::from_ansi(&self.raw).render_for(level))
+    }
+
+    /// Returns the raw text as a `String`.
+    pub fn into_string(self) -> String {
+        self.raw
+    }
+}
+
+impl fmt::Display for StyledString {
+    /// Writes the raw text. Width, alignment and precision flags apply to the visible content, with
+    /// padding placed outside the escape sequences.
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// # if cfg!(feature = "no-color-build") { return; }
+    /// use bytescolor::ByteColor;
+    ///
+    /// assert_eq!(format!("[{:>5}]", "ok".green()), "[   \x1b[32mok\x1b[0m]");
+    /// assert_eq!(format!("{:.3}", "error".red()), "\x1b[31merr\x1b[0m");
+    /// ```
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        ansi::pad(f, &self.raw)
+    }
+}
+
+impl Deref for StyledString {
+    type Target = str;
+
+    fn deref(&self) -> &str {
+        &self.raw
+    }
+}
+
+impl AsRef<str> for StyledString {
+    fn as_ref(&self) -> &str {
+        &self.raw
+    }
+}
+
+impl From<String> for StyledString {
+    fn from(raw: String) -> Self {
+        StyledString { raw }
+    }
+}
+
+impl From<&str> for StyledString {
+    fn from(raw: &str) -> Self {
+        StyledString::new(raw)
+    }
+}
+
+impl From<StyledString> for String {
+    fn from(styled: StyledString) -> Self {
+        styled.raw
+    }
+}
+
+/// Appends text, styled or not. Each styled fragment resets its own style, so styles stay intact
+/// whatever is appended.
+///
+/// # Examples
+///
+/// ```rust
+/// # if cfg!(feature = "no-color-build") { return; }
+/// use bytescolor::ByteColor;
+///
+/// let mut status = "ok".green() + " " + "3 passed".bold();
+/// status += ", 0 failed";
+/// assert_eq!(status, "ok 3 passed, 0 failed");
+/// assert!(status.as_str().starts_with("\x1b[32mok\x1b[0m "));
+/// ```
+impl<T: AsRef<str>> Add<T> for StyledString {
+    type Output = StyledString;
+
+    fn add(mut self, other: T) -> StyledString {
+        self.raw.push_str(other.as_ref());
+        self
+    }
+}
+
+impl<T: AsRef<str>> AddAssign<T> for StyledString {
+    fn add_assign(&mut self, other: T) {
+        self.raw.push_str(other.as_ref());
+    }
+}
+
+/// Concatenates fragments.
+///
+/// # Examples
+///
+/// ```rust
+/// use bytescolor::{ByteColor, StyledString};
+///
+/// let bar: StyledString = (0..4).map(|i| if i < 3 { "█".green() } else { "░".blue() }).collect();
+/// assert_eq!(bar, "███░");
+/// ```
+impl<T: AsRef<str>> FromIterator<T> for StyledString {
+    fn from_iter<I: IntoIterator<Item = T>>(fragments: I) -> Self {
+        let mut raw = String::new();
+        for fragment in fragments {
+            raw.push_str(fragment.as_ref());
+        }
+        StyledString { raw }
+    }
+}
+
+/// Hashes visible text to the value returned by the `hash_visible` methods.
+pub(crate) fn hash_visible(text: &str) -> u64 {
+    let mut hasher = DefaultHasher::new();
+    text.hash(&mut hasher);
+    hasher.finish()
+}
+
+impl PartialEq<str> for StyledString {
+    fn eq(&self, other: &str) -> bool {
+        self.visible() == other
+    }
+}
+
+impl PartialEq<&str> for StyledString {
+    fn eq(&self, other: &&str) -> bool {
+        self.visible() == *other
+    }
+}
+
+impl PartialEq<String> for StyledString {
+    fn eq(&self, other: &String) -> bool {
+        self.visible() == other.as_str()
+    }
+}
+
+impl PartialEq<StyledString> for str {
+    fn eq(&self, other: &StyledString) -> bool {
+        other == self
+    }
+}
+
+impl PartialEq<StyledString> for &str {
+    fn eq(&self, other: &StyledString) -> bool {
+        other == *self
+    }
+}
+
+impl PartialEq<StyledString> for String {
+    fn eq(&self, other: &StyledString) -> bool {
+        other == self
+    }
+}
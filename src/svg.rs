@@ -0,0 +1,229 @@
+//! # SVG Screenshots
+//!
+//! Renders styled output as an SVG image of a terminal: a monospace grid of colored text on a dark
+//! background, for shareable screenshots in documentation and bug reports. Any text containing ANSI
+//! escape sequences can be rendered, including the captured output of other programs.
+//!
+//! ```rust
+//! use bytescolor::svg::Svg;
+//! use bytescolor::ByteColor;
+//!
+//! let output = format!("{} build finished\n{} 2 warnings", "✔".green(), "!".yellow());
+//! let image = Svg::new().render(&output);
+//! assert!(image.starts_with("<svg"));
+//! // std::fs::write("screenshot.svg", image)?;
+//! ```
+//!
+//! Colors are written as RGB values, palette colors resolved to the xterm defaults. Every span is
+//! stretched to its exact cell width, so columns line up whatever monospace font the viewer picks; with
+//! the `unicode-width` feature, wide characters take two cells as in a terminal.
+//!
+//! This module is available with the `html` feature, enabled by default.
+
+use std::fmt::Write;
+
+use crate::markup::{escape_html, hex};
+use crate::{Color, Span, Style, StyledText};
+
+/// An SVG renderer for styled text.
+///
+/// # Examples
+///
+/// ```rust
+/// use bytescolor::svg::Svg;
+/// use bytescolor::{Color, Style, StyledText};
+///
+/// let text = StyledText::styled("ok", Style::new().fg(Color::Green).bold());
+/// let image = Svg::new().font_size(10).padding(0).render_text(&text);
+/// assert!(image.contains(r##"<text x="0" y="10" fill="#00cd00" font-weight="bold" textLength="12" lengthAdjust="spacingAndGlyphs">ok</text>"##));
+/// ```
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub struct Svg {
+    font_size: u16,
+    padding: u16,
+    columns: Option<usize>,
+    background: (u8, u8, u8),
+    foreground: (u8, u8, u8),
+}
+
+impl Default for Svg {
+    fn default() -> Self {
+        Svg {
+            font_size: 14,
+            padding: 16,
+            columns: None,
+            background: (30, 30, 30),
+            foreground: (212, 212, 212),
+        }
+    }
+}
+
+impl Svg {
+    /// Creates a renderer with a 14px font, 16px of padding and light gray text on a dark background.
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// Sets the font size in pixels. Cells are 0.6 times as wide and 1.25 times as high.
+    pub fn font_size(mut self, font_size: u16) -> Self {
+        self.font_size = font_size;
+        self
+    }
+
+    /// Sets the space in pixels between the text and the edges of the image.
+    pub fn padding(mut self, padding: u16) -> Self {
+        self.padding = padding;
+        self
+    }
+
+    /// Sets the width of the grid in columns, instead of fitting the longest line.
+    pub fn columns(mut self, columns: usize) -> Self {
+        self.columns = Some(columns);
+        self
+    }
+
+    /// Sets the background color of the image.
+    pub fn background(mut self, color: Color) -> Self {
+        self.background = color.to_rgb();
+        self
+    }
+
+    /// Sets the color of text without a foreground color.
+    pub fn foreground(mut self, color: Color) -> Self {
+        self.foreground = color.to_rgb();
+        self
+    }
+
+    /// Renders text containing ANSI escape sequences, see [`StyledText::from_ansi`].
+    pub fn render(&self, text: &str) -> String {
+        self.render_text(&StyledText::from_ansi(text))
+    }
+
+    /// Renders a styled document.
+    pub fn render_text(&self, text: &StyledText) -> String {
+        let lines = split_lines(text);
+        let font_size = f64::from(self.font_size);
+        let (cell_width, cell_height) = (font_size * 0.6, font_size * 1.25);
+        let padding = f64::from(self.padding);
+        let columns = self.columns.unwrap_or_else(|| {
+            lines
+                .iter()
+                .map(|line| line.iter().map(Span::width).sum())
+                .max()
+                .unwrap_or(0)
+        });
+        let width = 2.0 * padding + columns as f64 * cell_width;
+        let height = 2.0 * padding + lines.len() as f64 * cell_height;
+
+        let mut out = String::new();
+        let _ = writeln!(
+            out,
+            r#"<svg xmlns="http://www.w3.org/2000/svg" width="{w}" height="{h}" viewBox="0 0 {w} {h}" font-family="ui-monospace, Menlo, Consolas, monospace" font-size="{}" xml:space="preserve">"#,
+            self.font_size,
+            w = number(width),
+            h = number(height),
+        );
+        let _ = writeln!(
+            out,
+            r#"<rect width="100%" height="100%" fill="{}"/>"#,
+            hex(self.background)
+        );
+        for (row, line) in lines.iter().enumerate() {
+            let top = padding + row as f64 * cell_height;
+            let baseline = top + font_size;
+            let mut column = 0;
+            for span in line {
+                let len = span.width();
+                let x = padding + column as f64 * cell_width;
+                let span_width = len as f64 * cell_width;
+                column += len;
+                let style = span.style();
+                let (mut fg, mut bg) = (
+                    style.foreground().map_or(self.foreground, Color::to_rgb),
+                    style.background().map(Color::to_rgb),
+                );
+                if style.is_reverse() {
+                    let swapped = bg.unwrap_or(self.background);
+                    bg = Some(fg);
+                    fg = swapped;
+                }
+                if let Some(bg) = bg {
+                    let _ = writeln!(
+                        out,
+                        r#"<rect x="{}" y="{}" width="{}" height="{}" fill="{}"/>"#,
+                        number(x),
+                        number(top),
+                        number(span_width),
+                        number(cell_height),
+                        hex(bg)
+                    );
+                }
+                if style.is_hidden() || span.text().trim().is_empty() {
+                    continue;
+                }
+                let _ = write!(
+                    out,
+                    r#"<text x="{}" y="{}" fill="{}""#,
+                    number(x),
+                    number(baseline),
+                    hex(fg)
+                );
+                push_attributes(&mut out, style);
+                let _ = write!(
+                    out,
+                    r#" textLength="{}" lengthAdjust="spacingAndGlyphs">"#,
+                    number(span_width)
+                );
+                escape_html(&mut out, span.text());
+                out.push_str("</text>\n");
+            }
+        }
+        out.push_str("</svg>\n");
+        out
+    }
+}
+
+fn push_attributes(out: &mut String, style: Style) {
+    if style.is_bold() {
+        out.push_str(r#" font-weight="bold""#);
+    }
+    if style.is_italic() {
+        out.push_str(r#" font-style="italic""#);
+    }
+    if style.is_dim() {
+        out.push_str(r#" fill-opacity="0.6""#);
+    }
+    match (style.is_underline(), style.is_strikethrough()) {
+        (true, true) => out.push_str(r#" text-decoration="underline line-through""#),
+        (true, false) => out.push_str(r#" text-decoration="underline""#),
+        (false, true) => out.push_str(r#" text-decoration="line-through""#),
+        (false, false) => {}
+    }
+}
+
+/// Splits a document into lines of spans, dropping the line terminators.
+fn split_lines(text: &StyledText) -> Vec<Vec<Span>> {
+    let mut lines = vec![Vec::new()];
+    for span in text.spans() {
+        for (index, part) in span.text().split('\n').enumerate() {
+            if index > 0 {
+                lines.push(Vec::new());
+            }
+            let part = part.strip_suffix('\r').unwrap_or(part);
+            if !part.is_empty() {
+                let line = lines.last_mut().expect("there is always a line");
+                line.push(Span::new(part, span.style()));
+            }
+        }
+    }
+    if lines.len() > 1 && lines.last().is_some_and(Vec::is_empty) {
+        lines.pop();
+    }
+    lines
+}
+
+/// Formats a pixel value without trailing zeros.
+fn number(value: f64) -> String {
+    let text = format!("{:.2}", value);
+    text.trim_end_matches('0').trim_end_matches('.').to_string()
+}
@@ -0,0 +1,743 @@
+//! # Tables
+//!
+//! [`Table`] lays out rows of cells under a bold header, each column as wide as its widest cell. Cells
+//! may already be styled: widths are measured on the visible content.
+//!
+//! Numeric columns, created with [`Column::numeric`], align their values on the decimal point, or on
+//! the last digit of integers so units line up, and color each value by the [threshold
+//! zone](Column::threshold) it falls in, so metric tables read like reports:
+//!
+//! ```rust
+//! use bytescolor::table::{Column, Table};
+//! use bytescolor::{ansi, Style};
+//!
+//! let table = Table::new()
+//!     .column(Column::text("endpoint"))
+//!     .column(
+//!         Column::numeric("p99 (ms)")
+//!             .threshold(100.0, Style::YELLOW)
+//!             .threshold(500.0, Style::RED),
+//!     )
+//!     .row(["/health", "1.25"])
+//!     .row(["/search", "812.5"])
+//!     .row(["/upload", "140"]);
+//! assert_eq!(
+//!     ansi::strip(&table.render()),
+//!     "endpoint  p99 (ms)\n/health       1.25\n/search     812.5\n/upload     140"
+//! );
+//! ```
+//!
+//! This module is available with the `widgets` feature, enabled by default.
+
+use std::fmt;
+
+use crate::ansi::{self, visible_width};
+use crate::{Style, StyledText};
+
+/// A column of a [`Table`]: its title and how its cells are laid out.
+#[derive(Debug, Clone, PartialEq)]
+pub struct Column {
+    title: String,
+    numeric: bool,
+    thresholds: Vec<(f64, Style)>,
+    max_width: Option<usize>,
+}
+
+impl Column {
+    /// Creates a column of left-aligned text titled `title`.
+    pub fn text(title: impl fmt::Display) -> Self {
+        Column {
+            title: title.to_string(),
+            numeric: false,
+            thresholds: Vec::new(),
+            max_width: None,
+        }
+    }
+
+    /// Creates a column of numbers titled `title`, right-aligned and aligned on the decimal point.
+    /// Values may be followed by a unit (`12ms`) and grouped with `,` or `_`; cells that are not
+    /// numbers are right-aligned with the integer parts.
+    pub fn numeric(title: impl fmt::Display) -> Self {
+        Column {
+            numeric: true,
+            ..Column::text(title)
+        }
+    }
+
+    /// Styles the plain cells of a numeric column whose value is at least `from` with `style`, up to
+    /// the next threshold. Values below every threshold are left plain.
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// use bytescolor::table::{Column, Table};
+    /// use bytescolor::Style;
+    ///
+    /// let table = Table::new()
+    ///     .column(Column::numeric("free %").threshold(0.0, Style::RED).threshold(20.0, Style::GREEN))
+    ///     .row(["5.5"])
+    ///     .row(["64"]);
+    /// let lines: Vec<_> = table.render().lines().map(str::to_string).collect();
+    /// assert_eq!(lines[1], format!("   {}", Style::RED.paint("5.5")));
+    /// assert_eq!(lines[2], format!("  {}", Style::GREEN.paint("64")));
+    /// ```
+    pub fn threshold(mut self, from: f64, style: Style) -> Self {
+        self.thresholds.push((from, style));
+        self.thresholds.sort_by(|(a, _), (b, _)| a.total_cmp(b));
+        self
+    }
+
+    /// Word-wraps the cells of a text column wider than `width` columns, keeping their styles, so long
+    /// descriptions make their rows taller instead of the table wider. Words wider than `width` are
+    /// broken. Numeric columns are never wrapped.
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// use bytescolor::table::{Column, Table};
+    /// use bytescolor::ansi;
+    ///
+    /// let table = Table::new()
+    ///     .column(Column::text("flag"))
+    ///     .column(Column::text("description").max_width(16))
+    ///     .row(["--jobs", "number of parallel jobs to run"]);
+    /// assert_eq!(
+    ///     ansi::strip(&table.render()),
+    ///     "flag    description\n--jobs  number of\n        parallel jobs to\n        run"
+    /// );
+    /// ```
+    pub fn max_width(mut self, width: usize) -> Self {
+        self.max_width = Some(width);
+        self
+    }
+
+    /// Returns the title of the column.
+    pub fn title(&self) -> &str {
+        &self.title
+    }
+
+    /// Returns the style of a cell, from the threshold zone of its value.
+    fn cell_style(&self, cell: &str) -> Style {
+        if !self.numeric || cell.contains('\x1b') {
+            return Style::new();
+        }
+        let value = match parse_number(cell) {
+            Some(value) => value,
+            None => return Style::new(),
+        };
+        self.thresholds
+            .iter()
+            .rev()
+            .find(|&&(from, _)| value >= from)
+            .map_or(Style::new(), |&(_, style)| style)
+    }
+}
+
+/// A cell of a [`Table`], spanning one column or more.
+///
+/// Any `Display` value converts into a cell spanning one column.
+#[derive(Debug, Clone, PartialEq, Eq, Hash)]
+pub struct Cell {
+    text: String,
+    span: usize,
+}
+
+impl Cell {
+    /// Creates a cell showing `text`, spanning one column.
+    pub fn new(text: impl fmt::Display) -> Self {
+        Cell {
+            text: text.to_string(),
+            span: 1,
+        }
+    }
+
+    /// Makes the cell span `columns` columns, at least one. A spanning cell is laid out as
+    /// left-aligned text, wrapped at the width of its columns when one of them has a
+    /// [maximum width](Column::max_width), and widening the last of them otherwise.
+    pub fn span(mut self, columns: usize) -> Self {
+        self.span = columns.max(1);
+        self
+    }
+
+    /// Returns the text of the cell.
+    pub fn text(&self) -> &str {
+        &self.text
+    }
+
+    /// Returns the number of columns the cell spans.
+    pub fn columns(&self) -> usize {
+        self.span
+    }
+}
+
+impl<T: fmt::Display> From<T> for Cell {
+    fn from(text: T) -> Self {
+        Cell::new(text)
+    }
+}
+
+/// Rows of cells laid out in columns.
+///
+/// Rows may have more cells than there are columns, the extra cells going to untitled text columns,
+/// or fewer, the missing cells being left empty. Cells holding several lines, or wrapped at the
+/// [maximum width](Column::max_width) of their column, make their row as tall as needed.
+///
+/// # Examples
+///
+/// ```rust
+/// use bytescolor::table::{Column, Table};
+/// use bytescolor::{ansi, ByteColor};
+///
+/// let mut table = Table::new().column(Column::text("name")).column(Column::text("status"));
+/// table.push_row(["api", &"up".green()]);
+/// table.push_row(["worker", &"down".red()]);
+/// assert_eq!(ansi::strip(&table.render()), "name    status\napi     up\nworker  down");
+/// ```
+///
+/// Cells can span several columns:
+///
+/// ```rust
+/// use bytescolor::table::{Cell, Column, Table};
+/// use bytescolor::ansi;
+///
+/// let table = Table::new()
+///     .column(Column::text("test"))
+///     .column(Column::numeric("ms"))
+///     .row(["parse", "12"])
+///     .row([Cell::new("skipped: requires network").span(2)]);
+/// assert_eq!(
+///     ansi::strip(&table.render()),
+///     "test                   ms\nparse                  12\nskipped: requires network"
+/// );
+/// ```
+#[derive(Debug, Clone, PartialEq)]
+pub struct Table {
+    columns: Vec<Column>,
+    rows: Vec<Vec<Cell>>,
+    header_style: Style,
+    spacing: usize,
+}
+
+impl Default for Table {
+    fn default() -> Self {
+        Table {
+            columns: Vec::new(),
+            rows: Vec::new(),
+            header_style: Style::new().bold(),
+            spacing: 2,
+        }
+    }
+}
+
+impl Table {
+    /// Creates a table without columns or rows, with a bold header and columns two spaces apart.
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// Appends a column.
+    pub fn column(mut self, column: Column) -> Self {
+        self.columns.push(column);
+        self
+    }
+
+    /// Appends a row of cells.
+    pub fn row<I, T>(mut self, cells: I) -> Self
+    where
+        I: IntoIterator<Item = T>,
+        T: Into<Cell>,
+    {
+        self.push_row(cells);
+        self
+    }
+
+    /// Appends a row of cells, for tables built in a loop.
+    pub fn push_row<I, T>(&mut self, cells: I)
+    where
+        I: IntoIterator<Item = T>,
+        T: Into<Cell>,
+    {
+        self.rows.push(cells.into_iter().map(Into::into).collect());
+    }
+
+    /// Sets the style of the column titles.
+    pub fn header_style(mut self, style: Style) -> Self {
+        self.header_style = style;
+        self
+    }
+
+    /// Sets the number of spaces between columns.
+    pub fn spacing(mut self, spacing: usize) -> Self {
+        self.spacing = spacing;
+        self
+    }
+
+    /// Returns the columns of the table.
+    pub fn columns(&self) -> &[Column] {
+        &self.columns
+    }
+
+    /// Returns the rows of the table.
+    pub fn rows(&self) -> &[Vec<Cell>] {
+        &self.rows
+    }
+
+    /// Renders the table, one row per line, without trailing whitespace or a final newline. The header
+    /// is left out when no column has a title.
+    ///
+    /// Cells holding several lines make their row as tall as the tallest of them; each line of a
+    /// numeric cell is aligned on its own:
+    ///
+    /// ```rust
+    /// use bytescolor::table::{Column, Table};
+    /// use bytescolor::ansi;
+    ///
+    /// let table = Table::new().column(Column::numeric("n")).row(["1\n2"]).row(["3.5"]);
+    /// assert_eq!(ansi::strip(&table.render()), "  n\n1\n2\n3.5");
+    /// ```
+    pub fn render(&self) -> String {
+        let placed = self.placed();
+        let count = self.count(&placed);
+        let untitled = Column::text("");
+        let columns: Vec<&Column> = (0..count)
+            .map(|index| self.columns.get(index).unwrap_or(&untitled))
+            .collect();
+        let mut layouts: Vec<Layout> = columns
+            .iter()
+            .enumerate()
+            .map(|(index, column)| {
+                let cells = placed
+                    .iter()
+                    .flatten()
+                    .filter(|&&(start, cell)| start == index && cell.span == 1)
+                    .map(|&(_, cell)| cell.text.as_str());
+                Layout::new(column, cells)
+            })
+            .collect();
+        for &(start, cell) in placed.iter().flatten().filter(|(_, cell)| cell.span > 1) {
+            let spanned = start..start + cell.span;
+            if columns[spanned.clone()]
+                .iter()
+                .any(|column| column.max_width.is_some())
+            {
+                continue;
+            }
+            let needed = lines_width(&wrap(&cell.text, None));
+            let available = self.area(&layouts[spanned]);
+            if needed > available {
+                layouts[start + cell.span - 1].width += needed - available;
+            }
+        }
+
+        let mut lines = Vec::new();
+        if columns.iter().any(|column| !column.title.is_empty()) {
+            let titles = columns.iter().zip(&layouts).map(|(column, layout)| {
+                let title = self.header_style.wrap(&column.title);
+                let padding = layout.width.saturating_sub(visible_width(&column.title));
+                if column.numeric {
+                    format!("{}{}", " ".repeat(padding), title)
+                } else {
+                    format!("{}{}", title, " ".repeat(padding))
+                }
+            });
+            lines.push(self.join(titles));
+        }
+        for row in &placed {
+            // The start, span and lines of each cell, with empty cells completing short rows.
+            let mut cells: Vec<(usize, usize, Vec<String>)> = Vec::new();
+            for &(start, cell) in row {
+                let spanned = &layouts[start..start + cell.span];
+                let column = columns[start];
+                let cell_lines = if cell.span == 1 && column.numeric {
+                    wrap(&cell.text, None)
+                        .into_iter()
+                        .map(|line| column.cell_style(&line).wrap(&line))
+                        .collect()
+                } else if cell.span == 1 {
+                    wrap(&cell.text, column.max_width)
+                } else if spanned.iter().any(|layout| layout.wraps) {
+                    wrap(&cell.text, Some(self.area(spanned)))
+                } else {
+                    wrap(&cell.text, None)
+                };
+                cells.push((start, cell.span, cell_lines));
+            }
+            let filled = row.last().map_or(0, |&(start, cell)| start + cell.span);
+            cells.extend((filled..count).map(|start| (start, 1, Vec::new())));
+            let height = cells
+                .iter()
+                .map(|(_, _, lines)| lines.len())
+                .max()
+                .unwrap_or(1);
+            for line in 0..height.max(1) {
+                let segments = cells.iter().map(|(start, span, cell_lines)| {
+                    let text = cell_lines.get(line).map_or("", String::as_str);
+                    if *span == 1 {
+                        layouts[*start].pad(text)
+                    } else {
+                        let area = self.area(&layouts[*start..*start + *span]);
+                        let padding = area.saturating_sub(visible_width(text));
+                        format!("{}{}", text, " ".repeat(padding))
+                    }
+                });
+                lines.push(self.join(segments));
+            }
+        }
+        lines.join("\n")
+    }
+
+    /// Renders the table like [`render`](Table::render), without any escape sequences, for logs and
+    /// other outputs that are not terminals.
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// use bytescolor::table::{Column, Table};
+    /// use bytescolor::ByteColor;
+    ///
+    /// let table = Table::new().column(Column::text("check")).row([&"passed".green()]);
+    /// assert_eq!(table.to_plain(), "check\npassed");
+    /// ```
+    pub fn to_plain(&self) -> String {
+        ansi::strip(&self.render()).into_owned()
+    }
+
+    /// Renders the data of the table as comma-separated values, for scripts: the titles on the first
+    /// line if any column has one, then a line per row, each ending with a newline. Cells are
+    /// unstyled and unwrapped, fields holding a delimiter, a quote or a line break are quoted RFC 4180
+    /// style, spanning cells are followed by empty fields for the other columns they span, and short
+    /// rows are completed with empty fields.
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// use bytescolor::table::{Cell, Column, Table};
+    /// use bytescolor::ByteColor;
+    ///
+    /// let table = Table::new()
+    ///     .column(Column::text("name"))
+    ///     .column(Column::numeric("size"))
+    ///     .row([&"Cargo.toml".cyan(), &"1,204".bold()])
+    ///     .row([Cell::new("total").span(2)]);
+    /// assert_eq!(table.to_csv(), "name,size\nCargo.toml,\"1,204\"\ntotal,\n");
+    /// ```
+    pub fn to_csv(&self) -> String {
+        self.to_delimited(',')
+    }
+
+    /// Renders the data of the table as tab-separated values, like [`to_csv`](Table::to_csv).
+    pub fn to_tsv(&self) -> String {
+        self.to_delimited('\t')
+    }
+
+    fn to_delimited(&self, delimiter: char) -> String {
+        let placed = self.placed();
+        let count = self.count(&placed);
+        let field = |text: &str| {
+            let text = ansi::strip(text);
+            if text.contains([delimiter, '"', '\n', '\r']) {
+                format!("\"{}\"", text.replace('"', "\"\""))
+            } else {
+                text.into_owned()
+            }
+        };
+        let mut records = Vec::new();
+        if self.columns.iter().any(|column| !column.title.is_empty()) {
+            records.push(
+                (0..count)
+                    .map(|index| field(self.columns.get(index).map_or("", Column::title)))
+                    .collect::<Vec<_>>(),
+            );
+        }
+        records.extend(records_of(&placed, count, field));
+        let mut out = String::new();
+        for record in records {
+            out.push_str(&record.join(&delimiter.to_string()));
+            out.push('\n');
+        }
+        out
+    }
+
+    /// Renders the data of the table as a GitHub-flavored markdown table, each line ending with a
+    /// newline. Cells are unstyled and unwrapped, with `|` escaped and line breaks written as `<br>`;
+    /// numeric columns are right-aligned. Markdown has no spanning cells, so a spanning cell is followed
+    /// by empty cells for the other columns it spans. A table without titles gets an empty header row,
+    /// which markdown requires.
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// use bytescolor::table::{Column, Table};
+    /// use bytescolor::ByteColor;
+    ///
+    /// let table = Table::new()
+    ///     .column(Column::text("crate"))
+    ///     .column(Column::numeric("downloads"))
+    ///     .row([&"serde".cyan(), &"512.4".bold()])
+    ///     .row(["a|b", "3"]);
+    /// assert_eq!(
+    ///     table.to_markdown(),
+    ///     "| crate | downloads |\n\
+    ///      | ----- | --------: |\n\
+    ///      | serde |     512.4 |\n\
+    ///      | a\\|b  |         3 |\n"
+    /// );
+    /// ```
+    pub fn to_markdown(&self) -> String {
+        let placed = self.placed();
+        let count = self.count(&placed);
+        let escape = |text: &str| {
+            ansi::strip(text)
+                .replace('|', "\\|")
+                .replace("\r\n", "<br>")
+                .replace('\n', "<br>")
+        };
+        let titles: Vec<String> = (0..count)
+            .map(|index| escape(self.columns.get(index).map_or("", Column::title)))
+            .collect();
+        let rows = records_of(&placed, count, escape);
+        let numeric: Vec<bool> = (0..count)
+            .map(|index| self.columns.get(index).is_some_and(|column| column.numeric))
+            .collect();
+        let widths: Vec<usize> = (0..count)
+            .map(|index| {
+                rows.iter()
+                    .chain([&titles])
+                    .map(|record| visible_width(&record[index]))
+                    .max()
+                    .unwrap_or(0)
+                    .max(3)
+            })
+            .collect();
+        let line = |cells: Vec<String>| format!("| {} |\n", cells.join(" | "));
+        let pad = |record: &[String]| {
+            (0..count)
+                .map(|index| {
+                    let padding = " ".repeat(widths[index] - visible_width(&record[index]));
+                    if numeric[index] {
+                        format!("{}{}", padding, record[index])
+                    } else {
+                        format!("{}{}", record[index], padding)
+                    }
+                })
+                .collect()
+        };
+        let mut out = line(pad(&titles));
+        out.push_str(&line(
+            (0..count)
+                .map(|index| {
+                    if numeric[index] {
+                        format!("{}:", "-".repeat(widths[index] - 1))
+                    } else {
+                        "-".repeat(widths[index])
+                    }
+                })
+                .collect(),
+        ));
+        for record in &rows {
+            out.push_str(&line(pad(record)));
+        }
+        out
+    }
+
+    /// Returns the number of columns of the table, counting the untitled ones rows extend into.
+    fn count(&self, placed: &[Vec<(usize, &Cell)>]) -> usize {
+        placed
+            .iter()
+            .filter_map(|row| row.last().map(|&(start, cell)| start + cell.span))
+            .chain([self.columns.len()])
+            .max()
+            .unwrap_or(0)
+    }
+
+    /// Returns the cells of each row with the index of the first column they occupy.
+    fn placed(&self) -> Vec<Vec<(usize, &Cell)>> {
+        self.rows
+            .iter()
+            .map(|row| {
+                let mut start = 0;
+                row.iter()
+                    .map(|cell| {
+                        let placed = (start, cell);
+                        start += cell.span;
+                        placed
+                    })
+                    .collect()
+            })
+            .collect()
+    }
+
+    /// Returns the width of a cell spanning the columns of `layouts`, spacing included.
+    fn area(&self, layouts: &[Layout]) -> usize {
+        let widths: usize = layouts.iter().map(|layout| layout.width).sum();
+        widths + self.spacing * layouts.len().saturating_sub(1)
+    }
+
+    /// Joins the cells of a line, dropping the padding of the last ones.
+    fn join(&self, cells: impl Iterator<Item = String>) -> String {
+        let line = cells.collect::<Vec<_>>().join(&" ".repeat(self.spacing));
+        line.trim_end_matches(' ').to_string()
+    }
+}
+
+impl fmt::Display for Table {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.write_str(&self.render())
+    }
+}
+
+/// Returns the fields of each row, `field` applied to the text of each cell, with empty fields for the
+/// columns spanning cells extend into and those short rows do not reach.
+fn records_of(
+    placed: &[Vec<(usize, &Cell)>],
+    count: usize,
+    field: impl Fn(&str) -> String,
+) -> Vec<Vec<String>> {
+    placed
+        .iter()
+        .map(|row| {
+            let mut record = Vec::with_capacity(count);
+            for &(_, cell) in row {
+                record.push(field(&cell.text));
+                record.extend((1..cell.span).map(|_| String::new()));
+            }
+            record.resize(count, String::new());
+            record
+        })
+        .collect()
+}
+
+/// The widths of a column, and for numeric columns those of the parts around the alignment point.
+struct Layout {
+    width: usize,
+    numeric: bool,
+    wraps: bool,
+    integer: usize,
+    fraction: usize,
+}
+
+impl Layout {
+    fn new<'a>(column: &Column, cells: impl Iterator<Item = &'a str>) -> Self {
+        let mut layout = Layout {
+            width: visible_width(&column.title),
+            numeric: column.numeric,
+            wraps: column.max_width.is_some(),
+            integer: 0,
+            fraction: 0,
+        };
+        for cell in cells {
+            if column.numeric {
+                for line in wrap(cell, None) {
+                    let (integer, fraction) = split_number(&ansi::strip(&line));
+                    layout.integer = layout.integer.max(integer);
+                    layout.fraction = layout.fraction.max(fraction);
+                }
+            } else {
+                let width = lines_width(&wrap(cell, column.max_width));
+                layout.width = layout.width.max(width);
+            }
+        }
+        layout.width = layout.width.max(layout.integer + layout.fraction);
+        layout
+    }
+
+    /// Pads a line of a cell to the width of the column.
+    fn pad(&self, styled: &str) -> String {
+        if !self.numeric {
+            let padding = self.width.saturating_sub(visible_width(styled));
+            return format!("{}{}", styled, " ".repeat(padding));
+        }
+        let (integer, fraction) = split_number(&ansi::strip(styled));
+        let left = self.width - (self.integer + self.fraction) + (self.integer - integer);
+        format!(
+            "{}{}{}",
+            " ".repeat(left),
+            styled,
+            " ".repeat(self.fraction - fraction)
+        )
+    }
+}
+
+/// Splits a cell into its lines, each styled on its own, word-wrapped at `width` columns if given.
+/// Words wider than `width` are broken between grapheme clusters.
+fn wrap(text: &str, width: Option<usize>) -> Vec<String> {
+    let mut lines = Vec::new();
+    for line in StyledText::from_ansi(text).lines_styled() {
+        match width {
+            Some(width) => wrap_line(&line, width.max(1), &mut lines),
+            None => lines.push(line.render()),
+        }
+    }
+    if lines.is_empty() {
+        lines.push(String::new());
+    }
+    lines
+}
+
+/// Appends the lines of `line` word-wrapped at `width` columns to `out`.
+fn wrap_line(line: &StyledText, width: usize, out: &mut Vec<String>) {
+    let visible = line.visible();
+    // The range of characters of the line being filled, and its width.
+    let (mut start, mut end, mut used) = (0, 0, 0);
+    let mut index = 0;
+    for (number, word) in visible.split(' ').enumerate() {
+        let len = word.chars().count();
+        let word_width = ansi::text_width(word);
+        if number > 0 && used + 1 + word_width <= width {
+            end = index + len;
+            used += 1 + word_width;
+        } else {
+            if number > 0 {
+                out.push(line.slice(start..end).render());
+            }
+            (start, end, used) = (index, index, 0);
+            for cluster in ansi::graphemes(word) {
+                let cluster_width = ansi::text_width(cluster);
+                if used > 0 && used + cluster_width > width {
+                    out.push(line.slice(start..end).render());
+                    (start, used) = (end, 0);
+                }
+                end += cluster.chars().count();
+                used += cluster_width;
+            }
+        }
+        index += len + 1;
+    }
+    out.push(line.slice(start..end).render());
+}
+
+/// Returns the width of the widest of `lines`.
+fn lines_width(lines: &[String]) -> usize {
+    lines
+        .iter()
+        .map(|line| visible_width(line))
+        .max()
+        .unwrap_or(0)
+}
+
+/// Returns the widths of the parts of a number before and after its alignment point: the decimal
+/// point, or else the end of its last digit. Text without digits is all integer part.
+fn split_number(text: &str) -> (usize, usize) {
+    let point = text
+        .find('.')
+        .or_else(|| {
+            text.rfind(|c: char| c.is_ascii_digit())
+                .map(|index| index + 1)
+        })
+        .unwrap_or(text.len());
+    (visible_width(&text[..point]), visible_width(&text[point..]))
+}
+
+/// Parses the number at the start of a cell, ignoring group separators and a trailing unit.
+fn parse_number(cell: &str) -> Option<f64> {
+    let cell = cell.trim();
+    let end = cell
+        .char_indices()
+        .find(|&(index, c)| {
+            !(c.is_ascii_digit()
+                || matches!(c, '.' | ',' | '_')
+                || (index == 0 && matches!(c, '-' | '+')))
+        })
+        .map_or(cell.len(), |(index, _)| index);
+    cell[..end].replace([',', '_'], "").parse().ok()
+}
@@ -0,0 +1,348 @@
+//! # Terminal Control
+//!
+//! Wrappers for the common non-SGR escape sequences needed by interactive output: cursor movement,
+//! clearing, saving and restoring the cursor position, and hiding the cursor.
+//!
+//! [`terminal_size`] reports the size of the terminal, for layout code that has to fit it, and
+//! [`LiveLine`] rewrites the current line in place, the building block of progress and status displays.
+//!
+//! Fixed sequences are exposed as constants, parameterized ones as functions returning a `String`. Movement
+//! by zero cells returns an empty string, because most terminals treat a zero count as one.
+//!
+//! ```rust
+//! use bytescolor::term;
+//!
+//! // Rewrite the line above the cursor.
+//! print!("{}{}{}", term::cursor_up(1), term::CLEAR_LINE, "updated");
+//! ```
+
+use std::io::{self, Write};
+
+/// Clears the entire current line (the cursor does not move).
+pub const CLEAR_LINE: &str = "\x1b[2K";
+
+/// Clears from the cursor to the end of the current line.
+pub const CLEAR_LINE_END: &str = "\x1b[K";
+
+/// Clears from the start of the current line to the cursor.
+pub const CLEAR_LINE_START: &str = "\x1b[1K";
+
+/// Clears the entire screen and moves the cursor to the top-left corner.
+pub const CLEAR_SCREEN: &str = "\x1b[2J\x1b[H";
+
+/// Clears from the cursor to the end of the screen.
+pub const CLEAR_SCREEN_END: &str = "\x1b[J";
+
+/// Saves the cursor position (DEC sequence, the most widely supported variant).
+pub const SAVE_CURSOR: &str = "\x1b7";
+
+/// Restores the cursor position saved with [`SAVE_CURSOR`].
+pub const RESTORE_CURSOR: &str = "\x1b8";
+
+/// Hides the cursor.
+pub const HIDE_CURSOR: &str = "\x1b[?25l";
+
+/// Shows the cursor.
+pub const SHOW_CURSOR: &str = "\x1b[?25h";
+
+fn csi(n: u16, command: char) -> String {
+    if n == 0 {
+        String::new()
+    } else {
+        format!("\x1b[{}{}", n, command)
+    }
+}
+
+/// Moves the cursor up by `n` lines.
+///
+/// # Examples
+///
+/// ```rust
+/// use bytescolor::term;
+///
+/// assert_eq!(term::cursor_up(3), "\x1b[3A");
+/// assert_eq!(term::cursor_up(0), "");
+/// ```
+pub fn cursor_up(n: u16) -> String {
+    csi(n, 'A')
+}
+
+/// Moves the cursor down by `n` lines.
+pub fn cursor_down(n: u16) -> String {
+    csi(n, 'B')
+}
+
+/// Moves the cursor right by `n` columns.
+pub fn cursor_forward(n: u16) -> String {
+    csi(n, 'C')
+}
+
+/// Moves the cursor left by `n` columns.
+pub fn cursor_back(n: u16) -> String {
+    csi(n, 'D')
+}
+
+/// Moves the cursor to the start of the line `n` lines down.
+pub fn next_line(n: u16) -> String {
+    csi(n, 'E')
+}
+
+/// Moves the cursor to the start of the line `n` lines up.
+pub fn previous_line(n: u16) -> String {
+    csi(n, 'F')
+}
+
+/// Moves the cursor to the zero-based `column` of the current line.
+///
+/// # Examples
+///
+/// ```rust
+/// use bytescolor::term;
+///
+/// assert_eq!(term::cursor_column(0), "\x1b[1G");
+/// ```
+pub fn cursor_column(column: u16) -> String {
+    format!("\x1b[{}G", u32::from(column) + 1)
+}
+
+/// Moves the cursor to the zero-based `row` and `column` of the screen.
+///
+/// # Examples
+///
+/// ```rust
+/// use bytescolor::term;
+///
+/// assert_eq!(term::cursor_to(0, 4), "\x1b[1;5H");
+/// ```
+pub fn cursor_to(row: u16, column: u16) -> String {
+    format!("\x1b[{};{}H", u32::from(row) + 1, u32::from(column) + 1)
+}
+
+/// Returns the size of the terminal as `(columns, rows)`.
+///
+/// With the `terminal-size` feature, the size is queried from the terminal attached to stdout, stderr
+/// or stdin (`ioctl` on Unix, the console API on Windows). Otherwise, or when no terminal is attached
+/// (output piped, CI logs), it falls back to the `COLUMNS` and `LINES` environment variables, the rows
+/// defaulting to 24. Returns `None` when neither source knows the width.
+///
+/// # Examples
+///
+/// ```rust
+/// let columns = bytescolor::terminal_size().map_or(80, |(columns, _)| columns);
+/// println!("{}", "─".repeat(usize::from(columns)));
+/// ```
+pub fn terminal_size() -> Option<(u16, u16)> {
+    query_size().or_else(env_size)
+}
+
+fn env_size() -> Option<(u16, u16)> {
+    let var = |name: &str| {
+        std::env::var(name)
+            .ok()
+            .and_then(|value| value.trim().parse::<u16>().ok())
+            .filter(|&value| value > 0)
+    };
+    Some((var("COLUMNS")?, var("LINES").unwrap_or(24)))
+}
+
+#[cfg(all(unix, feature = "terminal-size"))]
+fn query_size() -> Option<(u16, u16)> {
+    [libc::STDOUT_FILENO, libc::STDERR_FILENO, libc::STDIN_FILENO]
+        .into_iter()
+        .find_map(|fd| {
+            // SAFETY: `winsize` is plain data, and TIOCGWINSZ only writes to it.
+            let mut size: libc::winsize = unsafe { std::mem::zeroed() };
+            let ok = unsafe { libc::ioctl(fd, libc::TIOCGWINSZ, &mut size) } == 0;
+            (ok && size.ws_col > 0).then_some((size.ws_col, size.ws_row))
+        })
+}
+
+#[cfg(all(windows, feature = "terminal-size"))]
+fn query_size() -> Option<(u16, u16)> {
+    console::size()
+}
+
+#[cfg(not(all(any(unix, windows), feature = "terminal-size")))]
+fn query_size() -> Option<(u16, u16)> {
+    None
+}
+
+#[cfg(all(windows, feature = "terminal-size"))]
+mod console {
+    use std::ffi::c_void;
+
+    // Mirrors of the Win32 structs; only the window rectangle is read.
+    #[repr(C)]
+    #[derive(Clone, Copy)]
+    #[allow(dead_code)]
+    struct Coord {
+        x: i16,
+        y: i16,
+    }
+
+    #[repr(C)]
+    #[derive(Clone, Copy)]
+    struct SmallRect {
+        left: i16,
+        top: i16,
+        right: i16,
+        bottom: i16,
+    }
+
+    #[repr(C)]
+    #[allow(dead_code)]
+    struct ScreenBufferInfo {
+        size: Coord,
+        cursor_position: Coord,
+        attributes: u16,
+        window: SmallRect,
+        maximum_window_size: Coord,
+    }
+
+    const STD_OUTPUT_HANDLE: u32 = -11i32 as u32;
+    const STD_ERROR_HANDLE: u32 = -12i32 as u32;
+
+    #[link(name = "kernel32")]
+    extern "system" {
+        fn GetStdHandle(handle: u32) -> *mut c_void;
+        fn GetConsoleScreenBufferInfo(console: *mut c_void, info: *mut ScreenBufferInfo) -> i32;
+    }
+
+    pub(super) fn size() -> Option<(u16, u16)> {
+        [STD_OUTPUT_HANDLE, STD_ERROR_HANDLE]
+            .into_iter()
+            .find_map(|handle| {
+                // SAFETY: the info struct matches CONSOLE_SCREEN_BUFFER_INFO and is only written to;
+                // invalid or non-console handles make the call fail.
+                let mut info: ScreenBufferInfo = unsafe { std::mem::zeroed() };
+                let ok =
+                    unsafe { GetConsoleScreenBufferInfo(GetStdHandle(handle), &mut info) } != 0;
+                let window = info.window;
+                let columns = i32::from(window.right) - i32::from(window.left) + 1;
+                let rows = i32::from(window.bottom) - i32::from(window.top) + 1;
+                (ok && columns > 0).then(|| (columns as u16, rows.max(0) as u16))
+            })
+    }
+}
+
+/// Hides the cursor of a writer for as long as the guard lives, showing it again when dropped — even
+/// when the program unwinds from a panic.
+///
+/// # Examples
+///
+/// ```rust
+/// use bytescolor::term::HiddenCursor;
+///
+/// let mut out = Vec::new();
+/// {
+///     let mut guard = HiddenCursor::new(&mut out).unwrap();
+///     std::io::Write::write_all(guard.writer(), b"working").unwrap();
+/// }
+/// assert_eq!(out, b"\x1b[?25lworking\x1b[?25h");
+/// ```
+#[derive(Debug)]
+pub struct HiddenCursor<W: Write> {
+    writer: W,
+}
+
+impl<W: Write> HiddenCursor<W> {
+    /// Hides the cursor on `writer`.
+    pub fn new(mut writer: W) -> io::Result<Self> {
+        writer.write_all(HIDE_CURSOR.as_bytes())?;
+        writer.flush()?;
+        Ok(HiddenCursor { writer })
+    }
+
+    /// Returns the wrapped writer.
+    pub fn writer(&mut self) -> &mut W {
+        &mut self.writer
+    }
+}
+
+impl<W: Write> Drop for HiddenCursor<W> {
+    fn drop(&mut self) {
+        let _ = self.writer.write_all(SHOW_CURSOR.as_bytes());
+        let _ = self.writer.flush();
+    }
+}
+
+/// A single terminal line rewritten in place: each update returns to the start of the line, writes the
+/// new content and clears what is left of the previous one. Content is cut at its first newline and
+/// truncated to the terminal width, so it never wraps onto a line that could not be rewritten anymore.
+///
+/// # Examples
+///
+/// ```rust
+/// use bytescolor::term::LiveLine;
+///
+/// let mut line = LiveLine::new(Vec::new()).width(12);
+/// line.update("downloading").unwrap();
+/// line.update("downloading 42%").unwrap();
+/// line.finish("done").unwrap();
+/// let out = String::from_utf8(line.into_inner()).unwrap();
+/// assert_eq!(out, "\rdownloading\x1b[K\rdownloading \x1b[K\rdone\x1b[K\n");
+/// ```
+#[derive(Debug)]
+pub struct LiveLine<W: Write> {
+    writer: W,
+    width: Option<usize>,
+}
+
+impl<W: Write> LiveLine<W> {
+    /// Creates a live line drawn on `writer`, as wide as the terminal.
+    pub fn new(writer: W) -> Self {
+        LiveLine {
+            writer,
+            width: None,
+        }
+    }
+
+    /// Sets the width content is truncated to instead of detecting it.
+    ///
+    /// By default the width of the terminal is queried on every update, so resizes are followed, as
+    /// reported by [`terminal_size`], falling back to 80.
+    pub fn width(mut self, width: usize) -> Self {
+        self.width = Some(width);
+        self
+    }
+
+    /// Replaces the content of the line, without moving to the next line.
+    pub fn update(&mut self, content: impl std::fmt::Display) -> io::Result<()> {
+        let content = content.to_string();
+        let first = content.split('\n').next().unwrap_or_default();
+        let width = self
+            .width
+            .unwrap_or_else(|| terminal_size().map_or(80, |(columns, _)| usize::from(columns)));
+        write!(
+            self.writer,
+            "\r{}{}",
+            crate::ansi::truncate(first, width),
+            CLEAR_LINE_END
+        )?;
+        self.writer.flush()
+    }
+
+    /// Clears the line, leaving the cursor at its start.
+    pub fn clear(&mut self) -> io::Result<()> {
+        write!(self.writer, "\r{}", CLEAR_LINE_END)?;
+        self.writer.flush()
+    }
+
+    /// Replaces the content of the line a last time and moves to the next line.
+    pub fn finish(&mut self, content: impl std::fmt::Display) -> io::Result<()> {
+        self.update(content)?;
+        self.writer.write_all(b"\n")?;
+        self.writer.flush()
+    }
+
+    /// Returns the wrapped writer, for instance to print a permanent line above the live one after
+    /// [`clear`](LiveLine::clear).
+    pub fn writer(&mut self) -> &mut W {
+        &mut self.writer
+    }
+
+    /// Consumes the live line, returning the wrapped writer.
+    pub fn into_inner(self) -> W {
+        self.writer
+    }
+}
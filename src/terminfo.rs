@@ -0,0 +1,348 @@
+//! # Terminfo
+//!
+//! A reader for compiled terminfo entries, the database describing the capabilities of each `$TERM`
+//! value. Consulting it is more reliable than guessing from the name of the terminal, especially for
+//! unusual `$TERM` values; [`control::detect_level`](crate::control::detect_level) uses it when the
+//! `terminfo` feature is enabled.
+//!
+//! Both the legacy format and the extended-number format of ncurses 6.1 are supported, including
+//! user-defined capabilities such as `RGB`, `Tc`, `Smulx` and `Setulc`.
+//!
+//! ```rust,no_run
+//! use bytescolor::terminfo::Terminfo;
+//!
+//! let info = Terminfo::from_env()?;
+//! println!("{} supports {:?} colors", info.names()[0], info.max_colors());
+//! # Ok::<(), std::io::Error>(())
+//! ```
+
+use std::collections::HashMap;
+use std::io;
+use std::path::{Path, PathBuf};
+
+use crate::control::ColorLevel;
+
+const MAGIC_LEGACY: u16 = 0o432;
+const MAGIC_EXTENDED_NUMBERS: u16 = 0o1036;
+
+/// Index of `max_colors` among the standard numeric capabilities.
+const MAX_COLORS: usize = 13;
+/// Index of `enter_underline_mode` among the standard string capabilities.
+const ENTER_UNDERLINE_MODE: usize = 36;
+
+/// A parsed terminfo entry.
+#[derive(Debug, Clone, Default)]
+pub struct Terminfo {
+    names: Vec<String>,
+    booleans: Vec<bool>,
+    numbers: Vec<Option<i32>>,
+    strings: Vec<Option<Vec<u8>>>,
+    extended_booleans: HashMap<String, bool>,
+    extended_numbers: HashMap<String, i32>,
+    extended_strings: HashMap<String, Vec<u8>>,
+}
+
+impl Terminfo {
+    /// Loads the entry for the `$TERM` environment variable.
+    pub fn from_env() -> io::Result<Self> {
+        match std::env::var("TERM") {
+            Ok(term) if !term.is_empty() => Terminfo::load(&term),
+            _ => Err(io::Error::new(io::ErrorKind::NotFound, "TERM is not set")),
+        }
+    }
+
+    /// Loads the entry for `term`, searching `$TERMINFO`, `~/.terminfo`, `$TERMINFO_DIRS` and the
+    /// usual system directories.
+    pub fn load(term: &str) -> io::Result<Self> {
+        let first = term
+            .chars()
+            .next()
+            .filter(|_| !term.contains(['/', '\\']))
+            .ok_or_else(|| invalid("invalid terminal name"))?;
+        for dir in search_dirs() {
+            for subdir in [first.to_string(), format!("{:x}", first as u32)] {
+                let path = dir.join(subdir).join(term);
+                if let Ok(bytes) = std::fs::read(&path) {
+                    return Terminfo::parse(&bytes);
+                }
+            }
+        }
+        Err(io::Error::new(
+            io::ErrorKind::NotFound,
+            format!("no terminfo entry for {}", term),
+        ))
+    }
+
+    /// Parses a compiled terminfo entry.
+    pub fn parse(bytes: &[u8]) -> io::Result<Self> {
+        let mut reader = Reader { bytes, pos: 0 };
+        let number_size = match reader.u16()? {
+            MAGIC_LEGACY => 2,
+            MAGIC_EXTENDED_NUMBERS => 4,
+            _ => return Err(invalid("not a compiled terminfo entry")),
+        };
+        let names_size = reader.count()?;
+        let boolean_count = reader.count()?;
+        let number_count = reader.count()?;
+        let string_count = reader.count()?;
+        let table_size = reader.count()?;
+
+        let names = String::from_utf8_lossy(reader.take(names_size)?);
+        let names = names
+            .trim_end_matches('\0')
+            .split('|')
+            .map(String::from)
+            .collect();
+        let booleans = reader
+            .take(boolean_count)?
+            .iter()
+            .map(|&b| b == 1)
+            .collect();
+        reader.align();
+        let numbers = (0..number_count)
+            .map(|_| reader.number(number_size))
+            .collect::<io::Result<_>>()?;
+        let offsets = (0..string_count)
+            .map(|_| reader.offset())
+            .collect::<io::Result<Vec<_>>>()?;
+        let table = reader.take(table_size)?;
+        let strings = offsets
+            .iter()
+            .map(|&offset| offset.and_then(|offset| string_at(table, offset)))
+            .map(|string| string.map(<[u8]>::to_vec))
+            .collect();
+
+        let mut info = Terminfo {
+            names,
+            booleans,
+            numbers,
+            strings,
+            ..Terminfo::default()
+        };
+        reader.align();
+        if reader.pos < bytes.len() {
+            info.parse_extended(&mut reader, number_size)?;
+        }
+        Ok(info)
+    }
+
+    fn parse_extended(&mut self, reader: &mut Reader, number_size: usize) -> io::Result<()> {
+        let boolean_count = reader.count()?;
+        let number_count = reader.count()?;
+        let string_count = reader.count()?;
+        let _offset_count = reader.count()?;
+        let table_size = reader.count()?;
+
+        let booleans = reader.take(boolean_count)?.to_vec();
+        reader.align();
+        let numbers = (0..number_count)
+            .map(|_| reader.number(number_size))
+            .collect::<io::Result<Vec<_>>>()?;
+        let value_offsets = (0..string_count)
+            .map(|_| reader.offset())
+            .collect::<io::Result<Vec<_>>>()?;
+        let name_offsets = (0..boolean_count + number_count + string_count)
+            .map(|_| reader.offset())
+            .collect::<io::Result<Vec<_>>>()?;
+        let table = reader.take(table_size)?;
+
+        // Names follow the last string value in the table.
+        let names_start = value_offsets
+            .iter()
+            .flatten()
+            .filter_map(|&offset| string_at(table, offset).map(|value| offset + value.len() + 1))
+            .max()
+            .unwrap_or(0);
+        let names_table = table.get(names_start..).unwrap_or_default();
+        let mut names = name_offsets.into_iter().map(|offset| {
+            offset
+                .and_then(|offset| string_at(names_table, offset))
+                .map(|name| String::from_utf8_lossy(name).into_owned())
+        });
+
+        for value in booleans {
+            if let Some(name) = names.next().flatten() {
+                self.extended_booleans.insert(name, value == 1);
+            }
+        }
+        for value in numbers {
+            if let (Some(name), Some(value)) = (names.next().flatten(), value) {
+                self.extended_numbers.insert(name, value);
+            }
+        }
+        for offset in value_offsets {
+            let value = offset.and_then(|offset| string_at(table, offset));
+            if let (Some(name), Some(value)) = (names.next().flatten(), value) {
+                self.extended_strings.insert(name, value.to_vec());
+            }
+        }
+        Ok(())
+    }
+
+    /// Returns the names of the terminal, the first being the `$TERM` value it is known by.
+    pub fn names(&self) -> &[String] {
+        &self.names
+    }
+
+    /// Returns the value of the standard boolean capability at `index`.
+    pub fn boolean(&self, index: usize) -> bool {
+        self.booleans.get(index).copied().unwrap_or(false)
+    }
+
+    /// Returns the value of the standard numeric capability at `index`, if present.
+    pub fn number(&self, index: usize) -> Option<i32> {
+        self.numbers.get(index).copied().flatten()
+    }
+
+    /// Returns the value of the standard string capability at `index`, if present.
+    pub fn string(&self, index: usize) -> Option<&[u8]> {
+        self.strings.get(index)?.as_deref()
+    }
+
+    /// Returns the value of the user-defined boolean capability `name`.
+    pub fn extended_boolean(&self, name: &str) -> bool {
+        self.extended_booleans.get(name).copied().unwrap_or(false)
+    }
+
+    /// Returns the value of the user-defined numeric capability `name`, if present.
+    pub fn extended_number(&self, name: &str) -> Option<i32> {
+        self.extended_numbers.get(name).copied()
+    }
+
+    /// Returns the value of the user-defined string capability `name`, if present.
+    pub fn extended_string(&self, name: &str) -> Option<&[u8]> {
+        self.extended_strings.get(name).map(Vec::as_slice)
+    }
+
+    /// Returns the number of colors the terminal supports (`max_colors`).
+    pub fn max_colors(&self) -> Option<i32> {
+        self.number(MAX_COLORS)
+    }
+
+    /// Returns `true` if the terminal declares 24-bit color support, through the `RGB` or `Tc`
+    /// capabilities or a direct-color `max_colors`.
+    pub fn supports_truecolor(&self) -> bool {
+        ["RGB", "Tc"].iter().any(|&name| {
+            self.extended_boolean(name)
+                || self.extended_number(name).is_some()
+                || self.extended_string(name).is_some()
+        }) || self.max_colors().is_some_and(|colors| colors >= 1 << 24)
+    }
+
+    /// Returns `true` if the terminal can underline text (`enter_underline_mode`).
+    pub fn supports_underline(&self) -> bool {
+        self.string(ENTER_UNDERLINE_MODE).is_some()
+    }
+
+    /// Returns `true` if the terminal supports curly, dotted and other underline styles (`Smulx`).
+    pub fn supports_styled_underline(&self) -> bool {
+        self.extended_string("Smulx").is_some()
+    }
+
+    /// Returns `true` if the terminal can color underlines independently (`Setulc`).
+    pub fn supports_underline_color(&self) -> bool {
+        self.extended_string("Setulc").is_some()
+    }
+
+    /// Returns the richest color level supported by the terminal.
+    pub fn color_level(&self) -> ColorLevel {
+        if self.supports_truecolor() {
+            return ColorLevel::TrueColor;
+        }
+        match self.max_colors() {
+            Some(256..) => ColorLevel::Ansi256,
+            Some(8..) => ColorLevel::Ansi16,
+            _ => ColorLevel::None,
+        }
+    }
+}
+
+fn search_dirs() -> Vec<PathBuf> {
+    let mut dirs = Vec::new();
+    if let Some(dir) = std::env::var_os("TERMINFO") {
+        dirs.push(PathBuf::from(dir));
+    }
+    if let Some(home) = std::env::var_os("HOME") {
+        dirs.push(Path::new(&home).join(".terminfo"));
+    }
+    let defaults = [
+        "/etc/terminfo",
+        "/lib/terminfo",
+        "/usr/share/terminfo",
+        "/usr/lib/terminfo",
+    ];
+    match std::env::var("TERMINFO_DIRS") {
+        Ok(list) => {
+            for dir in list.split(':') {
+                if dir.is_empty() {
+                    dirs.extend(defaults.iter().map(PathBuf::from));
+                } else {
+                    dirs.push(PathBuf::from(dir));
+                }
+            }
+        }
+        Err(_) => dirs.extend(defaults.iter().map(PathBuf::from)),
+    }
+    dirs
+}
+
+/// Returns the NUL-terminated string starting at `offset` in `table`.
+fn string_at(table: &[u8], offset: usize) -> Option<&[u8]> {
+    let rest = table.get(offset..)?;
+    let end = rest.iter().position(|&b| b == 0)?;
+    Some(&rest[..end])
+}
+
+fn invalid(message: &str) -> io::Error {
+    io::Error::new(io::ErrorKind::InvalidData, message.to_string())
+}
+
+struct Reader<'a> {
+    bytes: &'a [u8],
+    pos: usize,
+}
+
+impl<'a> Reader<'a> {
+    fn take(&mut self, len: usize) -> io::Result<&'a [u8]> {
+        let bytes = self
+            .bytes
+            .get(self.pos..self.pos + len)
+            .ok_or_else(|| invalid("truncated terminfo entry"))?;
+        self.pos += len;
+        Ok(bytes)
+    }
+
+    fn u16(&mut self) -> io::Result<u16> {
+        let bytes = self.take(2)?;
+        Ok(u16::from_le_bytes([bytes[0], bytes[1]]))
+    }
+
+    /// Reads a section size; negative sizes are treated as empty.
+    fn count(&mut self) -> io::Result<usize> {
+        Ok((self.u16()? as i16).max(0) as usize)
+    }
+
+    /// Reads a string offset, `None` meaning an absent or cancelled capability.
+    fn offset(&mut self) -> io::Result<Option<usize>> {
+        let value = self.u16()? as i16;
+        Ok((value >= 0).then_some(value as usize))
+    }
+
+    /// Reads a number, `None` meaning an absent or cancelled capability.
+    fn number(&mut self, size: usize) -> io::Result<Option<i32>> {
+        let bytes = self.take(size)?;
+        let value = if size == 2 {
+            i16::from_le_bytes([bytes[0], bytes[1]]) as i32
+        } else {
+            i32::from_le_bytes([bytes[0], bytes[1], bytes[2], bytes[3]])
+        };
+        Ok((value >= 0).then_some(value))
+    }
+
+    /// Skips the padding byte that keeps sections aligned on even offsets.
+    fn align(&mut self) {
+        if self.pos % 2 == 1 && self.pos < self.bytes.len() {
+            self.pos += 1;
+        }
+    }
+}
@@ -0,0 +1,281 @@
+//! # Property Testing Kit
+//!
+//! [`proptest`](mod@proptest) strategies generating colors, styles, text and styled documents, and
+//! the invariants every part of the crate relies on, as checks returning a [`TestCaseError`] so they
+//! can be used in `proptest!` tests with `?`:
+//!
+//! - styling only adds escape sequences: stripping them gives the text back and the visible width is
+//!   unchanged ([`check_styling`], [`check_paint`]);
+//! - rendering a [`StyledText`] and parsing the output gives the document back ([`check_roundtrip`]);
+//! - truncation never exceeds its width and keeps a prefix of the text ([`check_truncate`]).
+//!
+//! Features producing styled output hook into the kit by checking it with [`check_styling`] against
+//! the text it should display, and applications can do the same with their own formatters.
+//!
+//! ```rust
+//! use bytescolor::testkit::proptest::prelude::*;
+//! use bytescolor::testkit::{self, check_styling};
+//! use bytescolor::ByteColor;
+//!
+//! proptest!(|(text in testkit::text())| {
+//!     check_styling(&text, &text.red())?;
+//! });
+//! ```
+//!
+//! The version of `proptest` the kit is built with is re-exported, so tests do not need to depend on
+//! a matching version.
+//!
+//! This module is available with the `testkit` feature.
+
+pub use proptest;
+
+use proptest::prelude::*;
+use proptest::test_runner::TestCaseError;
+
+use crate::{ansi, backend, Color, Span, Style, StyledText};
+
+/// The 16 standard colors.
+const STANDARD_COLORS: [Color; 16] = [
+    Color::Black,
+    Color::Red,
+    Color::Green,
+    Color::Yellow,
+    Color::Blue,
+    Color::Magenta,
+    Color::Cyan,
+    Color::White,
+    Color::BrightBlack,
+    Color::BrightRed,
+    Color::BrightGreen,
+    Color::BrightYellow,
+    Color::BrightBlue,
+    Color::BrightMagenta,
+    Color::BrightCyan,
+    Color::BrightWhite,
+];
+
+/// Emoji, including sequences and modifiers, whose width terminals disagree on most.
+const EMOJI: [&str; 6] = ["😀", "👍🏽", "👩‍💻", "🇫🇷", "❤️", "1️⃣"];
+
+/// Escape sequences other than SGR, and malformed ones, found in real output.
+const SEQUENCES: [&str; 7] = [
+    "\x1b[2K",
+    "\x1b[1;1H",
+    "\x1b]0;title\x07",
+    "\x1b]8;;https://example.com\x1b\\",
+    "\x1b[38;5m",
+    "\x1b[",
+    "\x1b",
+];
+
+/// Generates colors of every kind: standard, from the 256-color palette and 24-bit.
+pub fn color() -> impl Strategy<Value = Color> {
+    prop_oneof![
+        prop::sample::select(&STANDARD_COLORS[..]),
+        any::<u8>().prop_map(Color::Fixed),
+        any::<(u8, u8, u8)>().prop_map(|(r, g, b)| Color::Rgb(r, g, b)),
+    ]
+}
+
+/// Generates styles with any combination of foreground, background and attributes, the plain style
+/// included.
+pub fn style() -> impl Strategy<Value = Style> {
+    (
+        prop::option::of(color()),
+        prop::option::of(color()),
+        any::<u8>(),
+    )
+        .prop_map(|(fg, bg, attrs)| {
+            let mut style = Style::new();
+            if let Some(fg) = fg {
+                style = style.fg(fg);
+            }
+            if let Some(bg) = bg {
+                style = style.bg(bg);
+            }
+            let attributes = [
+                Style::BOLD,
+                Style::DIM,
+                Style::ITALIC,
+                Style::UNDERLINE,
+                Style::BLINK,
+                Style::REVERSE,
+                Style::HIDDEN,
+                Style::STRIKETHROUGH,
+            ];
+            for (bit, attribute) in attributes.into_iter().enumerate() {
+                if attrs & (1 << bit) != 0 {
+                    style |= attribute;
+                }
+            }
+            style
+        })
+}
+
+/// Generates text without escape characters, weighted towards the characters layout code gets wrong:
+/// wide CJK characters, emoji, combining marks and control characters, mixed with plain ASCII.
+pub fn text() -> impl Strategy<Value = String> {
+    let piece = prop_oneof![
+        6 => proptest::char::range(' ', '~').prop_map(String::from),
+        1 => proptest::char::range('\u{4e00}', '\u{9fff}').prop_map(String::from),
+        1 => prop::sample::select(&EMOJI[..]).prop_map(String::from),
+        1 => proptest::char::range('\u{300}', '\u{36f}').prop_map(String::from),
+        1 => any::<char>()
+            .prop_filter("escape characters start sequences", |&c| c != '\x1b')
+            .prop_map(String::from),
+    ];
+    prop::collection::vec(piece, 0..24).prop_map(|pieces| pieces.concat())
+}
+
+/// Generates text with escape sequences: styles, cursor movement, hyperlinks, and truncated or
+/// malformed sequences, as found in captured output.
+pub fn ansi_text() -> impl Strategy<Value = String> {
+    let piece = prop_oneof![
+        4 => text(),
+        2 => style().prop_map(|style| style.prefix()),
+        1 => prop::sample::select(&SEQUENCES[..]).prop_map(String::from),
+    ];
+    prop::collection::vec(piece, 0..8).prop_map(|pieces| pieces.concat())
+}
+
+/// Generates styled documents of a few spans.
+pub fn styled_text() -> impl Strategy<Value = StyledText> {
+    prop::collection::vec((text(), style()), 0..8).prop_map(|spans| {
+        let mut document = StyledText::new();
+        for (text, style) in spans {
+            document.push(text, style);
+        }
+        document
+    })
+}
+
+/// Checks that `styled` displays `plain`: stripping its escape sequences gives `plain` back, and its
+/// visible width is that of `plain`.
+///
+/// # Errors
+///
+/// Returns a failure describing the first invariant that does not hold.
+///
+/// # Examples
+///
+/// ```rust
+/// use bytescolor::testkit::check_styling;
+/// use bytescolor::ByteColor;
+///
+/// assert!(check_styling("ok", &"ok".green()).is_ok());
+/// assert!(check_styling("ok", "ok!").is_err());
+/// ```
+pub fn check_styling(plain: &str, styled: &str) -> Result<(), TestCaseError> {
+    prop_assert_eq!(&*ansi::strip(styled), plain, "styling changed the text");
+    prop_assert_eq!(
+        ansi::visible_width(styled),
+        ansi::visible_width(plain),
+        "styling changed the width"
+    );
+    Ok(())
+}
+
+/// Checks that painting `text` in `style` only adds escape sequences (see [`check_styling`]), and
+/// that the width of the painted text is that of the equivalent [`StyledText`].
+///
+/// # Errors
+///
+/// Returns a failure describing the first invariant that does not hold.
+///
+/// # Examples
+///
+/// ```rust
+/// use bytescolor::testkit::{self, check_paint};
+/// use bytescolor::testkit::proptest::test_runner::TestRunner;
+///
+/// TestRunner::default()
+///     .run(&(testkit::text(), testkit::style()), |(text, style)| check_paint(&text, style))
+///     .unwrap();
+/// ```
+pub fn check_paint(text: &str, style: Style) -> Result<(), TestCaseError> {
+    let painted = style.paint(text);
+    check_styling(text, &painted)?;
+    prop_assert_eq!(
+        StyledText::styled(text, style).width(),
+        ansi::visible_width(&painted),
+        "the styled document and the painted text differ in width"
+    );
+    Ok(())
+}
+
+/// Checks that parsing the rendering of `document` with [`StyledText::from_ansi`] gives the document
+/// back, its styles as rendered under the global [`control`](crate::control) settings: plain when
+/// escape sequences are disabled, downgraded to the color level otherwise.
+///
+/// The check is only meaningful with a backend writing SGR sequences, such as the default
+/// [`Ansi`](crate::backend::Ansi) backend.
+///
+/// # Errors
+///
+/// Returns a failure describing the first invariant that does not hold.
+///
+/// # Examples
+///
+/// ```rust
+/// use bytescolor::testkit::{self, check_roundtrip};
+/// use bytescolor::testkit::proptest::test_runner::TestRunner;
+///
+/// TestRunner::default()
+///     .run(&testkit::styled_text(), |document| check_roundtrip(&document))
+///     .unwrap();
+/// ```
+pub fn check_roundtrip(document: &StyledText) -> Result<(), TestCaseError> {
+    let rendered = document.render();
+    check_styling(&document.visible(), &rendered)?;
+    let backend = backend::backend();
+    let mut expected = StyledText::new();
+    for span in document.spans() {
+        expected.push_span(Span::new(span.text(), span.style().effective(backend)));
+    }
+    prop_assert_eq!(
+        StyledText::from_ansi(&rendered),
+        expected,
+        "parsing {:?} did not give the document back",
+        rendered
+    );
+    Ok(())
+}
+
+/// Checks that [`ansi::truncate`] fits `text` in `max` columns, keeps a prefix of its visible text,
+/// and leaves text that fits untouched.
+///
+/// # Errors
+///
+/// Returns a failure describing the first invariant that does not hold.
+///
+/// # Examples
+///
+/// ```rust
+/// use bytescolor::testkit::{self, check_truncate};
+/// use bytescolor::testkit::proptest::test_runner::TestRunner;
+///
+/// TestRunner::default()
+///     .run(&(testkit::ansi_text(), 0..40_usize), |(text, max)| check_truncate(&text, max))
+///     .unwrap();
+/// ```
+pub fn check_truncate(text: &str, max: usize) -> Result<(), TestCaseError> {
+    let truncated = ansi::truncate(text, max);
+    let width = ansi::visible_width(&truncated);
+    prop_assert!(
+        width <= max,
+        "truncated to {} columns instead of {}",
+        width,
+        max
+    );
+    let (visible, kept) = (ansi::strip(text), ansi::strip(&truncated));
+    prop_assert!(
+        visible.starts_with(&*kept),
+        "{:?} is not a prefix of {:?}",
+        kept,
+        visible
+    );
+    if ansi::visible_width(text) <= max {
+        prop_assert_eq!(&*truncated, text, "text that fits was changed");
+    }
+    Ok(())
+}
@@ -0,0 +1,873 @@
+//! # Styled Text
+//!
+//! [`StyledText`] is a document made of [`Span`]s, runs of text sharing one [`Style`]. Unlike a rendered
+//! string, it keeps text and styling apart, so it can be concatenated, sliced and restyled by visible
+//! (character) index without parsing escape sequences back, and is rendered only once at the end.
+//!
+//! ```rust
+//! use bytescolor::{ansi, Color, Style, StyledText};
+//!
+//! let mut line = StyledText::plain("status: ");
+//! line.push("failed", Style::new().fg(Color::Red).bold());
+//! line.push(" (3 tests)", Style::new().dim());
+//!
+//! assert_eq!(line.len(), 24);
+//! assert_eq!(line.slice(8..14).visible(), "failed");
+//! assert_eq!(ansi::strip(&line.render()), "status: failed (3 tests)");
+//! ```
+//!
+//! ## Serialization
+//!
+//! With the `serde` feature, [`StyledText`], [`Span`], [`Style`] and [`Color`](crate::Color) implement
+//! `Serialize`, so styled output can be shipped to web frontends or log aggregators that render the
+//! styling themselves. A document is a sequence of spans, each a map of its text and the style
+//! attributes that are set; standard colors are names, palette colors indices and RGB colors `#rrggbb`
+//! strings.
+//!
+//! ```rust
+//! # #[cfg(feature = "serde")] {
+//! use bytescolor::{Color, Style, StyledText};
+//!
+//! let mut line = StyledText::plain("status: ");
+//! line.push("failed", Style::new().fg(Color::Red).bold());
+//! assert_eq!(
+//!     serde_json::to_string(&line).unwrap(),
+//!     r#"[{"text":"status: "},{"text":"failed","fg":"red","bold":true}]"#
+//! );
+//! # }
+//! ```
+
+use std::fmt;
+use std::ops::{Add, AddAssign, Bound, Range, RangeBounds};
+
+use crate::control::{self, ColorChoice, ColorLevel, Config};
+use crate::{ansi, styled, Style, StyledString};
+
+const ELLIPSIS: Style = Style::new().dim();
+
+/// A run of text rendered in a single style.
+#[derive(Debug, Clone, PartialEq, Eq, Hash, Default)]
+pub struct Span {
+    text: String,
+    style: Style,
+}
+
+impl Span {
+    /// Creates a span of `text` in `style`.
+    pub fn new(text: impl Into<String>, style: Style) -> Self {
+        Span {
+            text: text.into(),
+            style,
+        }
+    }
+
+    /// Returns the text of the span.
+    pub fn text(&self) -> &str {
+        &self.text
+    }
+
+    /// Returns the style of the span.
+    pub fn style(&self) -> Style {
+        self.style
+    }
+
+    /// Returns the number of characters in the span.
+    pub fn len(&self) -> usize {
+        self.text.chars().count()
+    }
+
+    /// Returns the number of terminal columns the span occupies, see
+    /// [`ansi::visible_width`](crate::ansi::visible_width).
+    pub fn width(&self) -> usize {
+        ansi::text_width(&self.text)
+    }
+
+    /// Returns `true` if the span has no text.
+    pub fn is_empty(&self) -> bool {
+        self.text.is_empty()
+    }
+
+    /// Renders the span with its escape sequences.
+    pub fn render(&self) -> String {
+        self.style.wrap(&self.text)
+    }
+}
+
+/// A sequence of styled spans.
+///
+/// Adjacent spans with the same style are merged and empty spans are dropped, so two documents with
+/// the same text and styling always have the same spans.
+/// Comparisons with strings and [`StyledString`] ignore the styling, while two `StyledText`s are
+/// equal only if their spans are.
+#[derive(Debug, Clone, PartialEq, Eq, Hash, Default)]
+pub struct StyledText {
+    spans: Vec<Span>,
+}
+
+impl StyledText {
+    /// Creates an empty document.
+    pub fn new() -> Self {
+        StyledText { spans: Vec::new() }
+    }
+
+    /// Creates a document holding `text` in the plain style.
+    pub fn plain(text: impl Into<String>) -> Self {
+        StyledText::styled(text, Style::new())
+    }
+
+    /// Creates a document holding `text` in `style`.
+    pub fn styled(text: impl Into<String>, style: Style) -> Self {
+        let mut document = StyledText::new();
+        document.push(text, style);
+        document
+    }
+
+    /// Parses text containing ANSI escape sequences, as produced by this crate or any other program,
+    /// into a document. SGR sequences set the style of the following text; other escape sequences are
+    /// dropped.
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// # if cfg!(feature = "no-color-build") { return; }
+    /// use bytescolor::{ByteColor, Color, Style, StyledText};
+    ///
+    /// let output = format!("{} {}", "error:".red(), "disk full");
+    /// let text = StyledText::from_ansi(&output);
+    /// assert_eq!(text.spans()[0].text(), "error:");
+    /// assert_eq!(text.spans()[0].style(), Style::new().fg(Color::Red));
+    /// assert_eq!(text.spans()[1].text(), " disk full");
+    /// ```
+    pub fn from_ansi(text: &str) -> Self {
+        let bytes = text.as_bytes();
+        let mut document = StyledText::new();
+        let mut style = Style::new();
+        let mut pos = 0;
+        while let Some(offset) = bytes[pos..].iter().position(|&b| b == 0x1b) {
+            let esc = pos + offset;
+            document.push(&text[pos..esc], style);
+            let len = ansi::sequence_len(&bytes[esc..]);
+            let sequence = &bytes[esc..esc + len];
+            if let Some(params) = sequence
+                .strip_prefix(b"\x1b[")
+                .and_then(|rest| rest.strip_suffix(b"m"))
+            {
+                let params: Vec<u16> = params
+                    .split(|&b| b == b';' || b == b':')
+                    .map(|param| {
+                        std::str::from_utf8(param)
+                            .ok()
+                            .and_then(|param| param.parse().ok())
+                            .unwrap_or(0)
+                    })
+                    .collect();
+                style = style.apply_sgr(&params);
+            }
+            pos = esc + len;
+            while !text.is_char_boundary(pos) {
+                pos += 1;
+            }
+        }
+        document.push(&text[pos..], style);
+        document
+    }
+
+    /// Appends `text` in `style`.
+    pub fn push(&mut self, text: impl Into<String>, style: Style) {
+        self.push_span(Span::new(text, style));
+    }
+
+    /// Appends a span.
+    pub fn push_span(&mut self, span: Span) {
+        if span.is_empty() {
+            return;
+        }
+        match self.spans.last_mut() {
+            Some(last) if last.style == span.style => last.text.push_str(&span.text),
+            _ => self.spans.push(span),
+        }
+    }
+
+    /// Appends all spans of `other`.
+    pub fn append(&mut self, other: StyledText) {
+        for span in other.spans {
+            self.push_span(span);
+        }
+    }
+
+    /// Returns the spans of the document.
+    pub fn spans(&self) -> &[Span] {
+        &self.spans
+    }
+
+    /// Returns the number of visible characters.
+    pub fn len(&self) -> usize {
+        self.spans.iter().map(Span::len).sum()
+    }
+
+    /// Returns the number of terminal columns the document occupies, see
+    /// [`ansi::visible_width`](crate::ansi::visible_width).
+    pub fn width(&self) -> usize {
+        self.spans.iter().map(Span::width).sum()
+    }
+
+    /// Returns `true` if the document has no text.
+    pub fn is_empty(&self) -> bool {
+        self.spans.is_empty()
+    }
+
+    /// Returns the text without any styling.
+    pub fn visible(&self) -> String {
+        self.spans.iter().map(|span| span.text.as_str()).collect()
+    }
+
+    /// Returns `true` if `other` shows the same text, whatever the styling of either.
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// use bytescolor::{Style, StyledText};
+    ///
+    /// let mut text = StyledText::styled("a", Style::BOLD);
+    /// text.push("b", Style::RED);
+    /// assert!(text.eq_visible(&StyledText::plain("ab")));
+    /// assert_ne!(text, StyledText::plain("ab"));
+    /// ```
+    pub fn eq_visible(&self, other: &StyledText) -> bool {
+        let mut ours = self.spans.iter().flat_map(|span| span.text.chars());
+        let mut theirs = other.spans.iter().flat_map(|span| span.text.chars());
+        loop {
+            match (ours.next(), theirs.next()) {
+                (None, None) => return true,
+                (a, b) if a != b => return false,
+                _ => {}
+            }
+        }
+    }
+
+    /// Returns a hash of the visible text, equal for documents that are
+    /// [`eq_visible`](Self::eq_visible) and to the
+    /// [`StyledString::hash_visible`](crate::StyledString::hash_visible) of the same text.
+    pub fn hash_visible(&self) -> u64 {
+        styled::hash_visible(&self.visible())
+    }
+
+    /// Returns the part of the document covering the visible characters in `range`, styles included.
+    ///
+    /// Out-of-range bounds are clamped to the document.
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// use bytescolor::{Color, Style, StyledText};
+    ///
+    /// let mut text = StyledText::styled("red", Style::new().fg(Color::Red));
+    /// text.push("blue", Style::new().fg(Color::Blue));
+    /// let middle = text.slice(1..5);
+    /// assert_eq!(middle.visible(), "edbl");
+    /// assert_eq!(middle.spans().len(), 2);
+    /// assert_eq!(text.slice(3..=usize::MAX).visible(), "blue");
+    /// ```
+    pub fn slice(&self, range: impl RangeBounds<usize>) -> StyledText {
+        let range = self.resolve(range);
+        let mut out = StyledText::new();
+        let mut offset = 0;
+        for span in &self.spans {
+            let len = span.len();
+            let start = range.start.max(offset).min(offset + len) - offset;
+            let end = range.end.max(offset).min(offset + len) - offset;
+            if start < end {
+                out.push(char_slice(&span.text, start..end), span.style);
+            }
+            offset += len;
+        }
+        out
+    }
+
+    /// Replaces the style of the visible characters in `range`.
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// use bytescolor::{ansi, Color, Style, StyledText};
+    ///
+    /// let mut text = StyledText::plain("a needle here");
+    /// text.restyle_range(2..8, Style::new().fg(Color::Yellow));
+    /// assert_eq!(text.spans()[1].text(), "needle");
+    /// assert_eq!(ansi::strip(&text.render()), "a needle here");
+    /// ```
+    pub fn restyle_range(&mut self, range: impl RangeBounds<usize>, style: Style) {
+        let range = self.resolve(range);
+        if range.start >= range.end {
+            return;
+        }
+        let mut out = self.slice(..range.start);
+        out.push(self.slice(range.clone()).visible(), style);
+        out.append(self.slice(range.end..));
+        *self = out;
+    }
+
+    /// Replaces the visible characters in `range` with `replacement`, which keeps its own styling: pass
+    /// a plain string to insert unstyled text. An empty range inserts at its start.
+    ///
+    /// Out-of-range bounds are clamped to the document.
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// use bytescolor::{Color, Style, StyledText};
+    ///
+    /// let mut line = StyledText::plain("state: ");
+    /// line.push("running", Style::new().fg(Color::Yellow));
+    /// line.replace_range_visible(7.., StyledText::styled("done", Style::new().fg(Color::Green)));
+    /// assert_eq!(line.visible(), "state: done");
+    /// assert_eq!(line.spans()[1].style(), Style::new().fg(Color::Green));
+    ///
+    /// line.replace_range_visible(0..5, "status");
+    /// assert_eq!(line.visible(), "status: done");
+    /// ```
+    pub fn replace_range_visible(
+        &mut self,
+        range: impl RangeBounds<usize>,
+        replacement: impl Into<StyledText>,
+    ) {
+        let range = self.resolve(range);
+        let mut out = self.slice(..range.start);
+        out.append(replacement.into());
+        out.append(self.slice(range.end.max(range.start)..));
+        *self = out;
+    }
+
+    /// Shortens the document to at most `max` columns by replacing its middle with a dimmed `…`,
+    /// keeping its start and end, which identify file paths and URLs best; an odd column goes to the
+    /// end. Grapheme clusters are never split, and the kept text keeps its styles. A document that
+    /// fits is returned unchanged.
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// use bytescolor::{Color, Style, StyledText};
+    ///
+    /// let mut path = StyledText::plain("/home/user/projects/");
+    /// path.push("main.rs", Style::new().fg(Color::Cyan));
+    /// let short = path.truncate_middle_visible(16);
+    /// assert_eq!(short.visible(), "/home/u…/main.rs");
+    /// assert_eq!(short.spans().last().unwrap().style(), Style::new().fg(Color::Cyan));
+    /// assert_eq!(path.truncate_middle_visible(40), path);
+    /// ```
+    pub fn truncate_middle_visible(&self, max: usize) -> StyledText {
+        if self.width() <= max {
+            return self.clone();
+        }
+        if max == 0 {
+            return StyledText::new();
+        }
+        let visible = self.visible();
+        let clusters: Vec<(usize, usize)> = ansi::graphemes(&visible)
+            .map(|cluster| (cluster.chars().count(), ansi::text_width(cluster)))
+            .collect();
+        let available = max - ansi::text_width("…");
+        let (mut head, mut head_width) = (0, 0);
+        for &(chars, width) in &clusters {
+            if head_width + width > available / 2 {
+                break;
+            }
+            head += chars;
+            head_width += width;
+        }
+        let (mut tail, mut tail_width) = (0, 0);
+        for &(chars, width) in clusters.iter().rev() {
+            if head_width + tail_width + width > available {
+                break;
+            }
+            tail += chars;
+            tail_width += width;
+        }
+        let mut out = self.slice(..head);
+        out.push("…", ELLIPSIS);
+        out.append(self.slice(self.len() - tail..));
+        out
+    }
+
+    /// Returns the document with each grapheme cluster (see the `unicode-segmentation` feature) styled
+    /// by `style`, called with the index of the cluster and its text. The returned style is combined
+    /// with the existing one (see [`Style`]'s `|` operator).
+    ///
+    /// Clusters are never split, so an emoji or a letter with combining marks gets a single style.
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// use bytescolor::{Style, StyledText};
+    ///
+    /// let text = StyledText::plain("e\u{301}h").style_graphemes(|index, _| {
+    ///     if index % 2 == 0 { Style::RED } else { Style::BLUE }
+    /// });
+    /// assert_eq!(text.spans()[0].text(), "e\u{301}");
+    /// assert_eq!(text.spans()[1].style(), Style::BLUE);
+    /// ```
+    pub fn style_graphemes(&self, mut style: impl FnMut(usize, &str) -> Style) -> StyledText {
+        let mut out = StyledText::new();
+        let mut index = 0;
+        for span in &self.spans {
+            for grapheme in ansi::graphemes(&span.text) {
+                out.push(grapheme, span.style | style(index, grapheme));
+                index += 1;
+            }
+        }
+        out
+    }
+
+    /// Returns the document with each character styled by `style`, called with the visible index of
+    /// the character and the character itself. A returned style is combined with the existing one (see
+    /// [`Style`]'s `|` operator); characters for which `style` returns `None` are left as they are.
+    ///
+    /// This splits combining marks and emoji sequences from their base character; use
+    /// [`style_graphemes`](StyledText::style_graphemes) for text that may contain them.
+    ///
+    /// # Examples
+    ///
+    /// Highlighting the part of a line past a column limit:
+    ///
+    /// ```rust
+    /// use bytescolor::{Color, Style, StyledText};
+    ///
+    /// let over = Style::new().bg(Color::Red);
+    /// let line = StyledText::plain("let total = 42;");
+    /// let text = line.style_chars(|index, _| (index >= 12).then_some(over));
+    /// assert_eq!(text.spans()[0].text(), "let total = ");
+    /// assert_eq!(text.spans()[1].text(), "42;");
+    /// assert_eq!(text.spans()[1].style(), over);
+    /// ```
+    pub fn style_chars(&self, mut style: impl FnMut(usize, char) -> Option<Style>) -> StyledText {
+        let mut out = StyledText::new();
+        let mut index = 0;
+        for span in &self.spans {
+            for c in span.text.chars() {
+                let own = style(index, c).map_or(span.style, |style| span.style | style);
+                out.push(c, own);
+                index += 1;
+            }
+        }
+        out
+    }
+
+    /// Returns the document with `prefix` prepended to every line, empty lines included, so a gutter
+    /// such as a colored `│ ` stays continuous. The styles of the document carry over the prefixes: a
+    /// span spanning several lines keeps its style on each of them, and the prefix keeps its own.
+    ///
+    /// # Examples
+    ///
+    /// Quoting the output of a subprocess:
+    ///
+    /// ```rust
+    /// use bytescolor::{ansi, Style, StyledText};
+    ///
+    /// let output = StyledText::styled("warning: unused\n\n  --> main.rs", Style::YELLOW);
+    /// let quoted = output.prefix_lines(StyledText::styled("│ ", Style::DIM));
+    /// assert_eq!(quoted.visible(), "│ warning: unused\n│ \n│   --> main.rs");
+    /// assert_eq!(quoted.spans()[1].style(), Style::YELLOW);
+    /// assert_eq!(ansi::strip(&quoted.render()), quoted.visible());
+    /// ```
+    pub fn prefix_lines(&self, prefix: impl Into<StyledText>) -> StyledText {
+        self.prefix_with(&prefix.into(), true)
+    }
+
+    /// Returns the document with every non-empty line indented by `n` unstyled spaces. Empty lines are
+    /// left empty, so no trailing whitespace is introduced.
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// use bytescolor::{Style, StyledText};
+    ///
+    /// let text = StyledText::styled("a\n\nb\n", Style::RED).indent(2);
+    /// assert_eq!(text.visible(), "  a\n\n  b\n");
+    /// assert_eq!(text.spans()[0].style(), Style::new());
+    /// ```
+    pub fn indent(&self, n: usize) -> StyledText {
+        self.prefix_with(&StyledText::plain(" ".repeat(n)), false)
+    }
+
+    /// Splits the document on the visible occurrences of `pattern`, like [`str::split`], each piece
+    /// keeping the styles of its text, so it can be processed and rendered on its own.
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// # if cfg!(feature = "no-color-build") { return; }
+    /// use bytescolor::{Style, StyledText};
+    ///
+    /// let text = StyledText::styled("a,b,c", Style::RED);
+    /// let fields = text.split_visible(",");
+    /// assert_eq!(fields.len(), 3);
+    /// assert_eq!(fields[1].render(), "\x1b[31mb\x1b[0m");
+    /// ```
+    pub fn split_visible(&self, pattern: &str) -> Vec<StyledText> {
+        let visible = self.visible();
+        self.pieces(&visible, visible.split(pattern))
+    }
+
+    /// Splits the document into lines, like [`str::lines`]: line terminators, `\n` or `\r\n`, are
+    /// dropped, and a final line terminator does not start an empty line. Styles spanning several
+    /// lines are kept on each of them.
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// # if cfg!(feature = "no-color-build") { return; }
+    /// use bytescolor::{Style, StyledText};
+    ///
+    /// let text = StyledText::styled("one\r\ntwo\n", Style::GREEN);
+    /// let lines = text.lines_styled();
+    /// assert_eq!(lines, ["one", "two"]);
+    /// assert_eq!(lines[1].render(), "\x1b[32mtwo\x1b[0m");
+    /// ```
+    pub fn lines_styled(&self) -> Vec<StyledText> {
+        let visible = self.visible();
+        self.pieces(&visible, visible.lines())
+    }
+
+    /// Slices the document at `pieces`, subslices of its `visible` text in order, walking the spans
+    /// once.
+    fn pieces<'a>(&self, visible: &str, pieces: impl Iterator<Item = &'a str>) -> Vec<StyledText> {
+        let mut spans = self.spans.iter().peekable();
+        // The byte offset of the first span left in `spans`.
+        let mut offset = 0;
+        pieces
+            .map(|piece| {
+                let start = piece.as_ptr() as usize - visible.as_ptr() as usize;
+                let end = start + piece.len();
+                let mut out = StyledText::new();
+                while let Some(span) = spans.peek() {
+                    let span_end = offset + span.text.len();
+                    if span_end <= start {
+                        offset = span_end;
+                        spans.next();
+                        continue;
+                    }
+                    if offset >= end {
+                        break;
+                    }
+                    let from = start.max(offset) - offset;
+                    out.push(&span.text[from..end.min(span_end) - offset], span.style);
+                    if span_end > end {
+                        break;
+                    }
+                    offset = span_end;
+                    spans.next();
+                }
+                out
+            })
+            .collect()
+    }
+
+    fn prefix_with(&self, prefix: &StyledText, empty_lines: bool) -> StyledText {
+        let mut out = StyledText::new();
+        let mut line_start = true;
+        for span in &self.spans {
+            for piece in span.text.split_inclusive('\n') {
+                if line_start && (empty_lines || piece != "\n") {
+                    out.append(prefix.clone());
+                }
+                out.push(piece, span.style);
+                line_start = piece.ends_with('\n');
+            }
+        }
+        out
+    }
+
+    /// Renders the document with its escape sequences, changing only what differs between
+    /// consecutive spans.
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// # if cfg!(feature = "no-color-build") { return; }
+    /// use bytescolor::{Style, StyledText};
+    ///
+    /// let mut text = StyledText::styled("warn", Style::YELLOW | Style::BOLD);
+    /// text.push("ing", Style::YELLOW);
+    /// assert_eq!(text.render(), "\x1b[1;33mwarn\x1b[22ming\x1b[0m");
+    /// ```
+    pub fn render(&self) -> String {
+        let mut out = String::new();
+        self.write_spans(&mut out)
+            .expect("writing to a String cannot fail");
+        out
+    }
+
+    /// Renders the document for a target supporting colors up to `level`, whatever the color
+    /// settings of the process, so one document can be rendered in 24-bit color for the terminal and
+    /// in the 16 standard colors for a CI log or an email. Colors beyond `level` are downgraded to
+    /// the closest color of the target; with [`ColorLevel::None`], only text attributes remain.
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// # if cfg!(feature = "no-color-build") { return; }
+    /// use bytescolor::control::ColorLevel;
+    /// use bytescolor::{Color, Style, StyledText};
+    ///
+    /// let text = StyledText::styled("deployed", Style::new().fg(Color::Rgb(255, 95, 0)).bold());
+    /// assert_eq!(text.render_for(ColorLevel::TrueColor), "\x1b[1;38;2;255;95;0mdeployed\x1b[0m");
+    /// assert_eq!(text.render_for(ColorLevel::Ansi16), "\x1b[1;91mdeployed\x1b[0m");
+    /// assert_eq!(text.render_for(ColorLevel::None), "\x1b[1mdeployed\x1b[0m");
+    /// ```
+    pub fn render_for(&self, level: ColorLevel) -> String {
+        let config = Config {
+            choice: ColorChoice::Always,
+            level,
+            ..control::config()
+        };
+        control::with_config(config, || self.render())
+    }
+
+    fn write_spans(&self, f: &mut dyn fmt::Write) -> fmt::Result {
+        let mut current = Style::new();
+        for span in &self.spans {
+            current.write_transition(span.style, f)?;
+            f.write_str(&span.text)?;
+            current = span.style;
+        }
+        current.write_transition(Style::new(), f)
+    }
+
+    fn resolve(&self, range: impl RangeBounds<usize>) -> Range<usize> {
+        let len = self.len();
+        let start = match range.start_bound() {
+            Bound::Included(&start) => start,
+            Bound::Excluded(&start) => start.saturating_add(1),
+            Bound::Unbounded => 0,
+        };
+        let end = match range.end_bound() {
+            Bound::Included(&end) => end.saturating_add(1),
+            Bound::Excluded(&end) => end,
+            Bound::Unbounded => len,
+        };
+        start.min(len)..end.min(len)
+    }
+}
+
+impl fmt::Display for StyledText {
+    /// Renders the document. Width, alignment and precision flags apply to the visible content.
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        if f.width().is_none() && f.precision().is_none() {
+            return self.write_spans(f);
+        }
+        ansi::pad(f, &self.render())
+    }
+}
+
+impl From<Span> for StyledText {
+    fn from(span: Span) -> Self {
+        let mut document = StyledText::new();
+        document.push_span(span);
+        document
+    }
+}
+
+impl From<&str> for StyledText {
+    fn from(text: &str) -> Self {
+        StyledText::plain(text)
+    }
+}
+
+impl From<String> for StyledText {
+    fn from(text: String) -> Self {
+        StyledText::plain(text)
+    }
+}
+
+/// Compares the visible text, like the comparisons of [`StyledString`] with plain strings.
+impl PartialEq<str> for StyledText {
+    fn eq(&self, other: &str) -> bool {
+        self.visible() == other
+    }
+}
+
+impl PartialEq<&str> for StyledText {
+    fn eq(&self, other: &&str) -> bool {
+        self.visible() == *other
+    }
+}
+
+impl PartialEq<String> for StyledText {
+    fn eq(&self, other: &String) -> bool {
+        self.visible() == *other
+    }
+}
+
+/// Compares the visible text of both sides.
+impl PartialEq<StyledString> for StyledText {
+    fn eq(&self, other: &StyledString) -> bool {
+        other.visible() == self.visible()
+    }
+}
+
+impl PartialEq<StyledText> for StyledString {
+    fn eq(&self, other: &StyledText) -> bool {
+        other == self
+    }
+}
+
+/// Parses the escape sequences of the string, see [`StyledText::from_ansi`].
+impl From<StyledString> for StyledText {
+    fn from(text: StyledString) -> Self {
+        StyledText::from_ansi(&text)
+    }
+}
+
+/// Appends spans, documents and strings, merging spans like [`append`](StyledText::append).
+///
+/// # Examples
+///
+/// ```rust
+/// use bytescolor::{ByteColor, Span, Style, StyledText};
+///
+/// let mut line = StyledText::styled("error", Style::RED) + ": " + Span::new("disk full", Style::BOLD);
+/// line += " on /dev/sda".styled(Style::DIM);
+/// assert_eq!(line, "error: disk full on /dev/sda");
+/// assert_eq!(line.spans()[2].style(), Style::BOLD);
+/// ```
+impl<T: Into<StyledText>> Add<T> for StyledText {
+    type Output = StyledText;
+
+    fn add(mut self, other: T) -> StyledText {
+        self.append(other.into());
+        self
+    }
+}
+
+impl<T: Into<StyledText>> AddAssign<T> for StyledText {
+    fn add_assign(&mut self, other: T) {
+        self.append(other.into());
+    }
+}
+
+impl Extend<Span> for StyledText {
+    fn extend<I: IntoIterator<Item = Span>>(&mut self, spans: I) {
+        for span in spans {
+            self.push_span(span);
+        }
+    }
+}
+
+impl Extend<StyledText> for StyledText {
+    fn extend<I: IntoIterator<Item = StyledText>>(&mut self, documents: I) {
+        for document in documents {
+            self.append(document);
+        }
+    }
+}
+
+/// Collects spans into a document, merging adjacent spans of the same style.
+///
+/// # Examples
+///
+/// ```rust
+/// use bytescolor::{Span, Style, StyledText};
+///
+/// let words = ["alpha", "beta", "gamma"];
+/// let text: StyledText = words
+///     .iter()
+///     .enumerate()
+///     .map(|(i, word)| Span::new(format!("{} ", word), if i % 2 == 0 { Style::CYAN } else { Style::new() }))
+///     .collect();
+/// assert_eq!(text, "alpha beta gamma ");
+/// ```
+impl FromIterator<Span> for StyledText {
+    fn from_iter<I: IntoIterator<Item = Span>>(spans: I) -> Self {
+        let mut document = StyledText::new();
+        document.extend(spans);
+        document
+    }
+}
+
+/// Concatenates documents.
+impl FromIterator<StyledText> for StyledText {
+    fn from_iter<I: IntoIterator<Item = StyledText>>(documents: I) -> Self {
+        let mut document = StyledText::new();
+        document.extend(documents);
+        document
+    }
+}
+
+/// Extension trait styling part of a string in place of slicing and joining it by hand.
+///
+/// The string is taken as plain text: escape sequences it contains count as characters and are not
+/// parsed. Out-of-range bounds are clamped to the string.
+pub trait StyleRange {
+    /// Styles the characters in `range`, counted in characters, leaving the rest plain.
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// use bytescolor::text::StyleRange;
+    /// use bytescolor::{ansi, Style};
+    ///
+    /// let line = "naïve match";
+    /// let styled = line.style_range(6.., Style::YELLOW);
+    /// assert_eq!(styled, "naïve match");
+    /// assert_eq!(ansi::strip(&styled.to_string()), line);
+    /// ```
+    fn style_range(&self, range: impl RangeBounds<usize>, style: Style) -> StyledString;
+
+    /// Styles the bytes in `range`, as returned by [`str::find`] or a regex match, leaving the rest
+    /// plain. Bounds inside a character are widened to include it.
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// use bytescolor::text::StyleRange;
+    /// use bytescolor::Style;
+    ///
+    /// let line = "naïve match";
+    /// let start = line.find("match").unwrap();
+    /// let styled = line.style_byte_range(start..start + 5, Style::YELLOW);
+    /// assert_eq!(styled, line);
+    /// assert_eq!(styled, line.style_range(6..11, Style::YELLOW));
+    /// ```
+    fn style_byte_range(&self, range: impl RangeBounds<usize>, style: Style) -> StyledString;
+}
+
+impl StyleRange for str {
+    fn style_range(&self, range: impl RangeBounds<usize>, style: Style) -> StyledString {
+        let mut text = StyledText::plain(self);
+        text.restyle_range(range, style);
+        StyledString::from(text.render())
+    }
+
+    fn style_byte_range(&self, range: impl RangeBounds<usize>, style: Style) -> StyledString {
+        let mut start = match range.start_bound() {
+            Bound::Included(&start) => start,
+            Bound::Excluded(&start) => start.saturating_add(1),
+            Bound::Unbounded => 0,
+        }
+        .min(self.len());
+        let mut end = match range.end_bound() {
+            Bound::Included(&end) => end.saturating_add(1),
+            Bound::Excluded(&end) => end,
+            Bound::Unbounded => self.len(),
+        }
+        .clamp(start, self.len());
+        while !self.is_char_boundary(start) {
+            start -= 1;
+        }
+        while !self.is_char_boundary(end) {
+            end += 1;
+        }
+        let chars = |byte: usize| self[..byte].chars().count();
+        self.style_range(chars(start)..chars(end), style)
+    }
+}
+
+/// Returns the characters of `text` in `range`, counted in characters.
+fn char_slice(text: &str, range: Range<usize>) -> &str {
+    let byte = |index: usize| {
+        text.char_indices()
+            .nth(index)
+            .map_or(text.len(), |(byte, _)| byte)
+    };
+    &text[byte(range.start)..byte(range.end)]
+}
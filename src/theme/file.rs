@@ -0,0 +1,320 @@
+//! Loading of themes from TOML and JSON files.
+//!
+//! Theme files are flat: each role is a key with a style string as value, written as accepted by
+//! [`Theme::with_overrides`]. Only the subset of each format needed for that is parsed, so no parser
+//! dependency is pulled in.
+
+use std::path::Path;
+
+use super::{Theme, ThemeError};
+
+impl Theme {
+    /// Loads a theme from a TOML file; see [`from_toml_str`](Theme::from_toml_str).
+    ///
+    /// This method is available with the `toml` feature.
+    ///
+    /// # Errors
+    ///
+    /// Returns an error if the file cannot be read or is not a valid theme.
+    #[cfg(feature = "toml")]
+    pub fn from_toml(path: impl AsRef<Path>) -> Result<Theme, ThemeError> {
+        Theme::from_toml_str(&read(path.as_ref())?)
+    }
+
+    /// Parses a theme from TOML: `role = "style"` entries in a `[theme]` table, or at the top level
+    /// of a text without one, overriding the default theme. When there is a `[theme]` table, the top
+    /// level and the other tables are skipped, so the theme can live in the configuration file of the
+    /// application.
+    ///
+    /// This method is available with the `toml` feature.
+    ///
+    /// # Errors
+    ///
+    /// Returns an error with the line and, if any, the role it is about, if the text is not a valid
+    /// theme.
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// use bytescolor::theme::Theme;
+    /// use bytescolor::{Color, Style};
+    ///
+    /// let file = "# my colors\n[theme]\nerror = \"bold magenta\"\nmuted = '+italic'\n\n[server]\nport = 80\n";
+    /// let theme = Theme::from_toml_str(file).unwrap();
+    /// assert_eq!(theme.error, Style::new().fg(Color::Magenta).bold());
+    ///
+    /// let error = Theme::from_toml_str("[theme]\nwarning = \"bold orange\"").unwrap_err();
+    /// assert_eq!(error.to_string(), "line 2: invalid theme entry 'warning': unknown color or attribute 'orange'");
+    ///
+    /// let config = "name = \"app\"\nport = 80\n\n[theme]\nerror = \"red\"\n";
+    /// let theme = Theme::from_toml_str(config).unwrap();
+    /// assert_eq!(theme.error, Style::new().fg(Color::Red));
+    ///
+    /// let flat = Theme::from_toml_str("info = \"blue\"").unwrap();
+    /// assert_eq!(flat.info, Style::new().fg(Color::Blue));
+    /// ```
+    #[cfg(feature = "toml")]
+    pub fn from_toml_str(text: &str) -> Result<Theme, ThemeError> {
+        let mut theme = Theme::default();
+        let mut in_theme = !text
+            .lines()
+            .any(|line| toml_table(line.trim()).and_then(Result::ok) == Some("theme"));
+        for (index, line) in text.lines().enumerate() {
+            let number = index + 1;
+            let line = line.trim();
+            if line.is_empty() || line.starts_with('#') {
+                continue;
+            }
+            if let Some(name) = toml_table(line) {
+                let name = name.map_err(|error| error.at_line(number))?;
+                in_theme = name == "theme";
+                continue;
+            }
+            if !in_theme {
+                continue;
+            }
+            let (role, value) = toml_entry(line).map_err(|error| error.at_line(number))?;
+            theme
+                .apply(&role, &value)
+                .map_err(|error| error.at_line(number))?;
+        }
+        Ok(theme)
+    }
+
+    /// Loads a theme from a JSON file; see [`from_json_str`](Theme::from_json_str).
+    ///
+    /// This method is available with the `json` feature.
+    ///
+    /// # Errors
+    ///
+    /// Returns an error if the file cannot be read or is not a valid theme.
+    #[cfg(feature = "json")]
+    pub fn from_json(path: impl AsRef<Path>) -> Result<Theme, ThemeError> {
+        Theme::from_json_str(&read(path.as_ref())?)
+    }
+
+    /// Parses a theme from a JSON object of `"role": "style"` members, overriding the default theme.
+    ///
+    /// This method is available with the `json` feature.
+    ///
+    /// # Errors
+    ///
+    /// Returns an error with the line and, if any, the role it is about, if the text is not a valid
+    /// theme.
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// use bytescolor::theme::Theme;
+    /// use bytescolor::{Color, Style};
+    ///
+    /// let theme = Theme::from_json_str(r#"{ "error": "bold magenta", "info": "blue" }"#).unwrap();
+    /// assert_eq!(theme.info, Style::new().fg(Color::Blue));
+    ///
+    /// let error = Theme::from_json_str("{\n  \"eror\": \"red\"\n}").unwrap_err();
+    /// assert_eq!((error.key(), error.line()), (Some("eror"), Some(2)));
+    ///
+    /// let error = Theme::from_json_str(r#"{ "\ud83d\ude00": "red" }"#).unwrap_err();
+    /// assert_eq!(error.key(), Some("😀"));
+    /// ```
+    #[cfg(feature = "json")]
+    pub fn from_json_str(text: &str) -> Result<Theme, ThemeError> {
+        let mut theme = Theme::default();
+        let mut parser = JsonParser { text, pos: 0 };
+        parser.expect(b'{')?;
+        if !parser.eat(b'}') {
+            loop {
+                let (line, role) = (parser.line(), parser.string()?);
+                parser.expect(b':')?;
+                if parser.peek() != Some(b'"') {
+                    return Err(
+                        ThemeError::entry(&role, "expected a string").at_line(parser.line())
+                    );
+                }
+                let value = parser.string()?;
+                theme
+                    .apply(&role, &value)
+                    .map_err(|error| error.at_line(line))?;
+                if parser.eat(b'}') {
+                    break;
+                }
+                parser.expect(b',')?;
+            }
+        }
+        if parser.peek().is_some() {
+            return Err(parser.error("unexpected text after the object"));
+        }
+        Ok(theme)
+    }
+}
+
+fn read(path: &Path) -> Result<String, ThemeError> {
+    std::fs::read_to_string(path).map_err(|error| {
+        ThemeError::syntax(format!(
+            "cannot read theme file '{}': {}",
+            path.display(),
+            error
+        ))
+    })
+}
+
+/// Returns the name of the table of a TOML `[table]` header line, or `None` if `line` is not one.
+#[cfg(feature = "toml")]
+fn toml_table(line: &str) -> Option<Result<&str, ThemeError>> {
+    let header = line.strip_prefix('[')?;
+    Some(
+        header
+            .split_once(']')
+            .map(|(name, _)| name.trim())
+            .ok_or_else(|| ThemeError::syntax("unclosed table header")),
+    )
+}
+
+/// Splits a TOML `key = "value"` line into its key and string value.
+#[cfg(feature = "toml")]
+fn toml_entry(line: &str) -> Result<(String, String), ThemeError> {
+    let (key, rest) = match line.strip_prefix('"') {
+        Some(quoted) => toml_string(quoted, '"')?,
+        None => {
+            let end = line
+                .find(|c: char| !(c.is_ascii_alphanumeric() || c == '_' || c == '-'))
+                .unwrap_or(line.len());
+            (line[..end].to_string(), &line[end..])
+        }
+    };
+    if key.is_empty() {
+        return Err(ThemeError::syntax("expected a key"));
+    }
+    let Some(rest) = rest.trim_start().strip_prefix('=') else {
+        return Err(ThemeError::entry(&key, "expected '='"));
+    };
+    let rest = rest.trim_start();
+    let (value, rest) = match rest.chars().next() {
+        Some(quote @ ('"' | '\'')) => toml_string(&rest[1..], quote)
+            .map_err(|error| ThemeError::entry(&key, error.message))?,
+        _ => return Err(ThemeError::entry(&key, "expected a string")),
+    };
+    let rest = rest.trim_start();
+    if !rest.is_empty() && !rest.starts_with('#') {
+        return Err(ThemeError::entry(&key, "unexpected text after the value"));
+    }
+    Ok((key, value))
+}
+
+/// Reads a TOML string whose opening `quote` has been consumed, returning it with the rest of the line.
+/// Basic (`"`) strings support the common escapes; literal (`'`) strings have none.
+#[cfg(feature = "toml")]
+fn toml_string(text: &str, quote: char) -> Result<(String, &str), ThemeError> {
+    let mut out = String::new();
+    let mut chars = text.char_indices();
+    while let Some((index, c)) = chars.next() {
+        match c {
+            c if c == quote => return Ok((out, &text[index + 1..])),
+            '\\' if quote == '"' => match chars.next() {
+                Some((_, 'n')) => out.push('\n'),
+                Some((_, 't')) => out.push('\t'),
+                Some((_, escaped @ ('"' | '\\'))) => out.push(escaped),
+                _ => return Err(ThemeError::syntax("invalid escape sequence")),
+            },
+            c => out.push(c),
+        }
+    }
+    Err(ThemeError::syntax("unterminated string"))
+}
+
+/// Reads the four hex digits of a JSON `\u` escape.
+#[cfg(feature = "json")]
+fn hex_unit(chars: &mut impl Iterator<Item = (usize, char)>) -> Option<u32> {
+    let hex: String = chars.take(4).map(|(_, c)| c).collect();
+    u32::from_str_radix(&hex, 16).ok()
+}
+
+/// A cursor over the JSON text of a theme.
+#[cfg(feature = "json")]
+struct JsonParser<'a> {
+    text: &'a str,
+    pos: usize,
+}
+
+#[cfg(feature = "json")]
+impl JsonParser<'_> {
+    /// Skips whitespace and returns the next byte, without consuming it.
+    fn peek(&mut self) -> Option<u8> {
+        let rest = &self.text[self.pos..];
+        self.pos += rest.len() - rest.trim_start().len();
+        self.text.as_bytes().get(self.pos).copied()
+    }
+
+    /// Consumes `byte` if it comes next.
+    fn eat(&mut self, byte: u8) -> bool {
+        let found = self.peek() == Some(byte);
+        if found {
+            self.pos += 1;
+        }
+        found
+    }
+
+    fn expect(&mut self, byte: u8) -> Result<(), ThemeError> {
+        if self.eat(byte) {
+            Ok(())
+        } else {
+            Err(self.error(format!("expected '{}'", char::from(byte))))
+        }
+    }
+
+    /// Parses a string, supporting all JSON escapes, surrogate pairs included.
+    fn string(&mut self) -> Result<String, ThemeError> {
+        self.expect(b'"')?;
+        let mut out = String::new();
+        let mut chars = self.text[self.pos..].char_indices();
+        while let Some((index, c)) = chars.next() {
+            match c {
+                '"' => {
+                    self.pos += index + 1;
+                    return Ok(out);
+                }
+                '\\' => {
+                    let escaped = match chars.next().map(|(_, c)| c) {
+                        Some('n') => '\n',
+                        Some('t') => '\t',
+                        Some('r') => '\r',
+                        Some('b') => '\u{8}',
+                        Some('f') => '\u{c}',
+                        Some(c @ ('"' | '\\' | '/')) => c,
+                        Some('u') => {
+                            let code = match hex_unit(&mut chars) {
+                                // A high surrogate, combined with the low one that must follow.
+                                Some(high @ 0xd800..=0xdbff) => {
+                                    let low = match (chars.next(), chars.next()) {
+                                        (Some((_, '\\')), Some((_, 'u'))) => hex_unit(&mut chars),
+                                        _ => None,
+                                    };
+                                    low.filter(|low| (0xdc00..=0xdfff).contains(low))
+                                        .map(|low| {
+                                            0x10000 + ((high - 0xd800) << 10) + (low - 0xdc00)
+                                        })
+                                }
+                                code => code,
+                            };
+                            code.and_then(char::from_u32)
+                                .ok_or_else(|| self.error("invalid unicode escape"))?
+                        }
+                        _ => return Err(self.error("invalid escape sequence")),
+                    };
+                    out.push(escaped);
+                }
+                c => out.push(c),
+            }
+        }
+        Err(self.error("unterminated string"))
+    }
+
+    /// Returns the line of the cursor, counting from 1.
+    fn line(&self) -> usize {
+        self.text[..self.pos].matches('\n').count() + 1
+    }
+
+    fn error(&self, message: impl std::fmt::Display) -> ThemeError {
+        ThemeError::syntax(message).at_line(self.line())
+    }
+}
@@ -0,0 +1,426 @@
+//! # Themes
+//!
+//! A [`Theme`] names the styles of an application by role (errors, warnings, headings, literals, ...)
+//! rather than by color, so a program can define its palette once and use it for everything it prints.
+//!
+//! ```rust
+//! use bytescolor::theme::Theme;
+//! use bytescolor::{Color, Style};
+//!
+//! let theme = Theme {
+//!     error: Style::new().fg(Color::BrightRed).bold(),
+//!     ..Theme::default()
+//! };
+//! eprintln!("{} disk full", theme.error.paint("error:"));
+//! ```
+//!
+//! End users can override the theme of a program without code changes through the
+//! `BYTESCOLOR_THEME` environment variable (see [`Theme::from_env`]), a list of `role=style` entries
+//! separated by `:`, such as `error=bold red:warn=yellow:muted=+italic`. A style starting with `+` is
+//! added to the style of the role instead of replacing it.
+//!
+//! The theme installed with [`set_theme`] is part of the global [`Config`](control::Config) and is
+//! returned by [`current`]; [`with_theme`] overrides it for the current thread only.
+//!
+//! With the `toml` and `json` features, themes can also be loaded from user-editable files with
+//! [`Theme::from_toml`] and [`Theme::from_json`], errors pointing to the offending line and role.
+//!
+//! With the `clap` feature, [`Theme::clap_styles`] exports the theme as the styles of `clap`'s help and
+//! error output, so the help text matches the rest of the program.
+//!
+//! ```rust
+//! # #[cfg(feature = "clap")] {
+//! use bytescolor::theme::Theme;
+//!
+//! let command = clap::Command::new("tool").styles(Theme::default().clap_styles());
+//! # }
+//! ```
+
+use std::error::Error;
+use std::fmt;
+
+use crate::style::StyleIssue;
+use crate::{control, Color, Style};
+
+#[cfg(any(feature = "json", feature = "toml"))]
+mod file;
+
+/// The environment variable read by [`Theme::from_env`].
+pub const THEME_VAR: &str = "BYTESCOLOR_THEME";
+
+/// The styles of an application, by role.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub struct Theme {
+    /// Error messages and their `error:` label.
+    pub error: Style,
+    /// Warnings.
+    pub warning: Style,
+    /// Successful outcomes.
+    pub success: Style,
+    /// Informational messages.
+    pub info: Style,
+    /// Section headings, such as `Options:` in a help text.
+    pub heading: Style,
+    /// Usage lines.
+    pub usage: Style,
+    /// Literal text the user can type, such as flags and subcommands.
+    pub literal: Style,
+    /// Placeholders the user replaces, such as `<FILE>`.
+    pub placeholder: Style,
+    /// A valid or suggested value, as in "did you mean ...".
+    pub valid: Style,
+    /// An invalid value given by the user.
+    pub invalid: Style,
+    /// Secondary text such as hints, defaults and timestamps.
+    pub muted: Style,
+}
+
+/// The theme returned by [`Theme::default`].
+pub(crate) const DEFAULT_THEME: Theme = Theme {
+    error: Style::new().fg(Color::Red).bold(),
+    warning: Style::new().fg(Color::Yellow).bold(),
+    success: Style::new().fg(Color::Green).bold(),
+    info: Style::new().fg(Color::Cyan),
+    heading: Style::new().fg(Color::Green).bold().underline(),
+    usage: Style::new().fg(Color::Green).bold().underline(),
+    literal: Style::new().fg(Color::Cyan).bold(),
+    placeholder: Style::new().fg(Color::Cyan),
+    valid: Style::new().fg(Color::Green),
+    invalid: Style::new().fg(Color::Yellow),
+    muted: Style::new().dim(),
+};
+
+impl Default for Theme {
+    fn default() -> Self {
+        DEFAULT_THEME
+    }
+}
+
+/// Returns the theme in effect on the current thread, part of the [`Config`](control::Config): the
+/// default theme unless the application installed one with [`set_theme`] or the thread is inside a
+/// [`with_theme`] scope. The formatters of the crate, such as
+/// [`UsageError`](crate::usage::UsageError), start from this theme.
+pub fn current() -> Theme {
+    control::config().theme
+}
+
+/// Installs `theme` as the global theme.
+///
+/// # Examples
+///
+/// ```rust
+/// use bytescolor::theme::{self, Theme};
+///
+/// theme::set_theme(Theme::from_env().unwrap_or_default());
+/// ```
+pub fn set_theme(theme: Theme) {
+    control::update_config(|config| config.theme = theme);
+}
+
+/// Runs `f` with `theme` as the theme of the current thread, and returns its result; see
+/// [`control::with_config`].
+///
+/// # Examples
+///
+/// ```rust
+/// use bytescolor::theme::{self, Theme};
+/// use bytescolor::Style;
+///
+/// let quiet = Theme { error: Style::new().bold(), ..Theme::default() };
+/// theme::with_theme(quiet, || assert_eq!(theme::current().error, Style::new().bold()));
+/// assert_eq!(theme::current(), Theme::default());
+/// ```
+pub fn with_theme<R>(theme: Theme, f: impl FnOnce() -> R) -> R {
+    control::with_config(
+        control::Config {
+            theme,
+            ..control::config()
+        },
+        f,
+    )
+}
+
+impl Theme {
+    /// Returns the default theme with the overrides of the [`THEME_VAR`] environment variable applied,
+    /// or the default theme if it is unset.
+    ///
+    /// # Errors
+    ///
+    /// Returns an error naming the offending entry if the variable is not a valid list of overrides
+    /// (see [`with_overrides`](Theme::with_overrides)). Programs usually warn and fall back to the
+    /// default theme rather than fail:
+    ///
+    /// ```rust
+    /// use bytescolor::theme::Theme;
+    ///
+    /// let theme = Theme::from_env().unwrap_or_else(|error| {
+    ///     eprintln!("warning: {}", error);
+    ///     Theme::default()
+    /// });
+    /// ```
+    pub fn from_env() -> Result<Theme, ThemeError> {
+        match std::env::var(THEME_VAR) {
+            Ok(spec) => Theme::default().with_overrides(&spec),
+            Err(_) => Ok(Theme::default()),
+        }
+    }
+
+    /// Returns the problems of the styles of the theme (see [`Style::validate`]), with the name of
+    /// the role they affect, so programs can warn about unreadable user themes.
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// use bytescolor::theme::Theme;
+    ///
+    /// assert!(Theme::default().lint().is_empty());
+    ///
+    /// let theme = Theme::default().with_overrides("muted=black:error=blink red").unwrap();
+    /// for (role, issue) in theme.lint() {
+    ///     eprintln!("warning: theme role '{}': {}", role, issue);
+    /// }
+    /// assert_eq!(theme.lint().len(), 2);
+    /// ```
+    pub fn lint(&self) -> Vec<(&'static str, StyleIssue)> {
+        self.roles()
+            .into_iter()
+            .flat_map(|(role, style)| style.validate().into_iter().map(move |issue| (role, issue)))
+            .collect()
+    }
+
+    /// Returns the styles of the theme with the names of their roles.
+    fn roles(&self) -> [(&'static str, Style); 11] {
+        [
+            ("error", self.error),
+            ("warning", self.warning),
+            ("success", self.success),
+            ("info", self.info),
+            ("heading", self.heading),
+            ("usage", self.usage),
+            ("literal", self.literal),
+            ("placeholder", self.placeholder),
+            ("valid", self.valid),
+            ("invalid", self.invalid),
+            ("muted", self.muted),
+        ]
+    }
+
+    /// Returns the theme with the style of the roles named in `spec` overridden. `spec` is a list of
+    /// `role=style` entries separated by `:`, roles named as the fields of [`Theme`] (`warn` is accepted
+    /// for `warning`) and styles written as accepted by [`Style`]'s `FromStr`. A style starting with
+    /// `+` is added to the current style of the role, so it inherits the colors and attributes it does
+    /// not set.
+    ///
+    /// # Errors
+    ///
+    /// Returns an error naming the offending entry if a role is unknown or a style invalid.
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// use bytescolor::theme::Theme;
+    /// use bytescolor::{Color, Style};
+    ///
+    /// let theme = Theme::default().with_overrides("error=bold magenta:muted=+italic").unwrap();
+    /// assert_eq!(theme.error, Style::new().fg(Color::Magenta).bold());
+    /// assert_eq!(theme.muted, Style::new().dim().italic());
+    ///
+    /// let error = Theme::default().with_overrides("eror=red").unwrap_err();
+    /// assert_eq!(error.key(), Some("eror"));
+    /// assert_eq!(error.to_string(), "invalid theme entry 'eror': unknown role");
+    /// ```
+    pub fn with_overrides(mut self, spec: &str) -> Result<Theme, ThemeError> {
+        for entry in spec
+            .split(':')
+            .map(str::trim)
+            .filter(|entry| !entry.is_empty())
+        {
+            let Some((role, value)) = entry.split_once('=') else {
+                return Err(ThemeError::entry(entry, "expected 'role=style'"));
+            };
+            self.apply(role.trim(), value.trim())?;
+        }
+        Ok(self)
+    }
+
+    /// Overrides the style of `role` with the style described by `value`, added to the current one if
+    /// `value` starts with `+`.
+    fn apply(&mut self, role: &str, value: &str) -> Result<(), ThemeError> {
+        let target = self
+            .role_mut(role)
+            .ok_or_else(|| ThemeError::entry(role, "unknown role"))?;
+        let (inherit, value) = match value.strip_prefix('+') {
+            Some(rest) => (true, rest),
+            None => (false, value),
+        };
+        let style: Style = value
+            .parse()
+            .map_err(|error| ThemeError::entry(role, error))?;
+        *target = if inherit { *target | style } else { style };
+        Ok(())
+    }
+
+    /// Returns the style of the role named `name`.
+    fn role_mut(&mut self, name: &str) -> Option<&mut Style> {
+        let style = match name {
+            "error" => &mut self.error,
+            "warning" | "warn" => &mut self.warning,
+            "success" => &mut self.success,
+            "info" => &mut self.info,
+            "heading" => &mut self.heading,
+            "usage" => &mut self.usage,
+            "literal" => &mut self.literal,
+            "placeholder" => &mut self.placeholder,
+            "valid" => &mut self.valid,
+            "invalid" => &mut self.invalid,
+            "muted" => &mut self.muted,
+            _ => return None,
+        };
+        Some(style)
+    }
+}
+
+/// An error describing why a theme description or file could not be loaded.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct ThemeError {
+    key: Option<String>,
+    line: Option<usize>,
+    message: String,
+}
+
+impl ThemeError {
+    /// Creates an error about the entry of `key`.
+    fn entry(key: &str, message: impl fmt::Display) -> Self {
+        ThemeError {
+            key: Some(key.to_string()),
+            line: None,
+            message: message.to_string(),
+        }
+    }
+
+    /// Creates an error about the description as a whole, such as a syntax error.
+    #[cfg(any(feature = "json", feature = "toml"))]
+    fn syntax(message: impl fmt::Display) -> Self {
+        ThemeError {
+            key: None,
+            line: None,
+            message: message.to_string(),
+        }
+    }
+
+    /// Sets the line of the file the error is at.
+    #[cfg(any(feature = "json", feature = "toml"))]
+    fn at_line(mut self, line: usize) -> Self {
+        self.line = Some(line);
+        self
+    }
+
+    /// Returns the role, or the whole entry if it has no role, that the error is about, or `None` for
+    /// errors about the description as a whole.
+    pub fn key(&self) -> Option<&str> {
+        self.key.as_deref()
+    }
+
+    /// Returns the line of the theme file the error is at, counting from 1, if the error comes from a
+    /// file.
+    pub fn line(&self) -> Option<usize> {
+        self.line
+    }
+}
+
+impl fmt::Display for ThemeError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        if let Some(line) = self.line {
+            write!(f, "line {}: ", line)?;
+        }
+        if let Some(key) = &self.key {
+            write!(f, "invalid theme entry '{}': ", key)?;
+        }
+        f.write_str(&self.message)
+    }
+}
+
+impl Error for ThemeError {}
+
+#[cfg(feature = "clap")]
+impl Theme {
+    /// Returns the theme as the styles of `clap`'s help and error output: headings, usage, literals,
+    /// placeholders, errors, valid and invalid values map to the roles of the same name.
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// use bytescolor::theme::Theme;
+    ///
+    /// let styles = Theme::default().clap_styles();
+    /// assert!(styles.get_error().get_effects().contains(clap::builder::styling::Effects::BOLD));
+    /// ```
+    pub fn clap_styles(&self) -> clap::builder::Styles {
+        clap::builder::Styles::styled()
+            .header(clap_style(self.heading))
+            .usage(clap_style(self.usage))
+            .literal(clap_style(self.literal))
+            .placeholder(clap_style(self.placeholder))
+            .error(clap_style(self.error))
+            .valid(clap_style(self.valid))
+            .invalid(clap_style(self.invalid))
+    }
+}
+
+#[cfg(feature = "clap")]
+impl From<Theme> for clap::builder::Styles {
+    fn from(theme: Theme) -> Self {
+        theme.clap_styles()
+    }
+}
+
+/// Converts a style to its `anstyle` equivalent, as re-exported by `clap`.
+#[cfg(feature = "clap")]
+fn clap_style(style: Style) -> clap::builder::styling::Style {
+    use clap::builder::styling::Effects;
+
+    let mut out = clap::builder::styling::Style::new()
+        .fg_color(style.foreground().map(clap_color))
+        .bg_color(style.background().map(clap_color));
+    for attribute in crate::markup::attributes(style) {
+        let effect = match attribute {
+            "bold" => Effects::BOLD,
+            "dim" => Effects::DIMMED,
+            "italic" => Effects::ITALIC,
+            "underline" => Effects::UNDERLINE,
+            "blink" => Effects::BLINK,
+            "reverse" => Effects::INVERT,
+            "hidden" => Effects::HIDDEN,
+            _ => Effects::STRIKETHROUGH,
+        };
+        out = out.effects(out.get_effects() | effect);
+    }
+    out
+}
+
+#[cfg(feature = "clap")]
+fn clap_color(color: Color) -> clap::builder::styling::Color {
+    use clap::builder::styling::{Ansi256Color, AnsiColor, RgbColor};
+
+    let ansi = match color {
+        Color::Black => AnsiColor::Black,
+        Color::Red => AnsiColor::Red,
+        Color::Green => AnsiColor::Green,
+        Color::Yellow => AnsiColor::Yellow,
+        Color::Blue => AnsiColor::Blue,
+        Color::Magenta => AnsiColor::Magenta,
+        Color::Cyan => AnsiColor::Cyan,
+        Color::White => AnsiColor::White,
+        Color::BrightBlack => AnsiColor::BrightBlack,
+        Color::BrightRed => AnsiColor::BrightRed,
+        Color::BrightGreen => AnsiColor::BrightGreen,
+        Color::BrightYellow => AnsiColor::BrightYellow,
+        Color::BrightBlue => AnsiColor::BrightBlue,
+        Color::BrightMagenta => AnsiColor::BrightMagenta,
+        Color::BrightCyan => AnsiColor::BrightCyan,
+        Color::BrightWhite => AnsiColor::BrightWhite,
+        Color::Fixed(index) => return Ansi256Color(index).into(),
+        Color::Rgb(r, g, b) => return RgbColor(r, g, b).into(),
+    };
+    ansi.into()
+}
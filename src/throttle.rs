@@ -0,0 +1,120 @@
+//! # Throttled Logging
+//!
+//! [`Throttle`] wraps a writer and suppresses runs of identical lines, replacing the repetitions with a
+//! dimmed `… repeated N times` summary, so a retry loop or a flapping check does not flood a colored
+//! log. Lines count as identical when their visible text is, whatever their styling.
+//!
+//! ```rust
+//! use bytescolor::throttle::Throttle;
+//! use bytescolor::{ansi, ByteColor};
+//!
+//! let mut log = Throttle::new(Vec::new());
+//! for _ in 0..4 {
+//!     log.line("connection refused".red())?;
+//! }
+//! log.line("connected".green())?;
+//! let out = String::from_utf8(log.finish()?).unwrap();
+//! assert_eq!(
+//!     ansi::strip(&out),
+//!     "connection refused\n… repeated 3 times\nconnected\n"
+//! );
+//! # Ok::<(), std::io::Error>(())
+//! ```
+//!
+//! This module is available with the `widgets` feature, enabled by default.
+
+use std::fmt::Display;
+use std::io::{self, Write};
+
+use crate::{ansi, Style};
+
+const SUMMARY: Style = Style::new().dim();
+
+/// A line writer collapsing consecutive identical lines.
+#[derive(Debug)]
+pub struct Throttle<W: Write> {
+    inner: Option<W>,
+    /// The visible text of the last line written.
+    last: Option<String>,
+    /// How many times the last line was suppressed since it was written.
+    repeats: usize,
+}
+
+impl<W: Write> Throttle<W> {
+    /// Creates a throttle writing to `inner`.
+    pub fn new(inner: W) -> Self {
+        Throttle {
+            inner: Some(inner),
+            last: None,
+            repeats: 0,
+        }
+    }
+
+    /// Writes `line` followed by a newline, unless its visible text is that of the previous line, in
+    /// which case it is only counted. The summary of a previous run of repetitions is written first.
+    pub fn line(&mut self, line: impl Display) -> io::Result<()> {
+        let raw = line.to_string();
+        let visible = ansi::strip(&raw);
+        if self.last.as_deref() == Some(&*visible) {
+            self.repeats += 1;
+            return Ok(());
+        }
+        self.last = Some(visible.into_owned());
+        self.summarize()?;
+        let inner = self.get_mut();
+        inner.write_all(raw.as_bytes())?;
+        inner.write_all(b"\n")?;
+        inner.flush()
+    }
+
+    /// Returns the number of times the last line was repeated since it was written.
+    pub fn repeats(&self) -> usize {
+        self.repeats
+    }
+
+    /// Returns the wrapped writer.
+    ///
+    /// Writing to it directly does not reset the repetition count, so an interleaved line does not
+    /// break a run of repetitions.
+    pub fn get_mut(&mut self) -> &mut W {
+        self.inner
+            .as_mut()
+            .expect("the writer is only taken by finish")
+    }
+
+    /// Writes the summary of pending repetitions, if any, flushes and returns the wrapped writer.
+    ///
+    /// # Errors
+    ///
+    /// Returns any error writing the summary or flushing.
+    pub fn finish(mut self) -> io::Result<W> {
+        self.summarize()?;
+        let mut inner = self
+            .inner
+            .take()
+            .expect("the writer is only taken by finish");
+        inner.flush()?;
+        Ok(inner)
+    }
+
+    /// Writes the `… repeated N times` line for the pending repetitions and resets their count.
+    fn summarize(&mut self) -> io::Result<()> {
+        let repeats = std::mem::take(&mut self.repeats);
+        if repeats == 0 {
+            return Ok(());
+        }
+        let times = if repeats == 1 { "time" } else { "times" };
+        let summary = SUMMARY.wrap(format!("… repeated {} {}", repeats, times));
+        writeln!(self.get_mut(), "{}", summary)
+    }
+}
+
+impl<W: Write> Drop for Throttle<W> {
+    /// Writes the summary of pending repetitions, so they are not silently lost.
+    fn drop(&mut self) {
+        if self.inner.is_some() {
+            let _ = self.summarize();
+            let _ = self.get_mut().flush();
+        }
+    }
+}
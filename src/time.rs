@@ -0,0 +1,260 @@
+//! # Time Formatting
+//!
+//! [`HumanDuration`] renders durations for benchmark and request-latency output as `2m 13s`, numbers and
+//! units styled separately, optionally colored by speed.
+//!
+//! ```rust
+//! use std::time::Duration;
+//! use bytescolor::time::HumanDuration;
+//!
+//! let elapsed = Duration::from_millis(133_250);
+//! println!("took {}", elapsed.human_duration()); // Displays "2m 13s"
+//! ```
+//!
+//! [`timestamp`] and [`rfc3339`] render timestamps with a dimmed date and a highlighted sub-second part,
+//! so they stop dominating log lines visually.
+//!
+//! This module is available with the `basic` feature, enabled by default.
+
+use std::time::{Duration, SystemTime, UNIX_EPOCH};
+
+use crate::number::Thresholds;
+use crate::{Color, Style, StyledString};
+
+const UNIT: Style = Style::new().dim();
+
+/// Extension trait formatting durations, and integers counting milliseconds, in human-readable form.
+pub trait HumanDuration {
+    /// Formats the duration with its two most significant units, the units dimmed.
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// use std::time::Duration;
+    /// use bytescolor::time::HumanDuration;
+    ///
+    /// assert_eq!(Duration::from_secs(133).human_duration(), "2m 13s");
+    /// assert_eq!(Duration::from_millis(1500).human_duration(), "1s 500ms");
+    /// assert_eq!(Duration::from_micros(850).human_duration(), "850µs");
+    /// assert_eq!(7_200_000_u64.human_duration(), "2h");
+    /// ```
+    fn human_duration(&self) -> StyledString;
+
+    /// Formats the duration like [`human_duration`](HumanDuration::human_duration), coloring the
+    /// numbers by the length of the duration in seconds.
+    fn human_duration_with(&self, thresholds: &Thresholds) -> StyledString;
+
+    /// Formats the duration colored green when under `fast`, red from `slow` on, and yellow in
+    /// between.
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// use std::time::Duration;
+    /// use bytescolor::time::HumanDuration;
+    ///
+    /// let latency = Duration::from_millis(340);
+    /// let (fast, slow) = (Duration::from_millis(100), Duration::from_secs(1));
+    /// println!("{}", latency.human_duration_graded(fast, slow)); // Displays "340ms" in yellow
+    /// ```
+    fn human_duration_graded(&self, fast: Duration, slow: Duration) -> StyledString {
+        let thresholds = Thresholds::new(Style::new().fg(Color::Green))
+            .above(fast.as_secs_f64(), Style::new().fg(Color::Yellow))
+            .above(slow.as_secs_f64(), Style::new().fg(Color::Red));
+        self.human_duration_with(&thresholds)
+    }
+}
+
+impl HumanDuration for Duration {
+    fn human_duration(&self) -> StyledString {
+        human_duration(*self, Style::new())
+    }
+
+    fn human_duration_with(&self, thresholds: &Thresholds) -> StyledString {
+        human_duration(*self, thresholds.style_for(self.as_secs_f64()))
+    }
+}
+
+macro_rules! impl_human_duration_for_millis {
+    ($($t:ty),*) => {
+        $(
+            impl HumanDuration for $t {
+                fn human_duration(&self) -> StyledString {
+                    Duration::from_millis(*self as u64).human_duration()
+                }
+
+                fn human_duration_with(&self, thresholds: &Thresholds) -> StyledString {
+                    Duration::from_millis(*self as u64).human_duration_with(thresholds)
+                }
+            }
+        )*
+    };
+}
+
+impl_human_duration_for_millis!(u16, u32, u64, usize);
+
+fn human_duration(duration: Duration, style: Style) -> StyledString {
+    const UNITS: [(&str, u128); 7] = [
+        ("d", 86_400_000_000_000),
+        ("h", 3_600_000_000_000),
+        ("m", 60_000_000_000),
+        ("s", 1_000_000_000),
+        ("ms", 1_000_000),
+        ("µs", 1_000),
+        ("ns", 1),
+    ];
+    let nanos = duration.as_nanos();
+    let first = UNITS
+        .iter()
+        .position(|&(_, size)| nanos >= size)
+        .unwrap_or(UNITS.len() - 1);
+    let mut out = String::new();
+    let (unit, size) = UNITS[first];
+    out.push_str(&style.wrap(nanos / size));
+    out.push_str(&UNIT.wrap(unit));
+    if let Some(&(unit, next)) = UNITS.get(first + 1) {
+        let rest = nanos % size / next;
+        if rest > 0 {
+            out.push(' ');
+            out.push_str(&style.wrap(rest));
+            out.push_str(&UNIT.wrap(unit));
+        }
+    }
+    StyledString::from(out)
+}
+
+/// Styles for the parts of a timestamp.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub struct TimestampStyles {
+    /// The date (`2024-05-01`) and the date-time separator.
+    pub date: Style,
+    /// The time of day (`12:34:56`).
+    pub time: Style,
+    /// The sub-second part (`.789`).
+    pub fraction: Style,
+    /// The UTC offset (`Z`, `+02:00`).
+    pub offset: Style,
+}
+
+impl Default for TimestampStyles {
+    fn default() -> Self {
+        TimestampStyles {
+            date: Style::new().dim(),
+            time: Style::new(),
+            fraction: Style::new().fg(Color::Cyan),
+            offset: Style::new().dim(),
+        }
+    }
+}
+
+/// Renders `time` as an RFC 3339 UTC timestamp with millisecond precision, styled with the default
+/// [`TimestampStyles`].
+///
+/// # Examples
+///
+/// ```rust
+/// use std::time::{Duration, UNIX_EPOCH};
+/// use bytescolor::time;
+///
+/// let at = UNIX_EPOCH + Duration::from_millis(1_714_566_896_789);
+/// assert_eq!(time::timestamp(at), "2024-05-01T12:34:56.789Z");
+/// ```
+pub fn timestamp(time: SystemTime) -> StyledString {
+    timestamp_with(time, &TimestampStyles::default())
+}
+
+/// Renders `time` as an RFC 3339 UTC timestamp with millisecond precision, styled with `styles`.
+pub fn timestamp_with(time: SystemTime, styles: &TimestampStyles) -> StyledString {
+    let millis = match time.duration_since(UNIX_EPOCH) {
+        Ok(after) => after.as_millis() as i128,
+        Err(before) => -(before.duration().as_millis() as i128),
+    };
+    let (days, millis) = (millis.div_euclid(86_400_000), millis.rem_euclid(86_400_000));
+    let (year, month, day) = civil_from_days(days as i64);
+    let secs = millis / 1000;
+    let text = format!(
+        "{:04}-{:02}-{:02}T{:02}:{:02}:{:02}.{:03}Z",
+        year,
+        month,
+        day,
+        secs / 3600,
+        secs / 60 % 60,
+        secs % 60,
+        millis % 1000
+    );
+    rfc3339_with(&text, styles)
+}
+
+/// Styles an RFC 3339 (or similar `date time` shaped) timestamp string with the default
+/// [`TimestampStyles`]. Text that does not look like a timestamp is returned unstyled.
+///
+/// # Examples
+///
+/// ```rust
+/// # if cfg!(feature = "no-color-build") { return; }
+/// use bytescolor::time;
+///
+/// let styled = time::rfc3339("2024-05-01T12:34:56.789+02:00");
+/// assert_eq!(styled, "2024-05-01T12:34:56.789+02:00");
+/// assert!(styled.as_str().starts_with("\x1b[2m2024-05-01T\x1b[0m12:34:56"));
+/// ```
+pub fn rfc3339(text: &str) -> StyledString {
+    rfc3339_with(text, &TimestampStyles::default())
+}
+
+/// Styles an RFC 3339 (or similar `date time` shaped) timestamp string with `styles`.
+pub fn rfc3339_with(text: &str, styles: &TimestampStyles) -> StyledString {
+    let separator = match text.find(['T', 't', ' ']) {
+        Some(index) if text.starts_with(|c: char| c.is_ascii_digit()) => index,
+        _ => return StyledString::from(text),
+    };
+    let (date, rest) = text.split_at(separator + 1);
+    let time_end = rest
+        .find(|c: char| !(c.is_ascii_digit() || c == ':'))
+        .unwrap_or(rest.len());
+    let (time, rest) = rest.split_at(time_end);
+    let fraction_end = match rest.strip_prefix(['.', ',']) {
+        Some(digits) => {
+            1 + digits
+                .find(|c: char| !c.is_ascii_digit())
+                .unwrap_or(digits.len())
+        }
+        None => 0,
+    };
+    let (fraction, offset) = rest.split_at(fraction_end);
+    let mut out = styles.date.wrap(date);
+    out.push_str(&styles.time.wrap(time));
+    if !fraction.is_empty() {
+        out.push_str(&styles.fraction.wrap(fraction));
+    }
+    if !offset.is_empty() {
+        out.push_str(&styles.offset.wrap(offset));
+    }
+    StyledString::from(out)
+}
+
+/// Converts a proleptic Gregorian `(year, month, day)` to days since 1970-01-01.
+#[cfg(feature = "widgets")]
+pub(crate) fn days_from_civil(year: i64, month: u32, day: u32) -> i64 {
+    let year = year - i64::from(month <= 2);
+    let era = year.div_euclid(400);
+    let yoe = year.rem_euclid(400);
+    let mp = i64::from((month + 9) % 12);
+    let doy = (153 * mp + 2) / 5 + i64::from(day) - 1;
+    let doe = yoe * 365 + yoe / 4 - yoe / 100 + doy;
+    era * 146_097 + doe - 719_468
+}
+
+/// Converts days since 1970-01-01 to a proleptic Gregorian `(year, month, day)`.
+pub(crate) fn civil_from_days(days: i64) -> (i64, u32, u32) {
+    let z = days + 719_468;
+    let era = z.div_euclid(146_097);
+    let doe = z.rem_euclid(146_097);
+    let yoe = (doe - doe / 1460 + doe / 36_524 - doe / 146_096) / 365;
+    let doy = doe - (365 * yoe + yoe / 4 - yoe / 100);
+    let mp = (5 * doy + 2) / 153;
+    let day = (doy - (153 * mp + 2) / 5 + 1) as u32;
+    let month = if mp < 10 { mp + 3 } else { mp - 9 } as u32;
+    let year = yoe + era * 400 + i64::from(month <= 2);
+    (year, month, day)
+}
@@ -0,0 +1,300 @@
+//! # TLV Structures
+//!
+//! [`Tlv`] walks tag-length-value encoded bytes, as found in ASN.1 (BER/DER), smart card and many
+//! network protocols, and prints one element per line: tag bytes cyan, length bytes yellow and value
+//! bytes green, with nested elements indented under their parent. Bytes that do not form a complete
+//! element are shown in red.
+//!
+//! ```rust
+//! use bytescolor::tlv::Tlv;
+//!
+//! // SEQUENCE { INTEGER 5, OCTET STRING "hi" }
+//! let der = [0x30, 0x07, 0x02, 0x01, 0x05, 0x04, 0x02, b'h', b'i'];
+//! println!("{}", Tlv::der().render(&der));
+//! // 30 07
+//! //   02 01 05
+//! //   04 02 68 69
+//! ```
+//!
+//! This module is available with the `widgets` feature, enabled by default.
+
+use crate::{Color, Style};
+
+const TAG: Style = Style::new().fg(Color::Cyan);
+const LENGTH: Style = Style::new().fg(Color::Yellow);
+const VALUE: Style = Style::new().fg(Color::Green);
+const INVALID: Style = Style::new().fg(Color::Red);
+const NOTE: Style = Style::new().dim();
+
+/// Maximum nesting depth walked, protecting against stack exhaustion on hostile input: deeper nested
+/// elements are shown as plain values.
+const MAX_DEPTH: usize = 64;
+
+/// How tags are encoded.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub enum TagFormat {
+    /// A fixed number of bytes.
+    Fixed(usize),
+    /// An ASN.1 BER tag: one byte, followed by more when its low five bits are all set, each with the
+    /// high bit set except the last.
+    Ber,
+    /// An unsigned LEB128 varint, such as a protocol buffers field key.
+    Varint,
+}
+
+/// How lengths are encoded.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub enum LengthFormat {
+    /// A big-endian unsigned integer of a fixed number of bytes.
+    Fixed(usize),
+    /// An ASN.1 BER definite length: one byte below `0x80`, or `0x80` plus the number of big-endian
+    /// bytes that follow.
+    Ber,
+    /// An unsigned LEB128 varint.
+    Varint,
+    /// The encoding of a protocol buffers field, given by the wire type in the low three bits of its
+    /// key: varint (0), 64-bit (1) and 32-bit (5) values have no length bytes, only length-delimited
+    /// values (2) are prefixed with a varint length. Groups (3 and 4) are not supported.
+    Protobuf,
+}
+
+/// A configurable TLV walker.
+///
+/// # Examples
+///
+/// ```rust
+/// use bytescolor::tlv::{LengthFormat, TagFormat, Tlv};
+/// use bytescolor::ansi;
+///
+/// let walker = Tlv::new()
+///     .tag(TagFormat::Fixed(2))
+///     .length(LengthFormat::Fixed(2))
+///     .nested(|tag| tag == 0x0100);
+/// let bytes = [0x01, 0x00, 0x00, 0x05, 0x00, 0x07, 0x00, 0x01, 0xff];
+/// let out = walker.render(&bytes);
+/// assert_eq!(ansi::strip(&out), "01 00 00 05\n  00 07 00 01 ff");
+///
+/// // Headers of no bytes cannot be walked.
+/// let walker = Tlv::new().tag(TagFormat::Fixed(0)).length(LengthFormat::Fixed(0));
+/// assert_eq!(ansi::strip(&walker.render(&[0x01, 0x02])), "01 02 (empty element)");
+/// ```
+#[derive(Debug, Clone, Copy)]
+pub struct Tlv {
+    tag: TagFormat,
+    length: LengthFormat,
+    nested: fn(u64) -> bool,
+    max_value: usize,
+}
+
+impl Default for Tlv {
+    fn default() -> Self {
+        Tlv {
+            tag: TagFormat::Fixed(1),
+            length: LengthFormat::Fixed(1),
+            nested: |_| false,
+            max_value: 16,
+        }
+    }
+}
+
+impl Tlv {
+    /// Creates a walker of one-byte tags and one-byte lengths, without nesting.
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// Creates a walker of ASN.1 BER and DER: BER tags and lengths, with the elements of constructed
+    /// tags (bit 6 of the first tag byte set) nested.
+    pub fn der() -> Self {
+        Tlv::new()
+            .tag(TagFormat::Ber)
+            .length(LengthFormat::Ber)
+            .nested(|tag| first_tag_byte(tag) & 0x20 != 0)
+    }
+
+    /// Creates a walker of protocol buffers messages: varint keys, the value of each field read
+    /// according to its wire type, without nesting since messages do not tell embedded messages from
+    /// bytes.
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// use bytescolor::tlv::Tlv;
+    /// use bytescolor::ansi;
+    ///
+    /// // field 1 = 150 (varint), field 2 = "hi" (bytes), field 3 = 1.0 (float)
+    /// let message = [0x08, 0x96, 0x01, 0x12, 0x02, b'h', b'i', 0x1d, 0x00, 0x00, 0x80, 0x3f];
+    /// assert_eq!(
+    ///     ansi::strip(&Tlv::protobuf().render(&message)),
+    ///     "08 96 01\n12 02 68 69\n1d 00 00 80 3f"
+    /// );
+    /// ```
+    pub fn protobuf() -> Self {
+        Tlv::new()
+            .tag(TagFormat::Varint)
+            .length(LengthFormat::Protobuf)
+    }
+
+    /// Sets the encoding of tags.
+    pub fn tag(mut self, tag: TagFormat) -> Self {
+        self.tag = tag;
+        self
+    }
+
+    /// Sets the encoding of lengths.
+    pub fn length(mut self, length: LengthFormat) -> Self {
+        self.length = length;
+        self
+    }
+
+    /// Sets which tags hold a sequence of elements rather than a plain value, given the tag bytes as a
+    /// big-endian integer. The value of such a tag is walked in turn, one level deeper, up to 64
+    /// levels.
+    pub fn nested(mut self, nested: fn(u64) -> bool) -> Self {
+        self.nested = nested;
+        self
+    }
+
+    /// Sets the number of value bytes shown before the rest is elided, 16 by default.
+    pub fn max_value(mut self, max_value: usize) -> Self {
+        self.max_value = max_value;
+        self
+    }
+
+    /// Renders the elements of `bytes`, one per line, without a final newline.
+    pub fn render(&self, bytes: &[u8]) -> String {
+        let mut lines = Vec::new();
+        self.walk(bytes, 0, &mut lines);
+        lines.join("\n")
+    }
+
+    fn walk(&self, mut bytes: &[u8], depth: usize, lines: &mut Vec<String>) {
+        let indent = "  ".repeat(depth);
+        while !bytes.is_empty() {
+            let Some((tag, length, header)) = self.header(bytes) else {
+                lines.push(format!(
+                    "{}{} {}",
+                    indent,
+                    INVALID.wrap(hex(bytes)),
+                    NOTE.wrap("(truncated header)")
+                ));
+                return;
+            };
+            if header + length == 0 {
+                // The walk would not advance.
+                lines.push(format!(
+                    "{}{} {}",
+                    indent,
+                    INVALID.wrap(hex(bytes)),
+                    NOTE.wrap("(empty element)")
+                ));
+                return;
+            }
+            let mut line = format!("{}{}", indent, TAG.wrap(hex(&bytes[..tag.1])));
+            if header > tag.1 {
+                line.push(' ');
+                line.push_str(&LENGTH.wrap(hex(&bytes[tag.1..header])));
+            }
+            let rest = &bytes[header..];
+            let Some(value) = rest.get(..length) else {
+                line.push(' ');
+                line.push_str(&INVALID.wrap(hex(rest)));
+                line.push(' ');
+                line.push_str(&NOTE.wrap(format!("({} of {} bytes)", rest.len(), length)));
+                lines.push(line);
+                return;
+            };
+            if (self.nested)(tag.0) && !value.is_empty() && depth < MAX_DEPTH {
+                lines.push(line);
+                self.walk(value, depth + 1, lines);
+            } else {
+                if !value.is_empty() {
+                    line.push(' ');
+                    line.push_str(&VALUE.wrap(hex(&value[..value.len().min(self.max_value)])));
+                }
+                if value.len() > self.max_value {
+                    line.push(' ');
+                    line.push_str(&NOTE.wrap(format!("… ({} bytes)", value.len())));
+                }
+                lines.push(line);
+            }
+            bytes = &rest[length..];
+        }
+    }
+
+    /// Decodes the tag and length at the start of `bytes`, returning the tag value with its byte
+    /// count, the length and the byte count of the whole header.
+    fn header(&self, bytes: &[u8]) -> Option<((u64, usize), usize, usize)> {
+        let tag_len = match self.tag {
+            TagFormat::Fixed(len) => len,
+            TagFormat::Ber => {
+                if bytes.first()? & 0x1f == 0x1f {
+                    1 + bytes[1..].iter().position(|&b| b & 0x80 == 0)? + 1
+                } else {
+                    1
+                }
+            }
+            TagFormat::Varint => varint(bytes)?.1,
+        };
+        let tag_bytes = bytes.get(..tag_len)?;
+        let tag = match self.tag {
+            TagFormat::Varint => varint(tag_bytes)?.0,
+            _ => big_endian(tag_bytes)?,
+        };
+        let rest = &bytes[tag_len..];
+        let (length, length_len) = match self.length {
+            LengthFormat::Fixed(len) => (big_endian(rest.get(..len)?)?, len),
+            LengthFormat::Ber => match *rest.first()? {
+                short if short < 0x80 => (u64::from(short), 1),
+                0x80 => return None,
+                long => {
+                    let count = usize::from(long & 0x7f);
+                    (big_endian(rest.get(1..1 + count)?)?, 1 + count)
+                }
+            },
+            LengthFormat::Varint => varint(rest)?,
+            LengthFormat::Protobuf => match tag & 7 {
+                0 => (varint(rest)?.1 as u64, 0),
+                1 => (8, 0),
+                2 => varint(rest)?,
+                5 => (4, 0),
+                _ => return None,
+            },
+        };
+        let length = usize::try_from(length).ok()?;
+        Some(((tag, tag_len), length, tag_len + length_len))
+    }
+}
+
+/// Returns the first byte of a tag given as a big-endian integer.
+fn first_tag_byte(mut tag: u64) -> u64 {
+    while tag > 0xff {
+        tag >>= 8;
+    }
+    tag
+}
+
+/// Decodes a big-endian unsigned integer of at most 8 bytes.
+fn big_endian(bytes: &[u8]) -> Option<u64> {
+    if bytes.len() > 8 {
+        return None;
+    }
+    Some(bytes.iter().fold(0, |acc, &b| acc << 8 | u64::from(b)))
+}
+
+/// Decodes an unsigned LEB128 varint, returning its value and byte count.
+fn varint(bytes: &[u8]) -> Option<(u64, usize)> {
+    let mut value = 0u64;
+    for (index, &byte) in bytes.iter().enumerate().take(10) {
+        value |= u64::from(byte & 0x7f) << (7 * index);
+        if byte & 0x80 == 0 {
+            return Some((value, index + 1));
+        }
+    }
+    None
+}
+
+fn hex(bytes: &[u8]) -> String {
+    let digits: Vec<String> = bytes.iter().map(|b| format!("{:02x}", b)).collect();
+    digits.join(" ")
+}
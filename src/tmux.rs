@@ -0,0 +1,95 @@
+//! # tmux Formats
+//!
+//! Converts [`Style`]s to tmux `#[...]` style directives, so tools generating status-line segments can
+//! share one styling definition with their terminal output.
+//!
+//! ```rust
+//! use bytescolor::{tmux, Color, Style};
+//!
+//! let alert = Style::new().fg(Color::White).bg(Color::Red).bold();
+//! assert_eq!(tmux::format(alert), "#[fg=white,bg=red,bold]");
+//! assert_eq!(tmux::wrap(alert, "CPU 97%"), "#[fg=white,bg=red,bold]CPU 97%#[default]");
+//! ```
+//!
+//! Directives are configuration text rather than escape sequences, so they are produced whatever the
+//! [`control`](crate::control) settings.
+
+use crate::{markup, Color, Style};
+
+/// Returns the `#[...]` directive selecting `style`, or `#[default]` for the plain style.
+///
+/// # Examples
+///
+/// ```rust
+/// use bytescolor::{tmux, Color, Style};
+///
+/// assert_eq!(tmux::format(Style::new().fg(Color::Fixed(208)).underline()), "#[fg=colour208,underscore]");
+/// assert_eq!(tmux::format(Style::new().fg(Color::Rgb(255, 128, 0))), "#[fg=#ff8000]");
+/// assert_eq!(tmux::format(Style::new()), "#[default]");
+/// ```
+pub fn format(style: Style) -> String {
+    let mut parts = Vec::new();
+    if let Some(fg) = style.foreground() {
+        parts.push(format!("fg={}", color(fg)));
+    }
+    if let Some(bg) = style.background() {
+        parts.push(format!("bg={}", color(bg)));
+    }
+    for attribute in markup::attributes(style) {
+        parts.push(
+            match attribute {
+                "italic" => "italics",
+                "underline" => "underscore",
+                attribute => attribute,
+            }
+            .to_string(),
+        );
+    }
+    if parts.is_empty() {
+        return "#[default]".to_string();
+    }
+    format!("#[{}]", parts.join(","))
+}
+
+/// Wraps `text` in the directive selecting `style` and a `#[default]` reset, escaping `#` in `text`
+/// so it is not taken for a format sequence. Plain text is only escaped.
+///
+/// # Examples
+///
+/// ```rust
+/// use bytescolor::{tmux, Style};
+///
+/// assert_eq!(tmux::wrap(Style::new().bold(), "#1"), "#[bold]##1#[default]");
+/// assert_eq!(tmux::wrap(Style::new(), "#1"), "##1");
+/// ```
+pub fn wrap(style: Style, text: &str) -> String {
+    let text = text.replace('#', "##");
+    if style.is_plain() {
+        return text;
+    }
+    format!("{}{}#[default]", format(style), text)
+}
+
+fn color(color: Color) -> String {
+    let name = match color {
+        Color::Black => "black",
+        Color::Red => "red",
+        Color::Green => "green",
+        Color::Yellow => "yellow",
+        Color::Blue => "blue",
+        Color::Magenta => "magenta",
+        Color::Cyan => "cyan",
+        Color::White => "white",
+        Color::BrightBlack => "brightblack",
+        Color::BrightRed => "brightred",
+        Color::BrightGreen => "brightgreen",
+        Color::BrightYellow => "brightyellow",
+        Color::BrightBlue => "brightblue",
+        Color::BrightMagenta => "brightmagenta",
+        Color::BrightCyan => "brightcyan",
+        Color::BrightWhite => "brightwhite",
+        Color::Fixed(index) => return format!("colour{}", index),
+        Color::Rgb(r, g, b) => return markup::hex((r, g, b)),
+    };
+    name.to_string()
+}
@@ -0,0 +1,153 @@
+//! # Trees
+//!
+//! [`Tree`] renders nested items with guide lines, the way `cargo tree` and `tree` do, for dependency
+//! trees, file hierarchies and other nested listings:
+//!
+//! ```text
+//! app
+//! ├─ serde
+//! │  └─ serde_derive
+//! └─ tokio
+//! ```
+//!
+//! Labels may already be styled; plain labels can be colored by depth with
+//! [`depth_styles`](Tree::depth_styles).
+//!
+//! ```rust
+//! use bytescolor::tree::Tree;
+//! use bytescolor::{Color, Style};
+//!
+//! let tree = Tree::new("app")
+//!     .child(Tree::new("serde").leaf("serde_derive"))
+//!     .leaf("tokio")
+//!     .depth_styles([Style::new().bold(), Style::new().fg(Color::Cyan)]);
+//! println!("{}", tree);
+//! ```
+//!
+//! This module is available with the `widgets` feature, enabled by default.
+
+use std::fmt;
+
+use crate::Style;
+
+/// A node with a label and child nodes.
+///
+/// The styles of the guides and depths are those of the root: the settings of the children are
+/// ignored when they are rendered as part of a larger tree.
+///
+/// # Examples
+///
+/// ```rust
+/// use bytescolor::tree::Tree;
+/// use bytescolor::ansi;
+///
+/// let mut src = Tree::new("src");
+/// for file in ["lib.rs", "tree.rs"] {
+///     src.push(Tree::new(file));
+/// }
+/// let tree = Tree::new(".").child(src).leaf("Cargo.toml");
+/// assert_eq!(
+///     ansi::strip(&tree.render()),
+///     ".\n├─ src\n│  ├─ lib.rs\n│  └─ tree.rs\n└─ Cargo.toml"
+/// );
+/// ```
+#[derive(Debug, Clone, PartialEq, Eq, Hash)]
+pub struct Tree {
+    label: String,
+    children: Vec<Tree>,
+    guide: Style,
+    depths: Vec<Style>,
+}
+
+impl Tree {
+    /// Creates a node labeled `label`, without children, with dimmed guides.
+    pub fn new(label: impl fmt::Display) -> Self {
+        Tree {
+            label: label.to_string(),
+            children: Vec::new(),
+            guide: Style::new().dim(),
+            depths: Vec::new(),
+        }
+    }
+
+    /// Adds `child` below the node.
+    pub fn child(mut self, child: Tree) -> Self {
+        self.push(child);
+        self
+    }
+
+    /// Adds a child node without children, labeled `label`.
+    pub fn leaf(self, label: impl fmt::Display) -> Self {
+        self.child(Tree::new(label))
+    }
+
+    /// Adds `child` below the node, for trees built in a loop.
+    pub fn push(&mut self, child: Tree) {
+        self.children.push(child);
+    }
+
+    /// Returns the children of the node.
+    pub fn children(&self) -> &[Tree] {
+        &self.children
+    }
+
+    /// Sets the style of the guide lines.
+    pub fn guide_style(mut self, style: Style) -> Self {
+        self.guide = style;
+        self
+    }
+
+    /// Styles the labels without escape sequences by depth: the root takes the first style, its
+    /// children the second, and so on, cycling through the styles for deeper nodes.
+    pub fn depth_styles(mut self, styles: impl IntoIterator<Item = Style>) -> Self {
+        self.depths = styles.into_iter().collect();
+        self
+    }
+
+    /// Renders the tree, one node per line, without a final newline. The continuation lines of
+    /// multi-line labels are aligned with their first line.
+    pub fn render(&self) -> String {
+        let mut out = String::new();
+        self.render_node(&mut out, self, 0, "", "");
+        out
+    }
+
+    /// Renders `node`, with `lead` before its first label line and `indent` before every other line.
+    fn render_node(&self, out: &mut String, node: &Tree, depth: usize, lead: &str, indent: &str) {
+        let style = if self.depths.is_empty() || node.label.contains('\x1b') {
+            Style::new()
+        } else {
+            self.depths[depth % self.depths.len()]
+        };
+        for (index, line) in node.label.split('\n').enumerate() {
+            if depth > 0 || index > 0 {
+                out.push('\n');
+            }
+            let guide = if index == 0 { lead } else { indent };
+            if !guide.is_empty() {
+                out.push_str(&self.guide.wrap(guide));
+            }
+            out.push_str(&style.wrap(line));
+        }
+        for (index, child) in node.children.iter().enumerate() {
+            let (branch, continuation) = if index + 1 == node.children.len() {
+                ("└─ ", "   ")
+            } else {
+                ("├─ ", "│  ")
+            };
+            self.render_node(
+                out,
+                child,
+                depth + 1,
+                &format!("{}{}", indent, branch),
+                &format!("{}{}", indent, continuation),
+            );
+        }
+    }
+}
+
+impl fmt::Display for Tree {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.write_str(&self.render())
+    }
+}
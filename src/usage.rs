@@ -0,0 +1,212 @@
+//! # Usage Errors
+//!
+//! [`UsageError`] formats the message a command-line program prints when its arguments cannot be
+//! parsed: an `error:` line, "did you mean" suggestions, hints and the usage line, in the conventional
+//! colors of a [`Theme`]. It is meant for hand-rolled argument parsers that want output looking like
+//! that of established tools.
+//!
+//! ```rust
+//! use bytescolor::usage::UsageError;
+//!
+//! let error = UsageError::unknown_argument("--verbos", ["--verbose", "--version", "--help"])
+//!     .usage("tool [OPTIONS] <FILE>");
+//! eprintln!("{}", error);
+//! // error: unexpected argument '--verbos'
+//! //
+//! //   did you mean '--verbose'?
+//! //
+//! // usage: tool [OPTIONS] <FILE>
+//! ```
+
+use std::fmt;
+
+use crate::theme::{self, Theme};
+use crate::{Style, StyledText};
+
+/// A formatted argument parsing error.
+///
+/// # Examples
+///
+/// ```rust
+/// use bytescolor::usage::UsageError;
+/// use bytescolor::ansi;
+///
+/// let error = UsageError::new("missing value for '--jobs'")
+///     .hint("pass a number, such as '--jobs 4'")
+///     .usage("tool --jobs <N>");
+/// assert_eq!(
+///     ansi::strip(&error.to_string()),
+///     "error: missing value for '--jobs'\n\n  hint: pass a number, such as '--jobs 4'\n\nusage: tool --jobs <N>"
+/// );
+/// ```
+#[derive(Debug, Clone, PartialEq, Eq, Hash)]
+pub struct UsageError {
+    message: String,
+    invalid: Option<String>,
+    suggestions: Vec<String>,
+    hints: Vec<String>,
+    usage: Option<String>,
+    theme: Theme,
+}
+
+impl UsageError {
+    /// Creates an error with `message`, styled with the current [`Theme`] (see [`theme::current`]).
+    pub fn new(message: impl Into<String>) -> Self {
+        UsageError {
+            message: message.into(),
+            invalid: None,
+            suggestions: Vec::new(),
+            hints: Vec::new(),
+            usage: None,
+            theme: theme::current(),
+        }
+    }
+
+    /// Creates the error of an unknown argument, suggesting the closest of the `known` ones when it is
+    /// close enough (see [`did_you_mean`]).
+    pub fn unknown_argument<I, T>(argument: &str, known: I) -> Self
+    where
+        I: IntoIterator<Item = T>,
+        T: AsRef<str>,
+    {
+        let mut error = UsageError::new("unexpected argument ").invalid(argument);
+        if let Some(suggestion) = did_you_mean(argument, known) {
+            error = error.suggest(suggestion);
+        }
+        error
+    }
+
+    /// Appends the invalid value the user gave to the message, quoted and in the invalid style.
+    pub fn invalid(mut self, value: impl Into<String>) -> Self {
+        self.invalid = Some(value.into());
+        self
+    }
+
+    /// Adds a "did you mean" suggestion.
+    pub fn suggest(mut self, suggestion: impl Into<String>) -> Self {
+        self.suggestions.push(suggestion.into());
+        self
+    }
+
+    /// Adds a hint line.
+    pub fn hint(mut self, hint: impl Into<String>) -> Self {
+        self.hints.push(hint.into());
+        self
+    }
+
+    /// Sets the usage line, without its `usage:` label. `<PLACEHOLDERS>` are styled as such.
+    pub fn usage(mut self, usage: impl Into<String>) -> Self {
+        self.usage = Some(usage.into());
+        self
+    }
+
+    /// Sets the theme the error is styled with.
+    pub fn theme(mut self, theme: Theme) -> Self {
+        self.theme = theme;
+        self
+    }
+
+    /// Returns the error as a styled document, without a final newline.
+    pub fn to_styled_text(&self) -> StyledText {
+        let theme = &self.theme;
+        let mut out = StyledText::styled("error:", theme.error);
+        out.push(format!(" {}", self.message), Style::new());
+        if let Some(invalid) = &self.invalid {
+            out.push(format!("'{}'", invalid), theme.invalid);
+        }
+        if !self.suggestions.is_empty() || !self.hints.is_empty() {
+            out.push("\n", Style::new());
+        }
+        for suggestion in &self.suggestions {
+            out.push("\n  did you mean ", Style::new());
+            out.push(format!("'{}'", suggestion), theme.valid);
+            out.push("?", Style::new());
+        }
+        for hint in &self.hints {
+            out.push("\n  ", Style::new());
+            out.push("hint:", theme.muted);
+            out.push(format!(" {}", hint), Style::new());
+        }
+        if let Some(usage) = &self.usage {
+            out.push("\n\n", Style::new());
+            out.push("usage:", theme.usage);
+            out.push(" ", Style::new());
+            push_usage(&mut out, usage, theme);
+        }
+        out
+    }
+}
+
+impl fmt::Display for UsageError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        fmt::Display::fmt(&self.to_styled_text(), f)
+    }
+}
+
+/// Pushes a usage line, `<PLACEHOLDERS>` in the placeholder style and the rest in the literal style.
+fn push_usage(out: &mut StyledText, usage: &str, theme: &Theme) {
+    let mut rest = usage;
+    while let Some(start) = rest.find('<') {
+        let Some(end) = rest[start..].find('>').map(|end| start + end + 1) else {
+            break;
+        };
+        out.push(&rest[..start], theme.literal);
+        out.push(&rest[start..end], theme.placeholder);
+        rest = &rest[end..];
+    }
+    out.push(rest, theme.literal);
+}
+
+/// Returns the candidate closest to `input` by edit distance, if it is close enough to be a likely
+/// typo: at most a third of the length of `input` away, or one edit for short inputs. On ties, the
+/// first candidate wins.
+///
+/// # Examples
+///
+/// ```rust
+/// use bytescolor::usage::did_you_mean;
+///
+/// assert_eq!(did_you_mean("stauts", ["status", "start", "stop"]), Some("status".to_string()));
+/// assert_eq!(did_you_mean("xyz", ["status", "start"]), None);
+/// ```
+pub fn did_you_mean<I, T>(input: &str, candidates: I) -> Option<String>
+where
+    I: IntoIterator<Item = T>,
+    T: AsRef<str>,
+{
+    let limit = (input.chars().count() / 3).max(1);
+    candidates
+        .into_iter()
+        .map(|candidate| {
+            let distance = edit_distance(input, candidate.as_ref());
+            (distance, candidate.as_ref().to_string())
+        })
+        .filter(|(distance, _)| *distance <= limit)
+        .min_by_key(|(distance, _)| *distance)
+        .map(|(_, candidate)| candidate)
+}
+
+/// Returns the Damerau-Levenshtein distance (with adjacent transpositions) between two strings.
+fn edit_distance(a: &str, b: &str) -> usize {
+    let (a, b): (Vec<char>, Vec<char>) = (a.chars().collect(), b.chars().collect());
+    let mut rows = vec![vec![0; b.len() + 1]; a.len() + 1];
+    for (i, row) in rows.iter_mut().enumerate() {
+        row[0] = i;
+    }
+    for (j, cell) in rows[0].iter_mut().enumerate() {
+        *cell = j;
+    }
+    for i in 1..=a.len() {
+        for j in 1..=b.len() {
+            let cost = usize::from(a[i - 1] != b[j - 1]);
+            let mut best = (rows[i - 1][j] + 1)
+                .min(rows[i][j - 1] + 1)
+                .min(rows[i - 1][j - 1] + cost);
+            if i > 1 && j > 1 && a[i - 1] == b[j - 2] && a[i - 2] == b[j - 1] {
+                best = best.min(rows[i - 2][j - 2] + 1);
+            }
+            rows[i][j] = best;
+        }
+    }
+    rows[a.len()][b.len()]
+}
@@ -0,0 +1,147 @@
+//! # Styled Writers
+//!
+//! [`StyledWriter`] wraps an [`io::Write`] with a stack of styles, for emitting large streamed documents
+//! such as logs and reports. Styles are pushed and popped around the text they apply to, nested styles
+//! inheriting from the enclosing ones, and escape sequences are only written when text is, so pushing and
+//! popping around nothing costs nothing and consecutive writes in the same style share one sequence.
+//! Between styles, only what differs is changed (see [`Style::transition_to`]).
+//!
+//! ```rust
+//! use std::io::Write;
+//! use bytescolor::writer::StyledWriter;
+//! use bytescolor::Style;
+//!
+//! let mut out = StyledWriter::new(Vec::new());
+//! out.push_style(Style::RED);
+//! write!(out, "error: ")?;
+//! out.push_style(Style::BOLD);
+//! write!(out, "disk full")?;
+//! out.pop();
+//! out.pop();
+//! writeln!(out, " (retrying)")?;
+//! let bytes = out.finish()?;
+//! # Ok::<(), std::io::Error>(())
+//! ```
+
+use std::io::{self, Write};
+
+use crate::Style;
+
+/// A writer applying a stack of styles to the text written through it.
+///
+/// # Examples
+///
+/// ```rust
+/// # if cfg!(feature = "no-color-build") { return Ok(()); }
+/// use std::io::Write;
+/// use bytescolor::writer::StyledWriter;
+/// use bytescolor::Style;
+///
+/// let mut out = StyledWriter::new(Vec::new());
+/// out.push_style(Style::GREEN);
+/// write!(out, "o")?;
+/// write!(out, "k")?;
+/// out.push_style(Style::BOLD);
+/// write!(out, "!")?;
+/// out.pop();
+/// out.pop();
+/// write!(out, ".")?;
+/// assert_eq!(out.finish()?, b"\x1b[32mok\x1b[1m!\x1b[0m.");
+/// # Ok::<(), std::io::Error>(())
+/// ```
+#[derive(Debug)]
+pub struct StyledWriter<W: Write> {
+    inner: Option<W>,
+    stack: Vec<Style>,
+    /// The style the output is currently in, as far as written escape sequences go.
+    emitted: Style,
+}
+
+impl<W: Write> StyledWriter<W> {
+    /// Creates a writer with an empty style stack.
+    pub fn new(inner: W) -> Self {
+        StyledWriter {
+            inner: Some(inner),
+            stack: Vec::new(),
+            emitted: Style::new(),
+        }
+    }
+
+    /// Pushes `style` on the stack, combined with the current style (see [`Style`]'s `|` operator):
+    /// the text written until the matching [`pop`](StyledWriter::pop) gets both.
+    pub fn push_style(&mut self, style: Style) {
+        let style = self.style() | style;
+        self.stack.push(style);
+    }
+
+    /// Removes the innermost style, returning it (combined with the enclosing ones), or `None` if the
+    /// stack is empty.
+    pub fn pop(&mut self) -> Option<Style> {
+        self.stack.pop()
+    }
+
+    /// Returns the style text is currently written in.
+    pub fn style(&self) -> Style {
+        self.stack.last().copied().unwrap_or_default()
+    }
+
+    /// Returns the number of styles on the stack.
+    pub fn depth(&self) -> usize {
+        self.stack.len()
+    }
+
+    /// Returns the wrapped writer.
+    pub fn get_mut(&mut self) -> &mut W {
+        self.inner
+            .as_mut()
+            .expect("the writer is only taken by finish")
+    }
+
+    /// Resets the output to the plain style if needed, flushes it and returns the wrapped writer.
+    ///
+    /// # Errors
+    ///
+    /// Returns any error writing the reset or flushing.
+    pub fn finish(mut self) -> io::Result<W> {
+        self.transition(Style::new())?;
+        self.flush()?;
+        Ok(self
+            .inner
+            .take()
+            .expect("the writer is only taken by finish"))
+    }
+
+    /// Writes the escape sequences switching the output from the emitted style to `style`.
+    fn transition(&mut self, style: Style) -> io::Result<()> {
+        if style == self.emitted {
+            return Ok(());
+        }
+        let sequence = self.emitted.transition_to(style);
+        self.emitted = style;
+        self.get_mut().write_all(sequence.as_bytes())
+    }
+}
+
+impl<W: Write> Write for StyledWriter<W> {
+    fn write(&mut self, buf: &[u8]) -> io::Result<usize> {
+        if buf.is_empty() {
+            return Ok(0);
+        }
+        self.transition(self.style())?;
+        self.get_mut().write(buf)
+    }
+
+    fn flush(&mut self) -> io::Result<()> {
+        self.get_mut().flush()
+    }
+}
+
+impl<W: Write> Drop for StyledWriter<W> {
+    /// Resets the output to the plain style, so a style left open does not leak past the writer.
+    fn drop(&mut self) {
+        if self.inner.is_some() {
+            let _ = self.transition(Style::new());
+            let _ = self.flush();
+        }
+    }
+}
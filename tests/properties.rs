@@ -0,0 +1,33 @@
+//! Property tests of the invariants the crate relies on, run with the strategies and checks of
+//! [`bytescolor::testkit`].
+//!
+//! Run with `cargo test --features testkit`.
+
+use bytescolor::testkit::proptest::prelude::*;
+use bytescolor::testkit::{self, check_paint, check_roundtrip, check_styling, check_truncate};
+use bytescolor::{ByteColor, Style};
+
+proptest! {
+    #[test]
+    fn styling_only_adds_escapes(text in testkit::text(), style in testkit::style()) {
+        check_styling(&text, &text.red())?;
+        check_styling(&text, &text.as_bytes().bold())?;
+        check_styling(&text, &text.styled(style))?;
+    }
+
+    #[test]
+    fn paint_matches_styled_text(text in testkit::text(), style in testkit::style()) {
+        check_paint(&text, style)?;
+        check_paint(&text, style | Style::BOLD)?;
+    }
+
+    #[test]
+    fn rendering_round_trips(document in testkit::styled_text()) {
+        check_roundtrip(&document)?;
+    }
+
+    #[test]
+    fn truncate_fits(text in testkit::ansi_text(), max in 0..40_usize) {
+        check_truncate(&text, max)?;
+    }
+}